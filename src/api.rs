@@ -17,7 +17,26 @@ pub async fn get(cfg: &Config, path: &str, query: &[(&str, String)]) -> Result<s
     if !query.is_empty() {
         req = req.query(query);
     }
-    send(req).await
+    send(cfg, req).await
+}
+
+/// Perform a GET request, also returning the body of responses whose status is
+/// in `allowed` (e.g. endpoints that report a 409 with a structured body).
+#[cfg(not(feature = "browser"))]
+pub async fn get_allowing(
+    cfg: &Config,
+    path: &str,
+    query: &[(&str, String)],
+    allowed: &[u16],
+) -> Result<serde_json::Value> {
+    let url = format!("{}{}", cfg.api_base_url(), path);
+    let client = reqwest::Client::new();
+    let mut req = client.get(&url);
+    req = apply_auth(req, cfg)?;
+    if !query.is_empty() {
+        req = req.query(query);
+    }
+    send_allowing(cfg, req, allowed).await
 }
 
 /// Perform a POST request with a JSON body.
@@ -27,7 +46,7 @@ pub async fn post(cfg: &Config, path: &str, body: &serde_json::Value) -> Result<
     let mut req = client.post(&url);
     req = apply_auth(req, cfg)?;
     req = req.json(body);
-    send(req).await
+    send(cfg, req).await
 }
 
 /// Perform a PUT request with a JSON body.
@@ -37,7 +56,7 @@ pub async fn put(cfg: &Config, path: &str, body: &serde_json::Value) -> Result<s
     let mut req = client.put(&url);
     req = apply_auth(req, cfg)?;
     req = req.json(body);
-    send(req).await
+    send(cfg, req).await
 }
 
 /// Perform a PATCH request with a JSON body.
@@ -51,7 +70,7 @@ pub async fn patch(
     let mut req = client.patch(&url);
     req = apply_auth(req, cfg)?;
     req = req.json(body);
-    send(req).await
+    send(cfg, req).await
 }
 
 /// Perform a DELETE request.
@@ -60,7 +79,23 @@ pub async fn delete(cfg: &Config, path: &str) -> Result<serde_json::Value> {
     let client = reqwest::Client::new();
     let mut req = client.delete(&url);
     req = apply_auth(req, cfg)?;
-    send(req).await
+    send(cfg, req).await
+}
+
+/// Perform a DELETE request with a JSON body. Only CLI commands use it, so the
+/// browser library leaves it out.
+#[cfg(not(feature = "browser"))]
+pub async fn delete_with_body(
+    cfg: &Config,
+    path: &str,
+    body: &serde_json::Value,
+) -> Result<serde_json::Value> {
+    let url = format!("{}{}", cfg.api_base_url(), path);
+    let client = reqwest::Client::new();
+    let mut req = client.delete(&url);
+    req = apply_auth(req, cfg)?;
+    req = req.json(body);
+    send(cfg, req).await
 }
 
 fn apply_auth(req: reqwest::RequestBuilder, cfg: &Config) -> Result<reqwest::RequestBuilder> {
@@ -78,17 +113,26 @@ fn apply_auth(req: reqwest::RequestBuilder, cfg: &Config) -> Result<reqwest::Req
     }
 }
 
-async fn send(req: reqwest::RequestBuilder) -> Result<serde_json::Value> {
+async fn send(cfg: &Config, req: reqwest::RequestBuilder) -> Result<serde_json::Value> {
+    send_allowing(cfg, req, &[]).await
+}
+
+async fn send_allowing(
+    cfg: &Config,
+    req: reqwest::RequestBuilder,
+    allowed: &[u16],
+) -> Result<serde_json::Value> {
     let resp = req
         .send()
         .await
         .map_err(|e| anyhow::anyhow!("HTTP request failed: {e}"))?;
+    warn_on_rate_limit(cfg, resp.headers());
     let status = resp.status();
     let body = resp
         .text()
         .await
         .map_err(|e| anyhow::anyhow!("failed to read response body: {e}"))?;
-    if !status.is_success() {
+    if !status.is_success() && !allowed.contains(&status.as_u16()) {
         bail!("API error (HTTP {status}): {body}");
     }
     if body.is_empty() {
@@ -96,3 +140,144 @@ async fn send(req: reqwest::RequestBuilder) -> Result<serde_json::Value> {
     }
     serde_json::from_str(&body).map_err(|e| anyhow::anyhow!("failed to parse JSON response: {e}"))
 }
+
+// ---------------------------------------------------------------------------
+// Rate-limit tracking
+// ---------------------------------------------------------------------------
+
+/// Rate-limit state reported by the `X-RateLimit-*` response headers.
+#[derive(Debug, PartialEq)]
+pub(crate) struct RateLimit {
+    pub limit: u64,
+    pub remaining: u64,
+    /// Seconds until the current rate-limit window resets.
+    pub reset_secs: u64,
+}
+
+impl RateLimit {
+    /// Parse the rate-limit headers. Returns `None` unless both `X-RateLimit-Limit`
+    /// and `X-RateLimit-Remaining` are present and numeric.
+    pub(crate) fn from_headers(headers: &reqwest::header::HeaderMap) -> Option<Self> {
+        let num =
+            |name: &str| -> Option<u64> { headers.get(name)?.to_str().ok()?.trim().parse().ok() };
+        Some(RateLimit {
+            limit: num("x-ratelimit-limit")?,
+            remaining: num("x-ratelimit-remaining")?,
+            reset_secs: num("x-ratelimit-reset").unwrap_or(0),
+        })
+    }
+
+    /// True when fewer than 10% of the window's requests remain.
+    pub(crate) fn is_low(&self) -> bool {
+        self.limit > 0 && self.remaining * 10 < self.limit
+    }
+}
+
+/// Format the wall-clock time at which a window resetting in `reset_secs` ends.
+#[cfg(not(feature = "browser"))]
+fn reset_time(reset_secs: u64) -> String {
+    let at = chrono::Local::now() + chrono::Duration::seconds(reset_secs as i64);
+    at.format("%H:%M:%S").to_string()
+}
+
+#[cfg(feature = "browser")]
+fn reset_time(reset_secs: u64) -> String {
+    format!("+{reset_secs}s")
+}
+
+pub(crate) fn rate_limit_warning(rl: &RateLimit, resets_at: &str) -> Option<String> {
+    if !rl.is_low() {
+        return None;
+    }
+    Some(format!(
+        "Rate limit warning: {} requests remaining, resets at {resets_at}",
+        rl.remaining
+    ))
+}
+
+/// Print a rate-limit warning to stderr when the response headers show the limit
+/// is nearly exhausted, unless disabled with `--no-rate-limit-warning`.
+pub(crate) fn warn_on_rate_limit(cfg: &Config, headers: &reqwest::header::HeaderMap) {
+    if cfg.rate_limit_warning {
+        print_rate_limit_warning(headers);
+    }
+}
+
+/// Number of rate-limit warnings printed, so tests can observe the warning.
+#[cfg(test)]
+pub(crate) static RATE_LIMIT_WARNINGS: std::sync::atomic::AtomicUsize =
+    std::sync::atomic::AtomicUsize::new(0);
+
+/// Print a rate-limit warning to stderr if the headers show the limit is low.
+pub(crate) fn print_rate_limit_warning(headers: &reqwest::header::HeaderMap) {
+    if let Some(rl) = RateLimit::from_headers(headers) {
+        if let Some(msg) = rate_limit_warning(&rl, &reset_time(rl.reset_secs)) {
+            eprintln!("{msg}");
+            #[cfg(test)]
+            RATE_LIMIT_WARNINGS.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::{HeaderMap, HeaderValue};
+
+    fn headers(pairs: &[(&'static str, &'static str)]) -> HeaderMap {
+        let mut h = HeaderMap::new();
+        for (k, v) in pairs {
+            h.insert(*k, HeaderValue::from_static(v));
+        }
+        h
+    }
+
+    #[test]
+    fn test_rate_limit_from_headers() {
+        let h = headers(&[
+            ("x-ratelimit-limit", "100"),
+            ("x-ratelimit-remaining", "5"),
+            ("x-ratelimit-reset", "30"),
+        ]);
+        assert_eq!(
+            RateLimit::from_headers(&h),
+            Some(RateLimit {
+                limit: 100,
+                remaining: 5,
+                reset_secs: 30
+            })
+        );
+    }
+
+    #[test]
+    fn test_rate_limit_from_headers_missing() {
+        assert_eq!(RateLimit::from_headers(&HeaderMap::new()), None);
+        let h = headers(&[("x-ratelimit-remaining", "5")]);
+        assert_eq!(RateLimit::from_headers(&h), None);
+    }
+
+    #[test]
+    fn test_rate_limit_warning_threshold() {
+        let low = RateLimit {
+            limit: 100,
+            remaining: 9,
+            reset_secs: 10,
+        };
+        assert_eq!(
+            rate_limit_warning(&low, "12:00:10").as_deref(),
+            Some("Rate limit warning: 9 requests remaining, resets at 12:00:10")
+        );
+        let ok = RateLimit {
+            limit: 100,
+            remaining: 10,
+            reset_secs: 10,
+        };
+        assert_eq!(rate_limit_warning(&ok, "12:00:10"), None);
+        let zero = RateLimit {
+            limit: 0,
+            remaining: 0,
+            reset_secs: 0,
+        };
+        assert_eq!(rate_limit_warning(&zero, ""), None);
+    }
+}
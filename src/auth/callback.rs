@@ -121,18 +121,13 @@ async fn accept_loop(
         }
 
         let query_string = parts[1].split('?').nth(1).unwrap_or("");
-        let params: std::collections::HashMap<String, String> =
-            url::form_urlencoded::parse(query_string.as_bytes())
-                .map(|(k, v)| (k.to_string(), v.to_string()))
-                .collect();
-
-        let code = params.get("code").cloned().unwrap_or_default();
-        let state = params.get("state").cloned().unwrap_or_default();
-        let error = params.get("error").cloned();
-        let error_description = params.get("error_description").cloned();
-
-        let (status, body) = if error.is_some() {
-            ("400 Bad Request", error_page(&error, &error_description))
+        let result = callback_result_from_query(query_string);
+
+        let (status, body) = if result.error.is_some() {
+            (
+                "400 Bad Request",
+                error_page(&result.error, &result.error_description),
+            )
         } else {
             ("200 OK", success_page())
         };
@@ -141,13 +136,6 @@ async fn accept_loop(
             body.len()
         );
         let _ = stream.write_all(response.as_bytes()).await;
-
-        let result = CallbackResult {
-            code,
-            state,
-            error,
-            error_description,
-        };
         if let Some(tx) = result_tx.lock().unwrap().take() {
             let _ = tx.send(result);
         }
@@ -155,6 +143,38 @@ async fn accept_loop(
     }
 }
 
+#[cfg(not(target_arch = "wasm32"))]
+fn callback_result_from_query(query_string: &str) -> CallbackResult {
+    let params: std::collections::HashMap<String, String> =
+        url::form_urlencoded::parse(query_string.as_bytes())
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+    CallbackResult {
+        code: params.get("code").cloned().unwrap_or_default(),
+        state: params.get("state").cloned().unwrap_or_default(),
+        error: params.get("error").cloned(),
+        error_description: params.get("error_description").cloned(),
+    }
+}
+
+/// Parse a redirect URL pasted by the user (for `--no-browser` logins, where the
+/// browser runs on another machine and cannot reach the callback server).
+/// Accepts the full `http://127.0.0.1:<port>/oauth/callback?...` URL or just its
+/// query string.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn parse_pasted_callback(input: &str) -> Result<CallbackResult> {
+    let input = input.trim();
+    let query_string = match input.split_once('?') {
+        Some((_, q)) => q,
+        None => input,
+    };
+    let result = callback_result_from_query(query_string);
+    if result.error.is_none() && (result.code.is_empty() || result.state.is_empty()) {
+        bail!("pasted URL has no code and state parameters; copy the full redirect URL from the browser's address bar");
+    }
+    Ok(result)
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 fn success_page() -> String {
     r#"<!DOCTYPE html>
@@ -181,3 +201,34 @@ h1{{color:#c00}}p{{color:#555}}</style></head>
 <p>Please close this window and try again.</p></div></body></html>"#
     )
 }
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_pasted_callback_full_url() {
+        let r = parse_pasted_callback("http://127.0.0.1:8000/oauth/callback?code=abc&state=xyz\n")
+            .unwrap();
+        assert_eq!(r.code, "abc");
+        assert_eq!(r.state, "xyz");
+        assert!(r.error.is_none());
+    }
+
+    #[test]
+    fn test_parse_pasted_callback_query_only() {
+        let r = parse_pasted_callback("code=abc&state=xyz").unwrap();
+        assert_eq!(r.code, "abc");
+    }
+
+    #[test]
+    fn test_parse_pasted_callback_error() {
+        let r = parse_pasted_callback("?error=access_denied&error_description=denied").unwrap();
+        assert_eq!(r.error.as_deref(), Some("access_denied"));
+    }
+
+    #[test]
+    fn test_parse_pasted_callback_missing_params() {
+        assert!(parse_pasted_callback("abc123").is_err());
+    }
+}
@@ -26,6 +26,7 @@ pub fn get_redirect_uris() -> Vec<String> {
 /// DCR + token exchange client.
 pub struct DcrClient {
     site: String,
+    api_base: String,
     http: reqwest::Client,
 }
 
@@ -60,8 +61,15 @@ struct TokenResponse {
 #[cfg(not(target_arch = "wasm32"))]
 impl DcrClient {
     pub fn new(site: &str) -> Self {
+        Self::with_api_base(site, &format!("https://api.{site}"))
+    }
+
+    /// Like `new`, but sends API requests to `api_base` instead of
+    /// `https://api.{site}` (used to point the client at a mock server).
+    pub fn with_api_base(site: &str, api_base: &str) -> Self {
         Self {
             site: site.to_string(),
+            api_base: api_base.trim_end_matches('/').to_string(),
             http: reqwest::Client::builder()
                 .timeout(std::time::Duration::from_secs(30))
                 .build()
@@ -75,7 +83,7 @@ impl DcrClient {
         redirect_uri: &str,
         _scopes: &[&str],
     ) -> Result<ClientCredentials> {
-        let url = format!("https://api.{}/api/v2/oauth2/register", self.site);
+        let url = format!("{}/api/v2/oauth2/register", self.api_base);
 
         let body = RegistrationRequest {
             client_name: DCR_CLIENT_NAME.to_string(),
@@ -147,7 +155,7 @@ impl DcrClient {
     }
 
     async fn request_tokens(&self, params: &[(&str, &str)], client_id: &str) -> Result<TokenSet> {
-        let url = format!("https://api.{}/oauth2/v1/token", self.site);
+        let url = format!("{}/oauth2/v1/token", self.api_base);
 
         // Filter out empty params
         let form_params: Vec<(&str, &str)> = params
@@ -186,6 +194,37 @@ impl DcrClient {
         })
     }
 
+    /// Revoke a token (RFC 7009). Revoking the refresh token also invalidates
+    /// access tokens issued from it.
+    pub async fn revoke_token(
+        &self,
+        token: &str,
+        token_type_hint: &str,
+        creds: &ClientCredentials,
+    ) -> Result<()> {
+        let url = format!("{}/oauth2/v1/revoke", self.api_base);
+        let params = [
+            ("token", token),
+            ("token_type_hint", token_type_hint),
+            ("client_id", creds.client_id.as_str()),
+        ];
+
+        let resp = self
+            .http
+            .post(&url)
+            .form(&params)
+            .send()
+            .await
+            .context("token revocation request failed")?;
+
+        if !resp.status().is_success() {
+            let status = resp.status();
+            let body = resp.text().await.unwrap_or_default();
+            bail!("token revocation failed (HTTP {status}): {body}");
+        }
+        Ok(())
+    }
+
     /// Build the authorization URL for the browser.
     pub fn build_authorization_url(
         &self,
@@ -208,3 +247,58 @@ impl DcrClient {
         format!("https://app.{}/oauth2/v1/authorize?{params}", self.site)
     }
 }
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+
+    fn creds() -> ClientCredentials {
+        ClientCredentials {
+            client_id: "client-123".into(),
+            client_name: DCR_CLIENT_NAME.into(),
+            redirect_uris: vec![],
+            registered_at: 0,
+            site: "datadoghq.com".into(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_revoke_token_success() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/oauth2/v1/revoke")
+            .match_body(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("token".into(), "tok".into()),
+                mockito::Matcher::UrlEncoded("token_type_hint".into(), "access_token".into()),
+                mockito::Matcher::UrlEncoded("client_id".into(), "client-123".into()),
+            ]))
+            .with_status(200)
+            .create_async()
+            .await;
+
+        let client = DcrClient::with_api_base("datadoghq.com", &server.url());
+        let result = client.revoke_token("tok", "access_token", &creds()).await;
+        assert!(result.is_ok(), "revoke failed: {:?}", result.err());
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_revoke_token_failure() {
+        let mut server = mockito::Server::new_async().await;
+        let _mock = server
+            .mock("POST", "/oauth2/v1/revoke")
+            .with_status(503)
+            .with_body("unavailable")
+            .create_async()
+            .await;
+
+        let client = DcrClient::with_api_base("datadoghq.com", &server.url());
+        let err = client
+            .revoke_token("tok", "access_token", &creds())
+            .await
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("HTTP 503"), "{err}");
+        assert!(err.contains("unavailable"), "{err}");
+    }
+}
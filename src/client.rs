@@ -33,6 +33,28 @@ impl Middleware for BearerAuthMiddleware {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Rate-limit warning middleware (native only)
+// ---------------------------------------------------------------------------
+
+#[cfg(not(target_arch = "wasm32"))]
+struct RateLimitWarningMiddleware;
+
+#[cfg(not(target_arch = "wasm32"))]
+#[async_trait]
+impl Middleware for RateLimitWarningMiddleware {
+    async fn handle(
+        &self,
+        req: reqwest::Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<reqwest::Response> {
+        let resp = next.run(req, extensions).await?;
+        crate::api::print_rate_limit_warning(resp.headers());
+        Ok(resp)
+    }
+}
+
 // ---------------------------------------------------------------------------
 // DD Configuration builder (native only)
 // ---------------------------------------------------------------------------
@@ -45,7 +67,7 @@ impl Middleware for BearerAuthMiddleware {
 pub fn make_dd_config(_cfg: &Config) -> datadog_api_client::datadog::Configuration {
     let mut dd_cfg = datadog_api_client::datadog::Configuration::new();
 
-    // Enable all 63 unstable operations (snake_case in Rust client)
+    // Enable all 66 unstable operations (snake_case in Rust client)
     for op in UNSTABLE_OPS {
         dd_cfg.set_unstable_operation_enabled(op, true);
     }
@@ -71,20 +93,27 @@ pub fn make_dd_config(_cfg: &Config) -> datadog_api_client::datadog::Configurati
     dd_cfg
 }
 
-/// Creates a reqwest middleware client with bearer token injection.
-/// Returns None if no bearer token is configured.
+/// Creates the reqwest middleware client for the typed DD API clients: injects
+/// the bearer token when one is configured and warns when the rate limit runs
+/// low. Returns None if neither is needed (the DD client's default is used).
 #[cfg(not(target_arch = "wasm32"))]
 pub fn make_bearer_client(cfg: &Config) -> Option<ClientWithMiddleware> {
-    let token = cfg.access_token.as_ref()?;
+    if cfg.access_token.is_none() && !cfg.rate_limit_warning {
+        return None;
+    }
     let reqwest_client = reqwest::Client::builder()
         .build()
         .expect("failed to build reqwest client");
-    let client = ClientBuilder::new(reqwest_client)
-        .with(BearerAuthMiddleware {
+    let mut builder = ClientBuilder::new(reqwest_client);
+    if let Some(token) = &cfg.access_token {
+        builder = builder.with(BearerAuthMiddleware {
             token: token.clone(),
-        })
-        .build();
-    Some(client)
+        });
+    }
+    if cfg.rate_limit_warning {
+        builder = builder.with(RateLimitWarningMiddleware);
+    }
+    Some(builder.build())
 }
 
 // ---------------------------------------------------------------------------
@@ -92,9 +121,9 @@ pub fn make_bearer_client(cfg: &Config) -> Option<ClientWithMiddleware> {
 // ---------------------------------------------------------------------------
 
 #[cfg(not(target_arch = "wasm32"))]
-/// All 63 unstable operations (snake_case for the Rust DD client).
+/// All 66 unstable operations (snake_case for the Rust DD client).
 static UNSTABLE_OPS: &[&str] = &[
-    // Incidents (16)
+    // Incidents (18)
     "v2.list_incidents",
     "v2.get_incident",
     "v2.create_incident",
@@ -111,6 +140,8 @@ static UNSTABLE_OPS: &[&str] = &[
     "v2.get_incident_postmortem_template",
     "v2.list_incident_postmortem_templates",
     "v2.update_incident_postmortem_template",
+    "v2.list_incident_integrations",
+    "v2.create_incident_integration",
     // Fleet Automation (14)
     "v2.list_fleet_agents",
     "v2.get_fleet_agent_info",
@@ -471,12 +502,18 @@ static OAUTH_EXCLUDED_ENDPOINTS: &[EndpointRequirement] = &[
 // Raw HTTP helpers (native only)
 // ---------------------------------------------------------------------------
 
-/// Makes an authenticated GET request directly via reqwest.
+/// Makes an authenticated request directly via reqwest.
 /// Used for endpoints not covered by the typed DD API client.
-pub async fn raw_get(cfg: &Config, path: &str) -> anyhow::Result<serde_json::Value> {
+/// Empty response bodies (e.g. HTTP 204) are returned as `{}`.
+pub async fn raw_request(
+    cfg: &Config,
+    method: reqwest::Method,
+    path: &str,
+    body: Option<serde_json::Value>,
+) -> anyhow::Result<serde_json::Value> {
     let url = format!("{}{}", cfg.api_base_url(), path);
     let client = reqwest::Client::new();
-    let mut req = client.get(&url);
+    let mut req = client.request(method, &url);
 
     if let Some(token) = &cfg.access_token {
         req = req.header("Authorization", format!("Bearer {token}"));
@@ -488,13 +525,29 @@ pub async fn raw_get(cfg: &Config, path: &str) -> anyhow::Result<serde_json::Val
         anyhow::bail!("no authentication configured");
     }
 
-    let resp = req.header("Accept", "application/json").send().await?;
+    req = req.header("Accept", "application/json");
+    if let Some(body) = body {
+        req = req.header("Content-Type", "application/json").json(&body);
+    }
+
+    let resp = req.send().await?;
+    crate::api::warn_on_rate_limit(cfg, resp.headers());
     if !resp.status().is_success() {
         let status = resp.status();
         let body = resp.text().await.unwrap_or_default();
         anyhow::bail!("API error (HTTP {status}): {body}");
     }
-    Ok(resp.json().await?)
+    let text = resp.text().await?;
+    if text.trim().is_empty() {
+        return Ok(serde_json::json!({}));
+    }
+    Ok(serde_json::from_str(&text)?)
+}
+
+/// Makes an authenticated GET request directly via reqwest.
+/// Used for endpoints not covered by the typed DD API client.
+pub async fn raw_get(cfg: &Config, path: &str) -> anyhow::Result<serde_json::Value> {
+    raw_request(cfg, reqwest::Method::GET, path, None).await
 }
 
 /// Makes an authenticated POST request directly via reqwest.
@@ -504,32 +557,23 @@ pub async fn raw_post(
     path: &str,
     body: serde_json::Value,
 ) -> anyhow::Result<serde_json::Value> {
-    let url = format!("{}{}", cfg.api_base_url(), path);
-    let client = reqwest::Client::new();
-    let mut req = client.post(&url);
+    raw_request(cfg, reqwest::Method::POST, path, Some(body)).await
+}
 
-    if let Some(token) = &cfg.access_token {
-        req = req.header("Authorization", format!("Bearer {token}"));
-    } else if let (Some(api_key), Some(app_key)) = (&cfg.api_key, &cfg.app_key) {
-        req = req
-            .header("DD-API-KEY", api_key.as_str())
-            .header("DD-APPLICATION-KEY", app_key.as_str());
-    } else {
-        anyhow::bail!("no authentication configured");
-    }
+/// Makes an authenticated PATCH request directly via reqwest.
+/// Used for endpoints not covered by the typed DD API client.
+pub async fn raw_patch(
+    cfg: &Config,
+    path: &str,
+    body: serde_json::Value,
+) -> anyhow::Result<serde_json::Value> {
+    raw_request(cfg, reqwest::Method::PATCH, path, Some(body)).await
+}
 
-    let resp = req
-        .header("Content-Type", "application/json")
-        .header("Accept", "application/json")
-        .json(&body)
-        .send()
-        .await?;
-    if !resp.status().is_success() {
-        let status = resp.status();
-        let body = resp.text().await.unwrap_or_default();
-        anyhow::bail!("API error (HTTP {status}): {body}");
-    }
-    Ok(resp.json().await?)
+/// Makes an authenticated DELETE request directly via reqwest.
+/// Used for endpoints not covered by the typed DD API client.
+pub async fn raw_delete(cfg: &Config, path: &str) -> anyhow::Result<serde_json::Value> {
+    raw_request(cfg, reqwest::Method::DELETE, path, None).await
 }
 
 #[cfg(test)]
@@ -547,6 +591,8 @@ mod tests {
             output_format: crate::config::OutputFormat::Json,
             auto_approve: false,
             agent_mode: false,
+            table_max_width: None,
+            rate_limit_warning: false,
         }
     }
 
@@ -632,7 +678,7 @@ mod tests {
 
     #[test]
     fn test_unstable_ops_count() {
-        assert_eq!(UNSTABLE_OPS.len(), 64);
+        assert_eq!(UNSTABLE_OPS.len(), 66);
     }
 
     #[test]
@@ -653,6 +699,13 @@ mod tests {
         assert!(make_bearer_client(&cfg).is_some());
     }
 
+    #[test]
+    fn test_make_bearer_client_some_with_rate_limit_warning() {
+        let mut cfg = test_cfg();
+        cfg.rate_limit_warning = true;
+        assert!(make_bearer_client(&cfg).is_some());
+    }
+
     #[test]
     fn test_make_dd_config_returns_valid() {
         let _guard = ENV_LOCK.lock().unwrap_or_else(|p| p.into_inner());
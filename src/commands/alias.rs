@@ -28,16 +28,22 @@ fn save_aliases(aliases: &BTreeMap<String, String>) -> Result<()> {
     Ok(())
 }
 
-pub fn list() -> Result<()> {
+#[derive(serde::Serialize)]
+struct AliasEntry {
+    name: String,
+    command: String,
+}
+
+fn alias_entries(aliases: BTreeMap<String, String>) -> Vec<AliasEntry> {
+    aliases
+        .into_iter()
+        .map(|(name, command)| AliasEntry { name, command })
+        .collect()
+}
+
+pub fn list(cfg: &config::Config) -> Result<()> {
     let aliases = load_aliases()?;
-    if aliases.is_empty() {
-        println!("No aliases configured.");
-        return Ok(());
-    }
-    for (name, command) in &aliases {
-        println!("{name} = {command}");
-    }
-    Ok(())
+    crate::formatter::output(cfg, &alias_entries(aliases))
 }
 
 pub fn set(name: String, command: String) -> Result<()> {
@@ -86,3 +92,29 @@ pub fn import(file: &str) -> Result<()> {
     println!("Imported {count} alias(es) from {file}.");
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_alias_entries_sorted_name_command() {
+        let mut aliases = BTreeMap::new();
+        aliases.insert(
+            "prod-errors".to_string(),
+            "logs search --query=status:error".to_string(),
+        );
+        aliases.insert(
+            "cpu".to_string(),
+            "metrics query --query=avg:system.cpu.user{*}".to_string(),
+        );
+        let value = serde_json::to_value(alias_entries(aliases)).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!([
+                { "name": "cpu", "command": "metrics query --query=avg:system.cpu.user{*}" },
+                { "name": "prod-errors", "command": "logs search --query=status:error" },
+            ])
+        );
+    }
+}
@@ -1,4 +1,7 @@
+use std::collections::HashMap;
+
 use anyhow::Result;
+use serde_json::Value;
 
 use crate::client;
 use crate::config::Config;
@@ -67,17 +70,37 @@ pub async fn entities_list(cfg: &Config, from: String, to: String) -> Result<()>
     crate::formatter::output(cfg, &data)
 }
 
+pub async fn entities_search(
+    cfg: &Config,
+    query: String,
+    from: String,
+    to: String,
+    limit: i32,
+) -> Result<()> {
+    let from_ts = util::parse_time_to_unix(&from)?;
+    let to_ts = util::parse_time_to_unix(&to)?;
+    let params = vec![
+        ("start", from_ts.to_string()),
+        ("end", to_ts.to_string()),
+        ("filter[query]", query),
+        ("limit", limit.to_string()),
+    ];
+    let data = crate::api::get(cfg, "/api/unstable/apm/entities", &params)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to search APM entities: {e:?}"))?;
+    formatter::output(cfg, &data)
+}
+
 #[cfg(not(target_arch = "wasm32"))]
-pub async fn dependencies_list(cfg: &Config, env: String, from: String, to: String) -> Result<()> {
+async fn fetch_dependencies(cfg: &Config, env: String, from: String, to: String) -> Result<Value> {
     let from_ts = util::parse_time_to_unix(&from)?;
     let to_ts = util::parse_time_to_unix(&to)?;
     let path = format!("/api/v1/service_dependencies?start={from_ts}&end={to_ts}&env={env}");
-    let data = client::raw_get(cfg, &path).await?;
-    formatter::output(cfg, &data)
+    client::raw_get(cfg, &path).await
 }
 
 #[cfg(target_arch = "wasm32")]
-pub async fn dependencies_list(cfg: &Config, env: String, from: String, to: String) -> Result<()> {
+async fn fetch_dependencies(cfg: &Config, env: String, from: String, to: String) -> Result<Value> {
     let from_ts = util::parse_time_to_unix(&from)?;
     let to_ts = util::parse_time_to_unix(&to)?;
     let query = vec![
@@ -85,8 +108,81 @@ pub async fn dependencies_list(cfg: &Config, env: String, from: String, to: Stri
         ("end", to_ts.to_string()),
         ("env", env),
     ];
-    let data = crate::api::get(cfg, "/api/v1/service_dependencies", &query).await?;
-    crate::formatter::output(cfg, &data)
+    crate::api::get(cfg, "/api/v1/service_dependencies", &query).await
+}
+
+pub async fn dependencies_list(cfg: &Config, env: String, from: String, to: String) -> Result<()> {
+    let data = fetch_dependencies(cfg, env, from, to).await?;
+    formatter::output(cfg, &data)
+}
+
+/// Restricts a service dependency map (`{"svc": {"calls": [...]}}`) to the
+/// edges within `depth` hops of `service`, following both callers and
+/// callees. Returns `None` when the service does not appear in the map.
+fn dependency_subgraph(data: &Value, service: &str, depth: u32) -> Option<Value> {
+    let map = data.as_object()?;
+    let edges: Vec<(&str, &str)> = map
+        .iter()
+        .flat_map(|(from, node)| {
+            node["calls"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(|to| to.as_str())
+                .map(move |to| (from.as_str(), to))
+        })
+        .collect();
+    if !map.contains_key(service) && !edges.iter().any(|(_, to)| *to == service) {
+        return None;
+    }
+
+    let mut distance: HashMap<&str, u32> = HashMap::from([(service, 0)]);
+    let mut frontier = vec![service];
+    for hop in 1..=depth {
+        let mut next = Vec::new();
+        for &(from, to) in &edges {
+            for (near, far) in [(from, to), (to, from)] {
+                if frontier.contains(&near) && !distance.contains_key(far) {
+                    distance.insert(far, hop);
+                    next.push(far);
+                }
+            }
+        }
+        frontier = next;
+    }
+
+    // Keep an edge when both ends are in range and at least one end is
+    // closer than the depth limit, so the outermost ring is not cross-linked.
+    let mut sub = serde_json::Map::new();
+    for &(from, to) in &edges {
+        let (Some(&df), Some(&dt)) = (distance.get(from), distance.get(to)) else {
+            continue;
+        };
+        if df.min(dt) < depth {
+            let calls = sub
+                .entry(from.to_string())
+                .or_insert_with(|| serde_json::json!({"calls": []}));
+            calls["calls"].as_array_mut()?.push(Value::from(to));
+        }
+    }
+    sub.entry(service.to_string())
+        .or_insert_with(|| serde_json::json!({"calls": []}));
+    Some(Value::Object(sub))
+}
+
+pub async fn dependencies_for_service(
+    cfg: &Config,
+    service: String,
+    env: String,
+    from: String,
+    to: String,
+    depth: Option<u32>,
+) -> Result<()> {
+    let data = fetch_dependencies(cfg, env.clone(), from, to).await?;
+    let Some(graph) = dependency_subgraph(&data, &service, depth.unwrap_or(1)) else {
+        anyhow::bail!("service {service} not found in the dependency map for env {env}");
+    };
+    formatter::output(cfg, &graph)
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -165,6 +261,46 @@ pub async fn services_resources(
     crate::formatter::output(cfg, &data)
 }
 
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn services_all_resources(
+    cfg: &Config,
+    service: String,
+    env: String,
+    from: String,
+    to: String,
+    limit: i32,
+) -> Result<()> {
+    let from_ts = util::parse_time_to_unix(&from)?;
+    let to_ts = util::parse_time_to_unix(&to)?;
+    let path = format!(
+        "/api/ui/apm/resources?service={service}&env={env}&start={from_ts}&end={to_ts}&limit={limit}"
+    );
+    let data = client::raw_get(cfg, &path).await?;
+    formatter::output(cfg, &data)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn services_all_resources(
+    cfg: &Config,
+    service: String,
+    env: String,
+    from: String,
+    to: String,
+    limit: i32,
+) -> Result<()> {
+    let from_ts = util::parse_time_to_unix(&from)?;
+    let to_ts = util::parse_time_to_unix(&to)?;
+    let query = vec![
+        ("service", service),
+        ("env", env),
+        ("start", from_ts.to_string()),
+        ("end", to_ts.to_string()),
+        ("limit", limit.to_string()),
+    ];
+    let data = crate::api::get(cfg, "/api/ui/apm/resources", &query).await?;
+    crate::formatter::output(cfg, &data)
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 pub async fn flow_map(
     cfg: &Config,
@@ -200,3 +336,53 @@ pub async fn flow_map(
     let data = crate::api::get(cfg, "/api/ui/apm/flow-map", &q).await?;
     crate::formatter::output(cfg, &data)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_map() -> Value {
+        serde_json::json!({
+            "web": {"calls": ["checkout", "search"]},
+            "checkout": {"calls": ["payments", "postgres"]},
+            "payments": {"calls": ["stripe-proxy"]},
+            "search": {"calls": ["elasticsearch"]},
+            "batch": {"calls": ["postgres"]},
+        })
+    }
+
+    #[test]
+    fn test_dependency_subgraph_direct_edges() {
+        let sub = dependency_subgraph(&sample_map(), "checkout", 1).unwrap();
+        assert_eq!(
+            sub,
+            serde_json::json!({
+                "web": {"calls": ["checkout"]},
+                "checkout": {"calls": ["payments", "postgres"]},
+            })
+        );
+    }
+
+    #[test]
+    fn test_dependency_subgraph_transitive() {
+        let sub = dependency_subgraph(&sample_map(), "checkout", 2).unwrap();
+        assert_eq!(
+            sub["web"]["calls"],
+            serde_json::json!(["checkout", "search"])
+        );
+        assert_eq!(
+            sub["payments"]["calls"],
+            serde_json::json!(["stripe-proxy"])
+        );
+        assert_eq!(sub["batch"]["calls"], serde_json::json!(["postgres"]));
+        assert!(sub.get("search").is_none());
+    }
+
+    #[test]
+    fn test_dependency_subgraph_leaf_and_missing() {
+        let sub = dependency_subgraph(&sample_map(), "postgres", 1).unwrap();
+        assert_eq!(sub["postgres"]["calls"], serde_json::json!([]));
+        assert_eq!(sub["batch"]["calls"], serde_json::json!(["postgres"]));
+        assert!(dependency_subgraph(&sample_map(), "nope", 3).is_none());
+    }
+}
@@ -15,7 +15,7 @@ where
 }
 
 #[cfg(not(target_arch = "wasm32"))]
-pub async fn login(cfg: &Config) -> Result<()> {
+pub async fn login(cfg: &Config, no_browser: bool) -> Result<()> {
     use crate::auth::{dcr, pkce, types};
 
     let site = &cfg.site;
@@ -60,16 +60,31 @@ pub async fn login(cfg: &Config) -> Result<()> {
         &scopes,
     );
 
-    // 5. Open browser
-    eprintln!("\n🌐 Opening browser for authentication...");
-    eprintln!("If the browser doesn't open, visit: {auth_url}");
-    let _ = open::that(&auth_url);
-
-    // 6. Wait for callback
-    eprintln!("\n⏳ Waiting for authorization...");
-    let result = server
-        .wait_for_callback(std::time::Duration::from_secs(300))
-        .await?;
+    // 5-6. Open browser and wait for callback, or have the user paste the
+    // redirect URL back when the browser runs on another machine.
+    let result = if no_browser {
+        eprintln!("\n🌐 Visit this URL in a browser to authenticate:");
+        println!("{auth_url}");
+        eprintln!(
+            "\nAfter approving, your browser is redirected to {redirect_uri}?... which will \
+             fail to load. Copy that full URL from the address bar and paste it here:"
+        );
+        let line = tokio::task::spawn_blocking(|| {
+            let mut line = String::new();
+            std::io::stdin().read_line(&mut line).map(|_| line)
+        })
+        .await??;
+        crate::auth::callback::parse_pasted_callback(&line)?
+    } else {
+        eprintln!("\n🌐 Opening browser for authentication...");
+        eprintln!("If the browser doesn't open, visit: {auth_url}");
+        let _ = open::that(&auth_url);
+
+        eprintln!("\n⏳ Waiting for authorization...");
+        server
+            .wait_for_callback(std::time::Duration::from_secs(300))
+            .await?
+    };
 
     if let Some(err) = &result.error {
         let desc = result.error_description.as_deref().unwrap_or("");
@@ -107,7 +122,7 @@ pub async fn login(cfg: &Config) -> Result<()> {
 }
 
 #[cfg(target_arch = "wasm32")]
-pub async fn login(_cfg: &Config) -> Result<()> {
+pub async fn login(_cfg: &Config, _no_browser: bool) -> Result<()> {
     bail!(
         "OAuth login is not available in WASM builds.\n\
          Use DD_ACCESS_TOKEN env var for bearer token auth,\n\
@@ -135,6 +150,98 @@ pub async fn logout(_cfg: &Config) -> Result<()> {
     )
 }
 
+/// Revokes the stored OAuth tokens server-side and removes them locally.
+/// If revocation fails the local credentials are kept so the command can be
+/// retried; `force` removes them anyway.
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn expire_token(cfg: &Config, force: bool) -> Result<()> {
+    use crate::auth::dcr;
+
+    let site = &cfg.site;
+
+    let tokens = with_storage(|store| store.load_tokens(site))?
+        .ok_or_else(|| anyhow::anyhow!("no tokens found for site {site} — nothing to expire"))?;
+    let creds = with_storage(|store| store.load_client_credentials(site))?;
+
+    let dcr_client = dcr::DcrClient::new(site);
+    let revoke_errors = revoke_tokens(&dcr_client, &tokens, creds.as_ref(), site).await;
+
+    with_storage(|store| finish_expire(store, site, &revoke_errors, force))
+}
+
+/// Revokes the refresh and access tokens, collecting every failure.
+#[cfg(not(target_arch = "wasm32"))]
+async fn revoke_tokens(
+    dcr_client: &crate::auth::dcr::DcrClient,
+    tokens: &crate::auth::types::TokenSet,
+    creds: Option<&crate::auth::types::ClientCredentials>,
+    site: &str,
+) -> Vec<anyhow::Error> {
+    let Some(creds) = creds else {
+        return vec![anyhow::anyhow!(
+            "no client credentials found for site {site}"
+        )];
+    };
+    let mut errors = Vec::new();
+    if !tokens.refresh_token.is_empty() {
+        if let Err(e) = dcr_client
+            .revoke_token(&tokens.refresh_token, "refresh_token", creds)
+            .await
+        {
+            errors.push(e);
+        }
+    }
+    if let Err(e) = dcr_client
+        .revoke_token(&tokens.access_token, "access_token", creds)
+        .await
+    {
+        errors.push(e);
+    }
+    errors
+}
+
+/// Clears local credentials once revocation succeeded (or `force` is set).
+/// On failure without `force` the credentials are left in place and an error
+/// is returned so the user can retry.
+#[cfg(not(target_arch = "wasm32"))]
+fn finish_expire(
+    store: &mut dyn storage::Storage,
+    site: &str,
+    revoke_errors: &[anyhow::Error],
+    force: bool,
+) -> Result<()> {
+    for e in revoke_errors {
+        eprintln!("⚠️  {e:#}");
+    }
+    if !revoke_errors.is_empty() && !force {
+        bail!(
+            "server-side revocation failed for site {site}; local credentials were kept \
+             so you can retry (use --force to remove them anyway)"
+        );
+    }
+
+    store.delete_tokens(site)?;
+    store.delete_client_credentials(site)?;
+
+    if revoke_errors.is_empty() {
+        eprintln!("✅ Token revoked for site: {site}. Local credentials removed.");
+    } else {
+        eprintln!(
+            "⚠️  Server-side revocation could not be confirmed for site: {site}. \
+             Local credentials removed (--force)."
+        );
+    }
+    Ok(())
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn expire_token(_cfg: &Config, _force: bool) -> Result<()> {
+    bail!(
+        "Token expiry is not available in WASM builds.\n\
+         Token storage is not available — credentials are read from environment variables."
+    )
+}
+
 pub fn status(cfg: &Config) -> Result<()> {
     let site = &cfg.site;
 
@@ -279,3 +386,129 @@ pub async fn refresh(_cfg: &Config) -> Result<()> {
          Use DD_ACCESS_TOKEN env var for bearer token auth."
     )
 }
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+    use crate::auth::dcr::DcrClient;
+    use crate::auth::types::{ClientCredentials, TokenSet};
+    use std::sync::Mutex;
+
+    /// In-memory store that only tracks whether tokens/credentials exist.
+    #[derive(Default)]
+    struct MemStore {
+        tokens: Mutex<Option<TokenSet>>,
+        creds: Mutex<Option<ClientCredentials>>,
+    }
+
+    impl storage::Storage for MemStore {
+        fn backend_type(&self) -> storage::BackendType {
+            storage::BackendType::File
+        }
+        fn storage_location(&self) -> String {
+            "memory".into()
+        }
+        fn save_tokens(&self, _site: &str, tokens: &TokenSet) -> Result<()> {
+            *self.tokens.lock().unwrap() = Some(tokens.clone());
+            Ok(())
+        }
+        fn load_tokens(&self, _site: &str) -> Result<Option<TokenSet>> {
+            Ok(self.tokens.lock().unwrap().clone())
+        }
+        fn delete_tokens(&self, _site: &str) -> Result<()> {
+            *self.tokens.lock().unwrap() = None;
+            Ok(())
+        }
+        fn save_client_credentials(&self, _site: &str, creds: &ClientCredentials) -> Result<()> {
+            *self.creds.lock().unwrap() = Some(creds.clone());
+            Ok(())
+        }
+        fn load_client_credentials(&self, _site: &str) -> Result<Option<ClientCredentials>> {
+            Ok(self.creds.lock().unwrap().clone())
+        }
+        fn delete_client_credentials(&self, _site: &str) -> Result<()> {
+            *self.creds.lock().unwrap() = None;
+            Ok(())
+        }
+    }
+
+    fn tokens() -> TokenSet {
+        TokenSet {
+            access_token: "access".into(),
+            refresh_token: "refresh".into(),
+            token_type: "Bearer".into(),
+            expires_in: 3600,
+            issued_at: 0,
+            scope: String::new(),
+            client_id: "client-123".into(),
+        }
+    }
+
+    fn creds() -> ClientCredentials {
+        ClientCredentials {
+            client_id: "client-123".into(),
+            client_name: "pup".into(),
+            redirect_uris: vec![],
+            registered_at: 0,
+            site: "datadoghq.com".into(),
+        }
+    }
+
+    fn seeded_store() -> MemStore {
+        let store = MemStore::default();
+        storage::Storage::save_tokens(&store, "datadoghq.com", &tokens()).unwrap();
+        storage::Storage::save_client_credentials(&store, "datadoghq.com", &creds()).unwrap();
+        store
+    }
+
+    async fn revoke_with_status(status: usize) -> Vec<anyhow::Error> {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/oauth2/v1/revoke")
+            .with_status(status)
+            .expect(2)
+            .create_async()
+            .await;
+        let client = DcrClient::with_api_base("datadoghq.com", &server.url());
+        let errors = revoke_tokens(&client, &tokens(), Some(&creds()), "datadoghq.com").await;
+        mock.assert_async().await;
+        errors
+    }
+
+    #[tokio::test]
+    async fn test_expire_token_revoke_success_clears_local_state() {
+        let errors = revoke_with_status(200).await;
+        assert!(errors.is_empty());
+
+        let mut store = seeded_store();
+        finish_expire(&mut store, "datadoghq.com", &errors, false).unwrap();
+        assert!(store.tokens.lock().unwrap().is_none());
+        assert!(store.creds.lock().unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_expire_token_revoke_failure_keeps_local_state() {
+        let errors = revoke_with_status(500).await;
+        assert_eq!(errors.len(), 2);
+
+        let mut store = seeded_store();
+        let err = finish_expire(&mut store, "datadoghq.com", &errors, false)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("--force"), "{err}");
+        assert!(store.tokens.lock().unwrap().is_some());
+        assert!(store.creds.lock().unwrap().is_some());
+
+        finish_expire(&mut store, "datadoghq.com", &errors, true).unwrap();
+        assert!(store.tokens.lock().unwrap().is_none());
+        assert!(store.creds.lock().unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_expire_token_without_credentials_is_an_error() {
+        let client = DcrClient::with_api_base("datadoghq.com", "http://127.0.0.1:1");
+        let errors = revoke_tokens(&client, &tokens(), None, "datadoghq.com").await;
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].to_string().contains("no client credentials"));
+    }
+}
@@ -368,6 +368,121 @@ pub async fn update_status(cfg: &Config, case_id: &str, status: &str) -> Result<
     crate::formatter::output(cfg, &data)
 }
 
+/// Read newline-separated case IDs from a file, skipping blank lines.
+pub fn read_case_ids_file(path: &str) -> Result<Vec<String>> {
+    let contents = std::fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("failed to read case IDs file {path}: {e}"))?;
+    Ok(contents
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .map(String::from)
+        .collect())
+}
+
+/// Print the per-case report and fail if any update did not succeed.
+fn report_bulk_status(cfg: &Config, results: Vec<(String, Result<()>)>) -> Result<()> {
+    let total = results.len();
+    let mut failed = 0;
+    let report: Vec<serde_json::Value> = results
+        .into_iter()
+        .map(|(case_id, res)| match res {
+            Ok(()) => serde_json::json!({ "case_id": case_id, "result": "updated" }),
+            Err(e) => {
+                failed += 1;
+                serde_json::json!({ "case_id": case_id, "result": "failed", "error": e.to_string() })
+            }
+        })
+        .collect();
+    formatter::output(cfg, &report)?;
+    if failed > 0 {
+        anyhow::bail!("{failed} of {total} case status updates failed");
+    }
+    Ok(())
+}
+
+/// Maximum number of status updates in flight during `bulk-update-status`.
+#[cfg(not(target_arch = "wasm32"))]
+const BULK_UPDATE_CONCURRENCY: usize = 8;
+
+#[cfg(not(target_arch = "wasm32"))]
+#[allow(deprecated)]
+pub async fn bulk_update_status(cfg: &Config, case_ids: Vec<String>, status: &str) -> Result<()> {
+    let status_val = match status.to_uppercase().as_str() {
+        "OPEN" => CaseStatus::OPEN,
+        "IN_PROGRESS" => CaseStatus::IN_PROGRESS,
+        "CLOSED" => CaseStatus::CLOSED,
+        _ => anyhow::bail!("invalid status: {status} (use OPEN, IN_PROGRESS, CLOSED)"),
+    };
+    if case_ids.is_empty() {
+        anyhow::bail!("no case IDs provided");
+    }
+    let api = make_api(cfg);
+    let body = CaseUpdateStatusRequest::new(CaseUpdateStatus::new(
+        CaseUpdateStatusAttributes::new().status(status_val),
+        CaseResourceType::CASE,
+    ));
+
+    let semaphore = std::sync::Arc::new(tokio::sync::Semaphore::new(BULK_UPDATE_CONCURRENCY));
+    let mut tasks = tokio::task::JoinSet::new();
+    for (idx, case_id) in case_ids.iter().enumerate() {
+        let (api, semaphore) = (api.clone(), semaphore.clone());
+        let body = body.clone();
+        let case_id = case_id.clone();
+        tasks.spawn(async move {
+            let res = match semaphore.acquire_owned().await {
+                Ok(_permit) => api
+                    .update_status(case_id, body)
+                    .await
+                    .map(|_| ())
+                    .map_err(|e| anyhow::anyhow!("failed to update case status: {e:?}")),
+                Err(e) => Err(e.into()),
+            };
+            (idx, res)
+        });
+    }
+
+    let mut outcomes: Vec<Option<Result<()>>> = case_ids.iter().map(|_| None).collect();
+    while let Some(joined) = tasks.join_next().await {
+        let (idx, res) = joined.map_err(|e| anyhow::anyhow!("case update task failed: {e}"))?;
+        outcomes[idx] = Some(res);
+    }
+    let results = case_ids
+        .into_iter()
+        .zip(outcomes)
+        .map(|(id, res)| (id, res.unwrap_or_else(|| Err(anyhow::anyhow!("no result")))))
+        .collect();
+    report_bulk_status(cfg, results)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn bulk_update_status(cfg: &Config, case_ids: Vec<String>, status: &str) -> Result<()> {
+    let s = status.to_uppercase();
+    match s.as_str() {
+        "OPEN" | "IN_PROGRESS" | "CLOSED" => {}
+        _ => anyhow::bail!("invalid status: {status} (use OPEN, IN_PROGRESS, CLOSED)"),
+    }
+    if case_ids.is_empty() {
+        anyhow::bail!("no case IDs provided");
+    }
+    let body = serde_json::json!({
+        "data": {
+            "attributes": {
+                "status": s
+            },
+            "type": "case"
+        }
+    });
+    let mut results = Vec::with_capacity(case_ids.len());
+    for case_id in case_ids {
+        let res = crate::api::post(cfg, &format!("/api/v2/cases/{case_id}/status"), &body)
+            .await
+            .map(|_| ());
+        results.push((case_id, res));
+    }
+    report_bulk_status(cfg, results)
+}
+
 // ---------------------------------------------------------------------------
 // Jira integration
 // ---------------------------------------------------------------------------
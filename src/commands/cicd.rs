@@ -10,14 +10,12 @@ use datadog_api_client::datadogV2::api_ci_visibility_tests::{
 #[cfg(not(target_arch = "wasm32"))]
 use datadog_api_client::datadogV2::api_dora_metrics::DORAMetricsAPI;
 #[cfg(not(target_arch = "wasm32"))]
-use datadog_api_client::datadogV2::api_test_optimization::{
-    SearchFlakyTestsOptionalParams, TestOptimizationAPI,
-};
+use datadog_api_client::datadogV2::api_test_optimization::TestOptimizationAPI;
 #[cfg(not(target_arch = "wasm32"))]
 use datadog_api_client::datadogV2::model::{
     CIAppPipelineEventsRequest, CIAppPipelinesQueryFilter, CIAppQueryPageOptions, CIAppSort,
     CIAppTestEventsRequest, CIAppTestsQueryFilter, DORADeploymentPatchRequest,
-    FlakyTestsSearchRequest, UpdateFlakyTestsRequest,
+    DORADeploymentRequest, UpdateFlakyTestsRequest,
 };
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -65,7 +63,7 @@ pub async fn pipelines_list(
         .search_ci_app_pipeline_events(params)
         .await
         .map_err(|e| anyhow::anyhow!("failed to list pipelines: {e:?}"))?;
-    formatter::output(cfg, &resp)
+    print_pipelines(cfg, &resp)
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -94,7 +92,85 @@ pub async fn pipelines_list(
         "sort": "-timestamp"
     });
     let data = crate::api::post(cfg, "/api/v2/ci/pipelines/events/search", &body).await?;
-    crate::formatter::output(cfg, &data)
+    print_pipelines(cfg, &data)
+}
+
+const PIPELINE_COLUMNS: &[&str] = &[
+    "pipeline_name",
+    "status",
+    "duration",
+    "branch",
+    "started_at",
+];
+
+/// Pipeline rows, most recently started first.
+fn pipeline_rows(resp: &serde_json::Value) -> Vec<Vec<String>> {
+    let mut rows: Vec<Vec<String>> = resp
+        .get("data")
+        .and_then(|d| d.as_array())
+        .into_iter()
+        .flatten()
+        .map(|e| {
+            // Span duration is reported in nanoseconds.
+            let duration = e
+                .pointer("/attributes/attributes/duration")
+                .and_then(|d| d.as_f64())
+                .map(|ns| human_duration(ns / 1e9))
+                .unwrap_or_default();
+            let started_at = match e.pointer("/attributes/attributes/start") {
+                Some(serde_json::Value::Number(ms)) => ms
+                    .as_i64()
+                    .and_then(chrono::DateTime::from_timestamp_millis)
+                    .map(|dt| dt.to_rfc3339())
+                    .unwrap_or_default(),
+                _ => formatter::str_at(e, "/attributes/timestamp"),
+            };
+            vec![
+                formatter::str_at(e, "/attributes/attributes/ci/pipeline/name"),
+                formatter::str_at(e, "/attributes/attributes/ci/status"),
+                duration,
+                formatter::str_at(e, "/attributes/attributes/git/branch"),
+                started_at,
+            ]
+        })
+        .collect();
+    rows.sort_by(|a, b| b[4].cmp(&a[4]));
+    rows
+}
+
+/// Format seconds as e.g. "850ms", "42s", "2m 31s" or "1h 5m 0s".
+fn human_duration(secs: f64) -> String {
+    if secs < 1.0 {
+        return format!("{}ms", (secs * 1000.0).round() as i64);
+    }
+    let total = secs.round() as i64;
+    let (h, m, s) = (total / 3600, total % 3600 / 60, total % 60);
+    match (h, m) {
+        (0, 0) => format!("{s}s"),
+        (0, _) => format!("{m}m {s}s"),
+        _ => format!("{h}h {m}m {s}s"),
+    }
+}
+
+/// Colors the status column green for success and red for failures.
+/// Styling needs comfy-table's tty support, which only native builds have.
+#[cfg(feature = "native")]
+fn pipeline_cell(col: usize, text: String) -> comfy_table::Cell {
+    let cell = comfy_table::Cell::new(&text);
+    match text.as_str() {
+        "success" if col == 1 => cell.fg(comfy_table::Color::Green),
+        "error" | "failed" | "failure" if col == 1 => cell.fg(comfy_table::Color::Red),
+        _ => cell,
+    }
+}
+
+#[cfg(not(feature = "native"))]
+fn pipeline_cell(_col: usize, text: String) -> comfy_table::Cell {
+    comfy_table::Cell::new(text)
+}
+
+fn print_pipelines<T: serde::Serialize>(cfg: &Config, resp: &T) -> Result<()> {
+    formatter::output_styled_rows(cfg, resp, PIPELINE_COLUMNS, pipeline_rows, pipeline_cell)
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -464,55 +540,184 @@ pub async fn dora_patch_deployment(cfg: &Config, deployment_id: &str, file: &str
     Ok(())
 }
 
-// ---- Flaky Tests ----
+/// Deployment fields given as flags to `cicd dora create-deployment`.
+/// Timestamps are unix nanoseconds, as the DORA API expects.
+pub struct DoraDeploymentArgs {
+    pub service: Option<String>,
+    pub version: Option<String>,
+    pub repository_url: Option<String>,
+    pub commit_sha: Option<String>,
+    pub env: Option<String>,
+    pub started_at: Option<i64>,
+    pub finished_at: Option<i64>,
+}
 
-#[cfg(not(target_arch = "wasm32"))]
-pub async fn flaky_tests_search(cfg: &Config, query: Option<String>) -> Result<()> {
-    let dd_cfg = client::make_dd_config(cfg);
-    let api = match client::make_bearer_client(cfg) {
-        Some(c) => TestOptimizationAPI::with_client_and_config(dd_cfg, c),
-        None => TestOptimizationAPI::with_config(dd_cfg),
+/// Builds a DORA deployment request from flags. `finished_at` defaults to
+/// now so a CI step can report a deployment as it completes.
+fn dora_deployment_body(args: DoraDeploymentArgs) -> Result<serde_json::Value> {
+    let Some(service) = args.service else {
+        anyhow::bail!("--service is required unless --file is given");
     };
+    let Some(started_at) = args.started_at else {
+        anyhow::bail!("--started-at is required unless --file is given");
+    };
+    let finished_at = args
+        .finished_at
+        .unwrap_or_else(|| chrono::Utc::now().timestamp_nanos_opt().unwrap_or(i64::MAX));
+    if finished_at < started_at {
+        anyhow::bail!("--finished-at must not be earlier than --started-at");
+    }
+    let mut attrs = serde_json::json!({
+        "service": service,
+        "started_at": started_at,
+        "finished_at": finished_at,
+    });
+    match (args.repository_url, args.commit_sha) {
+        (Some(repository_url), Some(commit_sha)) => {
+            attrs["git"] = serde_json::json!({
+                "repository_url": repository_url,
+                "commit_sha": commit_sha,
+            });
+        }
+        (None, None) => {}
+        _ => anyhow::bail!("--repository-url and --commit-sha must be given together"),
+    }
+    if let Some(v) = args.version {
+        attrs["version"] = serde_json::json!(v);
+    }
+    if let Some(e) = args.env {
+        attrs["env"] = serde_json::json!(e);
+    }
+    Ok(serde_json::json!({ "data": { "attributes": attrs } }))
+}
 
-    let mut body = FlakyTestsSearchRequest::new();
-    if let Some(q) = query {
-        use datadog_api_client::datadogV2::model::{
-            FlakyTestsSearchFilter, FlakyTestsSearchRequestAttributes, FlakyTestsSearchRequestData,
-            FlakyTestsSearchRequestDataType,
-        };
-        let filter = FlakyTestsSearchFilter::new().query(q);
-        let attrs = FlakyTestsSearchRequestAttributes::new().filter(filter);
-        let data = FlakyTestsSearchRequestData::new()
-            .attributes(attrs)
-            .type_(FlakyTestsSearchRequestDataType::SEARCH_FLAKY_TESTS_REQUEST);
-        body = body.data(data);
+fn dora_deployment_request(
+    args: DoraDeploymentArgs,
+    file: Option<String>,
+) -> Result<serde_json::Value> {
+    match file {
+        Some(f) => crate::util::read_json_file(&f),
+        None => dora_deployment_body(args),
     }
+}
 
-    let params = SearchFlakyTestsOptionalParams::default().body(body);
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn dora_create_deployment(
+    cfg: &Config,
+    args: DoraDeploymentArgs,
+    file: Option<String>,
+) -> Result<()> {
+    let dd_cfg = client::make_dd_config(cfg);
+    let api = match client::make_bearer_client(cfg) {
+        Some(c) => DORAMetricsAPI::with_client_and_config(dd_cfg, c),
+        None => DORAMetricsAPI::with_config(dd_cfg),
+    };
+    let body: DORADeploymentRequest = serde_json::from_value(dora_deployment_request(args, file)?)
+        .map_err(|e| anyhow::anyhow!("invalid DORA deployment: {e}"))?;
     let resp = api
-        .search_flaky_tests(params)
+        .create_dora_deployment(body)
         .await
-        .map_err(|e| anyhow::anyhow!("failed to search flaky tests: {e:?}"))?;
+        .map_err(|e| anyhow::anyhow!("failed to create DORA deployment: {e:?}"))?;
     formatter::output(cfg, &resp)
 }
 
 #[cfg(target_arch = "wasm32")]
-pub async fn flaky_tests_search(cfg: &Config, query: Option<String>) -> Result<()> {
-    let mut body = serde_json::json!({});
+pub async fn dora_create_deployment(
+    cfg: &Config,
+    args: DoraDeploymentArgs,
+    file: Option<String>,
+) -> Result<()> {
+    let body = dora_deployment_request(args, file)?;
+    let data = crate::api::post(cfg, "/api/v2/dora/deployment", &body)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to create DORA deployment: {e:?}"))?;
+    formatter::output(cfg, &data)
+}
+
+// ---- Flaky Tests ----
+
+fn flaky_tests_request(
+    query: Option<&str>,
+    cursor: Option<&str>,
+    limit: i64,
+    include_history: bool,
+    sort: Option<&str>,
+) -> serde_json::Value {
+    let mut attrs = serde_json::json!({ "page": { "limit": limit } });
     if let Some(q) = query {
-        body = serde_json::json!({
-            "data": {
-                "attributes": {
-                    "filter": {
-                        "query": q
-                    }
-                },
-                "type": "search_flaky_tests_request"
+        attrs["filter"] = serde_json::json!({ "query": q });
+    }
+    if let Some(c) = cursor {
+        attrs["page"]["cursor"] = serde_json::json!(c);
+    }
+    if include_history {
+        attrs["include_history"] = serde_json::json!(true);
+    }
+    if let Some(s) = sort {
+        attrs["sort"] = serde_json::json!(s);
+    }
+    serde_json::json!({
+        "data": {
+            "attributes": attrs,
+            "type": "search_flaky_tests_request"
+        }
+    })
+}
+
+fn flaky_tests_next_cursor(resp: &serde_json::Value) -> Option<String> {
+    resp.pointer("/meta/pagination/next_page")
+        .and_then(|c| c.as_str())
+        .filter(|c| !c.is_empty())
+        .map(String::from)
+}
+
+/// Search flaky tests. With `all`, follows the pagination cursor and prints every
+/// page as one array; otherwise prints a single page and reports the next cursor
+/// on stderr.
+pub async fn flaky_tests_search(
+    cfg: &Config,
+    query: Option<String>,
+    cursor: Option<String>,
+    limit: i64,
+    include_history: bool,
+    sort: Option<String>,
+    all: bool,
+) -> Result<()> {
+    let mut cursor = cursor;
+    let mut tests: Vec<serde_json::Value> = Vec::new();
+    loop {
+        let body = flaky_tests_request(
+            query.as_deref(),
+            cursor.as_deref(),
+            limit,
+            include_history,
+            sort.as_deref(),
+        );
+        let resp = crate::api::post(cfg, "/api/v2/ci/tests/flaky", &body)
+            .await
+            .map_err(|e| anyhow::anyhow!("failed to search flaky tests: {e:?}"))?;
+        let next = flaky_tests_next_cursor(&resp);
+        if !all {
+            formatter::output(cfg, &resp)?;
+            if let Some(c) = &next {
+                eprintln!("# next cursor: {c} (pass --cursor to fetch the next page)");
             }
-        });
+            return Ok(());
+        }
+        if let Some(page) = resp.get("data").and_then(|d| d.as_array()) {
+            tests.extend(page.iter().cloned());
+        }
+        match next {
+            None => break,
+            // A cursor that does not advance would page forever.
+            Some(c) if cursor.as_deref() == Some(c.as_str()) => anyhow::bail!(
+                "flaky tests search returned cursor {c:?} again after {} test(s); stopping",
+                tests.len()
+            ),
+            next => cursor = next,
+        }
     }
-    let data = crate::api::post(cfg, "/api/v2/ci/tests/flaky", &body).await?;
-    crate::formatter::output(cfg, &data)
+    formatter::output(cfg, &serde_json::json!({ "data": tests }))
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -536,3 +741,121 @@ pub async fn flaky_tests_update(cfg: &Config, file: &str) -> Result<()> {
     let data = crate::api::patch(cfg, "/api/v2/ci/tests/flaky", &body).await?;
     crate::formatter::output(cfg, &data)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dora_args() -> DoraDeploymentArgs {
+        DoraDeploymentArgs {
+            service: Some("web-store".into()),
+            version: None,
+            repository_url: None,
+            commit_sha: None,
+            env: None,
+            started_at: Some(1_700_000_000_000_000_000),
+            finished_at: Some(1_700_000_060_000_000_000),
+        }
+    }
+
+    #[test]
+    fn test_dora_deployment_body() {
+        let mut args = dora_args();
+        args.env = Some("prod".into());
+        args.repository_url = Some("github.com/org/web-store".into());
+        args.commit_sha = Some("66adc9350f2cc9b250b69abddab733dd55e1a588".into());
+        let body = dora_deployment_body(args).unwrap();
+        let attrs = &body["data"]["attributes"];
+        assert_eq!(attrs["service"], "web-store");
+        assert_eq!(attrs["env"], "prod");
+        assert_eq!(attrs["git"]["repository_url"], "github.com/org/web-store");
+        assert_eq!(attrs["finished_at"], 1_700_000_060_000_000_000i64);
+        assert!(attrs.get("version").is_none());
+    }
+
+    #[test]
+    fn test_dora_deployment_body_validation() {
+        let mut args = dora_args();
+        args.service = None;
+        assert!(dora_deployment_body(args).is_err());
+
+        let mut args = dora_args();
+        args.commit_sha = Some("abc".into());
+        assert!(dora_deployment_body(args).is_err());
+
+        let mut args = dora_args();
+        args.finished_at = Some(1);
+        assert!(dora_deployment_body(args).is_err());
+    }
+
+    #[test]
+    fn test_pipeline_rows_sorted_by_start_desc() {
+        let resp = serde_json::json!({
+            "data": [
+                {
+                    "attributes": {
+                        "attributes": {
+                            "ci": { "pipeline": { "name": "build" }, "status": "success" },
+                            "git": { "branch": "main" },
+                            "duration": 90_000_000_000i64,
+                            "start": 1704067200000i64
+                        }
+                    }
+                },
+                {
+                    "attributes": {
+                        "timestamp": "2024-01-02T00:00:00+00:00",
+                        "attributes": {
+                            "ci": { "pipeline": { "name": "deploy" }, "status": "error" }
+                        }
+                    }
+                }
+            ]
+        });
+        let rows = pipeline_rows(&resp);
+        assert_eq!(
+            rows[0],
+            ["deploy", "error", "", "", "2024-01-02T00:00:00+00:00"]
+        );
+        assert_eq!(
+            rows[1],
+            [
+                "build",
+                "success",
+                "1m 30s",
+                "main",
+                "2024-01-01T00:00:00+00:00"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_human_duration() {
+        assert_eq!(human_duration(0.85), "850ms");
+        assert_eq!(human_duration(42.4), "42s");
+        assert_eq!(human_duration(151.0), "2m 31s");
+        assert_eq!(human_duration(3900.0), "1h 5m 0s");
+    }
+
+    #[test]
+    #[cfg(feature = "native")]
+    fn test_pipeline_cell_colors_status() {
+        let row = |status: &str| {
+            [
+                "build",
+                status,
+                "2m 31s",
+                "main",
+                "2024-01-01T00:00:00+00:00",
+            ]
+            .map(String::from)
+            .to_vec()
+        };
+        let rows = [row("success"), row("error")];
+        let plain = formatter::format_rows(PIPELINE_COLUMNS, &rows, None, None);
+        assert!(!plain.contains('\x1b'));
+        let colored = formatter::format_rows(PIPELINE_COLUMNS, &rows, None, Some(pipeline_cell));
+        assert!(colored.contains("\x1b[38;5;10m success"), "{colored:?}");
+        assert!(colored.contains("\x1b[38;5;9m error"), "{colored:?}");
+    }
+}
@@ -8,10 +8,15 @@ use datadog_api_client::datadogV1::api_azure_integration::AzureIntegrationAPI;
 #[cfg(not(target_arch = "wasm32"))]
 use datadog_api_client::datadogV1::api_gcp_integration::GCPIntegrationAPI;
 #[cfg(not(target_arch = "wasm32"))]
+use datadog_api_client::datadogV1::model::{AzureAccount, GCPAccount};
+#[cfg(not(target_arch = "wasm32"))]
+use datadog_api_client::datadogV2::api_aws_integration::AWSIntegrationAPI as AWSIntegrationV2API;
+#[cfg(not(target_arch = "wasm32"))]
 use datadog_api_client::datadogV2::api_oci_integration::OCIIntegrationAPI;
 #[cfg(not(target_arch = "wasm32"))]
 use datadog_api_client::datadogV2::model::{
-    CreateTenancyConfigRequest, UpdateTenancyConfigRequest,
+    AWSAccountCreateRequest, AWSAccountUpdateRequest, CreateTenancyConfigRequest,
+    UpdateTenancyConfigRequest,
 };
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -60,12 +65,63 @@ pub async fn gcp_list(cfg: &Config) -> Result<()> {
 }
 
 #[cfg(not(target_arch = "wasm32"))]
-pub async fn azure_list(cfg: &Config) -> Result<()> {
+fn make_gcp_api(cfg: &Config) -> GCPIntegrationAPI {
     let dd_cfg = client::make_dd_config(cfg);
-    let api = match client::make_bearer_client(cfg) {
-        Some(c) => AzureIntegrationAPI::with_client_and_config(dd_cfg, c),
-        None => AzureIntegrationAPI::with_config(dd_cfg),
-    };
+    match client::make_bearer_client(cfg) {
+        Some(c) => GCPIntegrationAPI::with_client_and_config(dd_cfg, c),
+        None => GCPIntegrationAPI::with_config(dd_cfg),
+    }
+}
+
+/// Create a GCP integration from a service account key file. The file is the
+/// JSON key downloaded from GCP (`type`, `project_id`, `private_key_id`,
+/// `private_key`, `client_email`, `client_id`, `auth_uri`, `token_uri`,
+/// `auth_provider_x509_cert_url`, `client_x509_cert_url`), optionally with
+/// Datadog fields such as `host_filters`.
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn gcp_create(cfg: &Config, file: &str) -> Result<()> {
+    let api = make_gcp_api(cfg);
+    let body: GCPAccount = crate::util::read_json_file(file)?;
+    let resp = api
+        .create_gcp_integration(body)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to create GCP integration: {e:?}"))?;
+    formatter::output(cfg, &resp)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn gcp_create(cfg: &Config, file: &str) -> Result<()> {
+    let body: serde_json::Value = crate::util::read_json_file(file)?;
+    let data = crate::api::post(cfg, "/api/v1/integration/gcp", &body).await?;
+    crate::formatter::output(cfg, &data)
+}
+
+/// Delete a GCP integration. The v1 API identifies the account by its project
+/// ID and service account email, both sent in the request body.
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn gcp_delete(cfg: &Config, project_id: &str, client_email: &str) -> Result<()> {
+    let api = make_gcp_api(cfg);
+    let body = GCPAccount::new()
+        .project_id(project_id.to_string())
+        .client_email(client_email.to_string());
+    api.delete_gcp_integration(body)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to delete GCP integration: {e:?}"))?;
+    println!("GCP integration '{project_id}' deleted.");
+    Ok(())
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn gcp_delete(cfg: &Config, project_id: &str, client_email: &str) -> Result<()> {
+    let body = serde_json::json!({"project_id": project_id, "client_email": client_email});
+    crate::api::delete_with_body(cfg, "/api/v1/integration/gcp", &body).await?;
+    println!("GCP integration '{project_id}' deleted.");
+    Ok(())
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn azure_list(cfg: &Config) -> Result<()> {
+    let api = make_azure_api(cfg);
     let resp = api
         .list_azure_integration()
         .await
@@ -79,6 +135,177 @@ pub async fn azure_list(cfg: &Config) -> Result<()> {
     crate::formatter::output(cfg, &data)
 }
 
+#[cfg(not(target_arch = "wasm32"))]
+fn make_azure_api(cfg: &Config) -> AzureIntegrationAPI {
+    let dd_cfg = client::make_dd_config(cfg);
+    match client::make_bearer_client(cfg) {
+        Some(c) => AzureIntegrationAPI::with_client_and_config(dd_cfg, c),
+        None => AzureIntegrationAPI::with_config(dd_cfg),
+    }
+}
+
+/// Create an Azure integration. The file is an Azure account definition
+/// (`tenant_name`, `client_id`, `client_secret`, plus optional settings such
+/// as `host_filters`).
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn azure_create(cfg: &Config, file: &str) -> Result<()> {
+    let api = make_azure_api(cfg);
+    let body: AzureAccount = crate::util::read_json_file(file)?;
+    let resp = api
+        .create_azure_integration(body)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to create Azure integration: {e:?}"))?;
+    formatter::output(cfg, &resp)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn azure_create(cfg: &Config, file: &str) -> Result<()> {
+    let body: serde_json::Value = crate::util::read_json_file(file)?;
+    let data = crate::api::post(cfg, "/api/v1/integration/azure", &body).await?;
+    crate::formatter::output(cfg, &data)
+}
+
+/// Update an Azure integration. The v1 API identifies the account by the
+/// `tenant_name` and `client_id` in the body; use `new_tenant_name` and
+/// `new_client_id` in the file to change them.
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn azure_update(cfg: &Config, tenant_name: &str, file: &str) -> Result<()> {
+    let api = make_azure_api(cfg);
+    let mut body: AzureAccount = crate::util::read_json_file(file)?;
+    body.tenant_name = Some(tenant_name.to_string());
+    let resp = api
+        .update_azure_integration(body)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to update Azure integration: {e:?}"))?;
+    formatter::output(cfg, &resp)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn azure_update(cfg: &Config, tenant_name: &str, file: &str) -> Result<()> {
+    let mut body: serde_json::Value = crate::util::read_json_file(file)?;
+    body["tenant_name"] = serde_json::json!(tenant_name);
+    let data = crate::api::put(cfg, "/api/v1/integration/azure", &body).await?;
+    crate::formatter::output(cfg, &data)
+}
+
+/// Delete an Azure integration, identified by tenant name and client ID.
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn azure_delete(cfg: &Config, tenant_name: &str, client_id: &str) -> Result<()> {
+    let api = make_azure_api(cfg);
+    let body = AzureAccount::new()
+        .tenant_name(tenant_name.to_string())
+        .client_id(client_id.to_string());
+    api.delete_azure_integration(body)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to delete Azure integration: {e:?}"))?;
+    println!("Azure integration '{tenant_name}' deleted.");
+    Ok(())
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn azure_delete(cfg: &Config, tenant_name: &str, client_id: &str) -> Result<()> {
+    let body = serde_json::json!({"tenant_name": tenant_name, "client_id": client_id});
+    crate::api::delete_with_body(cfg, "/api/v1/integration/azure", &body).await?;
+    println!("Azure integration '{tenant_name}' deleted.");
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// AWS account management (v2)
+// ---------------------------------------------------------------------------
+
+#[cfg(not(target_arch = "wasm32"))]
+fn make_aws_v2_api(cfg: &Config) -> AWSIntegrationV2API {
+    let dd_cfg = client::make_dd_config(cfg);
+    match client::make_bearer_client(cfg) {
+        Some(c) => AWSIntegrationV2API::with_client_and_config(dd_cfg, c),
+        None => AWSIntegrationV2API::with_config(dd_cfg),
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn aws_get(cfg: &Config, account_id: &str) -> Result<()> {
+    let api = make_aws_v2_api(cfg);
+    let resp = api
+        .get_aws_account(account_id.to_string())
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to get AWS account: {e:?}"))?;
+    formatter::output(cfg, &resp)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn aws_get(cfg: &Config, account_id: &str) -> Result<()> {
+    let data = crate::api::get(
+        cfg,
+        &format!("/api/v2/integration/aws/accounts/{account_id}"),
+        &[],
+    )
+    .await?;
+    crate::formatter::output(cfg, &data)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn aws_create(cfg: &Config, file: &str) -> Result<()> {
+    let api = make_aws_v2_api(cfg);
+    let body: AWSAccountCreateRequest = crate::util::read_json_file(file)?;
+    let resp = api
+        .create_aws_account(body)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to create AWS account: {e:?}"))?;
+    formatter::output(cfg, &resp)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn aws_create(cfg: &Config, file: &str) -> Result<()> {
+    let body: serde_json::Value = crate::util::read_json_file(file)?;
+    let data = crate::api::post(cfg, "/api/v2/integration/aws/accounts", &body).await?;
+    crate::formatter::output(cfg, &data)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn aws_update(cfg: &Config, account_id: &str, file: &str) -> Result<()> {
+    let api = make_aws_v2_api(cfg);
+    let body: AWSAccountUpdateRequest = crate::util::read_json_file(file)?;
+    let resp = api
+        .update_aws_account(account_id.to_string(), body)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to update AWS account: {e:?}"))?;
+    formatter::output(cfg, &resp)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn aws_update(cfg: &Config, account_id: &str, file: &str) -> Result<()> {
+    let body: serde_json::Value = crate::util::read_json_file(file)?;
+    let data = crate::api::patch(
+        cfg,
+        &format!("/api/v2/integration/aws/accounts/{account_id}"),
+        &body,
+    )
+    .await?;
+    crate::formatter::output(cfg, &data)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn aws_delete(cfg: &Config, account_id: &str) -> Result<()> {
+    let api = make_aws_v2_api(cfg);
+    api.delete_aws_account(account_id.to_string())
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to delete AWS account: {e:?}"))?;
+    println!("AWS account '{account_id}' deleted.");
+    Ok(())
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn aws_delete(cfg: &Config, account_id: &str) -> Result<()> {
+    crate::api::delete(
+        cfg,
+        &format!("/api/v2/integration/aws/accounts/{account_id}"),
+    )
+    .await?;
+    println!("AWS account '{account_id}' deleted.");
+    Ok(())
+}
+
 // ---------------------------------------------------------------------------
 // OCI tenancy management
 // ---------------------------------------------------------------------------
@@ -39,11 +39,11 @@ pub async fn branch_summary(cfg: &Config, repo: String, branch: String) -> Resul
             "type": "ci_app_coverage_branch_summary_request",
             "attributes": {
                 "branch": branch,
-                "repository_url": repo,
+                "repository_id": repo,
             }
         }
     });
-    let data = crate::api::post(cfg, "/api/v2/ci/code-coverage/branch-summary", &body).await?;
+    let data = crate::api::post(cfg, "/api/v2/code-coverage/branch/summary", &body).await?;
     crate::formatter::output(cfg, &data)
 }
 
@@ -72,10 +72,10 @@ pub async fn commit_summary(cfg: &Config, repo: String, commit: String) -> Resul
             "type": "ci_app_coverage_commit_summary_request",
             "attributes": {
                 "commit_sha": commit,
-                "repository_url": repo,
+                "repository_id": repo,
             }
         }
     });
-    let data = crate::api::post(cfg, "/api/v2/ci/code-coverage/commit-summary", &body).await?;
+    let data = crate::api::post(cfg, "/api/v2/code-coverage/commit/summary", &body).await?;
     crate::formatter::output(cfg, &data)
 }
@@ -105,3 +105,165 @@ pub async fn attribution(cfg: &Config, start: String, fields: Option<String>) ->
     let data = crate::api::get(cfg, "/api/v2/cost_by_tag/monthly_cost_attribution", &query).await?;
     crate::formatter::output(cfg, &data)
 }
+
+// ---- Forecast ----
+// There is no dedicated forecast endpoint, so the forecast joins month-to-date
+// estimated cost with the projected end-of-month cost, per product.
+
+#[derive(serde::Serialize, Debug, PartialEq)]
+struct ForecastRow {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    org: Option<String>,
+    product: String,
+    current_spend: f64,
+    projected_end_of_month: f64,
+}
+
+/// Sum `total` charges per (org, product) from a cost response.
+fn total_charges(
+    resp: &serde_json::Value,
+    by_org: bool,
+) -> std::collections::BTreeMap<(Option<String>, String), f64> {
+    let mut totals = std::collections::BTreeMap::new();
+    let entries = resp.get("data").and_then(|d| d.as_array());
+    for attrs in entries
+        .into_iter()
+        .flatten()
+        .filter_map(|e| e.get("attributes"))
+    {
+        let org = by_org
+            .then(|| attrs.get("org_name").and_then(|o| o.as_str()))
+            .flatten()
+            .map(String::from);
+        let charges = attrs.get("charges").and_then(|c| c.as_array());
+        for charge in charges.into_iter().flatten() {
+            if charge.get("charge_type").and_then(|t| t.as_str()) != Some("total") {
+                continue;
+            }
+            let Some(product) = charge.get("product_name").and_then(|p| p.as_str()) else {
+                continue;
+            };
+            let cost = charge.get("cost").and_then(|c| c.as_f64()).unwrap_or(0.0);
+            *totals
+                .entry((org.clone(), product.to_string()))
+                .or_insert(0.0) += cost;
+        }
+    }
+    totals
+}
+
+fn forecast_rows(
+    estimated: &serde_json::Value,
+    projected: &serde_json::Value,
+    product: Option<&str>,
+    by_org: bool,
+) -> Vec<ForecastRow> {
+    let current = total_charges(estimated, by_org);
+    let mut projected = total_charges(projected, by_org);
+    let mut keys: Vec<_> = current.keys().chain(projected.keys()).cloned().collect();
+    keys.sort();
+    keys.dedup();
+    let filter = product.map(str::to_lowercase);
+    keys.into_iter()
+        .filter(|(_, p)| {
+            filter
+                .as_deref()
+                .is_none_or(|f| p.to_lowercase().contains(f))
+        })
+        .map(|key| ForecastRow {
+            current_spend: current.get(&key).copied().unwrap_or(0.0),
+            projected_end_of_month: projected.remove(&key).unwrap_or(0.0),
+            org: key.0,
+            product: key.1,
+        })
+        .collect()
+}
+
+pub async fn forecast(
+    cfg: &Config,
+    product: Option<String>,
+    granularity: Option<String>,
+) -> Result<()> {
+    let by_org = match granularity.as_deref() {
+        None | Some("product") => false,
+        Some("org") => true,
+        Some(other) => {
+            anyhow::bail!("invalid --granularity {other:?}: expected one of: product, org")
+        }
+    };
+    let month_start = chrono::Utc::now().format("%Y-%m-01T00:00:00Z").to_string();
+    let estimated = crate::api::get(
+        cfg,
+        "/api/v2/usage/estimated_cost",
+        &[("start_month", month_start)],
+    )
+    .await
+    .map_err(|e| anyhow::anyhow!("failed to get estimated cost: {e:?}"))?;
+    let projected = crate::api::get(cfg, "/api/v2/usage/projected_cost", &[])
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to get projected cost: {e:?}"))?;
+    let rows = forecast_rows(&estimated, &projected, product.as_deref(), by_org);
+    formatter::output(cfg, &rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cost_resp(org: &str, charges: &[(&str, &str, f64)]) -> serde_json::Value {
+        let charges: Vec<_> = charges
+            .iter()
+            .map(|(p, t, c)| serde_json::json!({ "product_name": p, "charge_type": t, "cost": c }))
+            .collect();
+        serde_json::json!({
+            "data": [{ "attributes": { "org_name": org, "charges": charges } }]
+        })
+    }
+
+    #[test]
+    fn test_forecast_rows_joins_current_and_projected() {
+        let estimated = cost_resp(
+            "acme",
+            &[
+                ("logs", "total", 40.0),
+                ("logs", "committed", 30.0),
+                ("apm", "total", 10.0),
+            ],
+        );
+        let projected = cost_resp("acme", &[("logs", "total", 100.0), ("rum", "total", 5.0)]);
+        let rows = forecast_rows(&estimated, &projected, None, false);
+        assert_eq!(
+            rows,
+            vec![
+                ForecastRow {
+                    org: None,
+                    product: "apm".into(),
+                    current_spend: 10.0,
+                    projected_end_of_month: 0.0,
+                },
+                ForecastRow {
+                    org: None,
+                    product: "logs".into(),
+                    current_spend: 40.0,
+                    projected_end_of_month: 100.0,
+                },
+                ForecastRow {
+                    org: None,
+                    product: "rum".into(),
+                    current_spend: 0.0,
+                    projected_end_of_month: 5.0,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_forecast_rows_product_filter_and_org() {
+        let estimated = cost_resp("acme", &[("logs", "total", 40.0)]);
+        let projected = cost_resp("acme", &[("logs", "total", 100.0), ("apm", "total", 1.0)]);
+        let rows = forecast_rows(&estimated, &projected, Some("LOG"), true);
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].org.as_deref(), Some("acme"));
+        assert_eq!(rows[0].projected_end_of_month, 100.0);
+    }
+}
@@ -10,8 +10,42 @@ use crate::config::Config;
 use crate::formatter;
 use crate::util;
 
+/// Maximum number of concurrent dashboard fetches for `list --with-widgets`.
 #[cfg(not(target_arch = "wasm32"))]
-pub async fn list(cfg: &Config) -> Result<()> {
+const WIDGET_FETCH_CONCURRENCY: usize = 8;
+
+fn dashboard_ids(list: &serde_json::Value) -> Vec<String> {
+    list.get("dashboards")
+        .and_then(|d| d.as_array())
+        .map(|ds| {
+            ds.iter()
+                .filter_map(|d| d.get("id").and_then(|id| id.as_str()))
+                .map(String::from)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Merge full dashboard definitions (keyed by ID) into the list summaries.
+fn merge_dashboards(
+    list: &mut serde_json::Value,
+    mut full: std::collections::HashMap<String, serde_json::Value>,
+) {
+    let Some(ds) = list.get_mut("dashboards").and_then(|d| d.as_array_mut()) else {
+        return;
+    };
+    for d in ds {
+        let id = d.get("id").and_then(|id| id.as_str()).unwrap_or_default();
+        if let (Some(serde_json::Value::Object(def)), Some(summary)) =
+            (full.remove(id), d.as_object_mut())
+        {
+            summary.extend(def);
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn list(cfg: &Config, with_widgets: bool) -> Result<()> {
     let dd_cfg = client::make_dd_config(cfg);
     let api = match client::make_bearer_client(cfg) {
         Some(c) => DashboardsAPI::with_client_and_config(dd_cfg, c),
@@ -21,15 +55,96 @@ pub async fn list(cfg: &Config) -> Result<()> {
         .list_dashboards(ListDashboardsOptionalParams::default())
         .await
         .map_err(|e| anyhow::anyhow!("failed to list dashboards: {e:?}"))?;
-    formatter::output(cfg, &resp)
+    if !with_widgets {
+        return formatter::output(cfg, &resp);
+    }
+
+    let mut data = serde_json::to_value(&resp)?;
+    let semaphore = std::sync::Arc::new(tokio::sync::Semaphore::new(WIDGET_FETCH_CONCURRENCY));
+    let mut tasks = tokio::task::JoinSet::new();
+    for id in dashboard_ids(&data) {
+        let api = api.clone();
+        let semaphore = semaphore.clone();
+        tasks.spawn(async move {
+            let _permit = semaphore.acquire_owned().await?;
+            let dash = api
+                .get_dashboard(id.clone())
+                .await
+                .map_err(|e| anyhow::anyhow!("failed to get dashboard {id}: {e:?}"))?;
+            Ok::<_, anyhow::Error>((id, serde_json::to_value(dash)?))
+        });
+    }
+    let mut full = std::collections::HashMap::new();
+    while let Some(joined) = tasks.join_next().await {
+        let (id, dash) =
+            joined.map_err(|e| anyhow::anyhow!("dashboard fetch task failed: {e}"))??;
+        full.insert(id, dash);
+    }
+    merge_dashboards(&mut data, full);
+    formatter::output(cfg, &data)
 }
 
 #[cfg(target_arch = "wasm32")]
-pub async fn list(cfg: &Config) -> Result<()> {
-    let data = crate::api::get(cfg, "/api/v1/dashboard", &[]).await?;
+pub async fn list(cfg: &Config, with_widgets: bool) -> Result<()> {
+    let mut data = crate::api::get(cfg, "/api/v1/dashboard", &[]).await?;
+    if with_widgets {
+        let mut full = std::collections::HashMap::new();
+        for id in dashboard_ids(&data) {
+            let dash = crate::api::get(cfg, &format!("/api/v1/dashboard/{id}"), &[]).await?;
+            full.insert(id, dash);
+        }
+        merge_dashboards(&mut data, full);
+    }
     crate::formatter::output(cfg, &data)
 }
 
+/// Page size used when walking the dashboard list for `search`.
+const DASHBOARD_SEARCH_PAGE_SIZE: usize = 100;
+
+/// Keep dashboards whose title contains `query`, ignoring case.
+fn filter_dashboards_by_title(
+    dashboards: Vec<serde_json::Value>,
+    query: &str,
+) -> Vec<serde_json::Value> {
+    let query = query.to_lowercase();
+    dashboards
+        .into_iter()
+        .filter(|d| {
+            d.get("title")
+                .and_then(|t| t.as_str())
+                .is_some_and(|t| t.to_lowercase().contains(&query))
+        })
+        .collect()
+}
+
+/// Search dashboards by title. The list API has no text filter, so every page
+/// is fetched and matched client-side.
+pub async fn search(cfg: &Config, query: &str) -> Result<()> {
+    let mut matches = Vec::new();
+    let mut start = 0;
+    loop {
+        let params = vec![
+            ("count", DASHBOARD_SEARCH_PAGE_SIZE.to_string()),
+            ("start", start.to_string()),
+        ];
+        let page = crate::api::get(cfg, "/api/v1/dashboard", &params)
+            .await
+            .map_err(|e| anyhow::anyhow!("failed to list dashboards: {e:?}"))?;
+        let dashboards = page
+            .get("dashboards")
+            .and_then(|d| d.as_array())
+            .cloned()
+            .unwrap_or_default();
+        let fetched = dashboards.len();
+        matches.extend(filter_dashboards_by_title(dashboards, query));
+        if fetched < DASHBOARD_SEARCH_PAGE_SIZE {
+            break;
+        }
+        start += fetched;
+    }
+    formatter::output(cfg, &serde_json::json!({ "dashboards": matches }))
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 pub async fn get(cfg: &Config, id: &str) -> Result<()> {
     let dd_cfg = client::make_dd_config(cfg);
@@ -52,7 +167,14 @@ pub async fn get(cfg: &Config, id: &str) -> Result<()> {
 
 #[cfg(not(target_arch = "wasm32"))]
 pub async fn create(cfg: &Config, file: &str) -> Result<()> {
-    let body: Dashboard = util::read_json_file(file)?;
+    let body: serde_json::Value = util::read_json_file(file)?;
+    create_payload(cfg, body).await
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn create_payload(cfg: &Config, body: serde_json::Value) -> Result<()> {
+    let body: Dashboard = serde_json::from_value(body)
+        .map_err(|e| anyhow::anyhow!("invalid dashboard definition: {e}"))?;
     let dd_cfg = client::make_dd_config(cfg);
     let api = match client::make_bearer_client(cfg) {
         Some(c) => DashboardsAPI::with_client_and_config(dd_cfg, c),
@@ -68,10 +190,57 @@ pub async fn create(cfg: &Config, file: &str) -> Result<()> {
 #[cfg(target_arch = "wasm32")]
 pub async fn create(cfg: &Config, file: &str) -> Result<()> {
     let body: serde_json::Value = util::read_json_file(file)?;
+    create_payload(cfg, body).await
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn create_payload(cfg: &Config, body: serde_json::Value) -> Result<()> {
     let data = crate::api::post(cfg, "/api/v1/dashboard", &body).await?;
     crate::formatter::output(cfg, &data)
 }
 
+/// Build a minimal empty dashboard definition from flags. Template variables
+/// are comma-separated `name` or `name:default` entries; each is prefixed by
+/// the tag of the same name.
+pub fn dashboard_body(
+    title: &str,
+    description: Option<&str>,
+    layout_type: &str,
+    template_variables: Option<&str>,
+) -> Result<serde_json::Value> {
+    if !matches!(layout_type, "ordered" | "free") {
+        anyhow::bail!("invalid --layout-type: {layout_type} (use ordered or free)");
+    }
+    let mut body = serde_json::json!({
+        "title": title,
+        "layout_type": layout_type,
+        "widgets": []
+    });
+    if let Some(d) = description {
+        body["description"] = serde_json::json!(d);
+    }
+    if let Some(vars) = template_variables {
+        let vars: Vec<serde_json::Value> = vars
+            .split(',')
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+            .map(|v| {
+                let (name, default) = match v.split_once(':') {
+                    Some((n, d)) => (n, Some(d)),
+                    None => (v, None),
+                };
+                let mut var = serde_json::json!({ "name": name, "prefix": name });
+                if let Some(d) = default {
+                    var["defaults"] = serde_json::json!([d]);
+                }
+                var
+            })
+            .collect();
+        body["template_variables"] = serde_json::json!(vars);
+    }
+    Ok(body)
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 pub async fn update(cfg: &Config, id: &str, file: &str) -> Result<()> {
     let body: Dashboard = util::read_json_file(file)?;
@@ -113,3 +282,67 @@ pub async fn delete(cfg: &Config, id: &str) -> Result<()> {
     let data = crate::api::delete(cfg, &format!("/api/v1/dashboard/{id}")).await?;
     crate::formatter::output(cfg, &data)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter_dashboards_by_title() {
+        let dashboards = vec![
+            serde_json::json!({"id": "a", "title": "Checkout Latency"}),
+            serde_json::json!({"id": "b", "title": "Payments"}),
+            serde_json::json!({"id": "c"}),
+        ];
+        let found = filter_dashboards_by_title(dashboards, "checkout");
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0]["id"], "a");
+    }
+
+    #[test]
+    fn test_dashboard_body_minimal() {
+        let body = dashboard_body("Web", None, "ordered", None).unwrap();
+        assert_eq!(
+            body,
+            serde_json::json!({ "title": "Web", "layout_type": "ordered", "widgets": [] })
+        );
+    }
+
+    #[test]
+    fn test_dashboard_body_template_variables() {
+        let body =
+            dashboard_body("Web", Some("Team board"), "free", Some("env:prod, service")).unwrap();
+        assert_eq!(body["description"], "Team board");
+        assert_eq!(
+            body["template_variables"],
+            serde_json::json!([
+                { "name": "env", "prefix": "env", "defaults": ["prod"] },
+                { "name": "service", "prefix": "service" }
+            ])
+        );
+    }
+
+    #[test]
+    fn test_dashboard_body_rejects_layout() {
+        assert!(dashboard_body("Web", None, "grid", None).is_err());
+    }
+
+    #[test]
+    fn test_merge_dashboards_adds_widgets() {
+        let mut list = serde_json::json!({
+            "dashboards": [
+                { "id": "a", "title": "A" },
+                { "id": "b", "title": "B" }
+            ]
+        });
+        assert_eq!(dashboard_ids(&list), vec!["a", "b"]);
+        let mut full = std::collections::HashMap::new();
+        full.insert(
+            "a".to_string(),
+            serde_json::json!({ "id": "a", "title": "A", "widgets": [{ "id": 1 }] }),
+        );
+        merge_dashboards(&mut list, full);
+        assert_eq!(list["dashboards"][0]["widgets"][0]["id"], 1);
+        assert!(list["dashboards"][1].get("widgets").is_none());
+    }
+}
@@ -1,19 +1,89 @@
 use anyhow::Result;
 #[cfg(not(target_arch = "wasm32"))]
 use datadog_api_client::datadogV2::api_sensitive_data_scanner::SensitiveDataScannerAPI;
+#[cfg(not(target_arch = "wasm32"))]
+use datadog_api_client::datadogV2::model::{
+    SensitiveDataScannerConfigRequest, SensitiveDataScannerConfigurationRelationships,
+    SensitiveDataScannerConfigurationType, SensitiveDataScannerGroupItem,
+    SensitiveDataScannerGroupList, SensitiveDataScannerGroupType,
+    SensitiveDataScannerMetaVersionOnly, SensitiveDataScannerReorderConfig,
+    SensitiveDataScannerRuleCreateRequest, SensitiveDataScannerRuleDeleteRequest,
+    SensitiveDataScannerRuleUpdateRequest,
+};
+use serde_json::Value;
 
 #[cfg(not(target_arch = "wasm32"))]
 use crate::client;
 use crate::config::Config;
 use crate::formatter;
+use crate::util;
 
 #[cfg(not(target_arch = "wasm32"))]
-pub async fn scanner_rules_list(cfg: &Config) -> Result<()> {
+fn make_api(cfg: &Config) -> SensitiveDataScannerAPI {
     let dd_cfg = client::make_dd_config(cfg);
-    let api = match client::make_bearer_client(cfg) {
+    match client::make_bearer_client(cfg) {
         Some(c) => SensitiveDataScannerAPI::with_client_and_config(dd_cfg, c),
         None => SensitiveDataScannerAPI::with_config(dd_cfg),
-    };
+    }
+}
+
+/// Extracts the scanning groups from the `included` section of a scanner
+/// configuration response.
+fn scanner_groups(config: &Value) -> Value {
+    let groups = config["included"]
+        .as_array()
+        .map(|items| {
+            items
+                .iter()
+                .filter(|i| i["type"].as_str() == Some("sensitive_data_scanner_group"))
+                .cloned()
+                .collect()
+        })
+        .unwrap_or_default();
+    Value::Array(groups)
+}
+
+/// Builds a raw scanner configuration reorder request. The API expects the
+/// complete list of group IDs in their new order.
+#[cfg(target_arch = "wasm32")]
+fn reorder_body(config_id: &str, version: Option<i64>, ids: &[String]) -> Value {
+    let groups: Vec<Value> = ids
+        .iter()
+        .map(|id| serde_json::json!({"id": id, "type": "sensitive_data_scanner_group"}))
+        .collect();
+    serde_json::json!({
+        "data": {
+            "id": config_id,
+            "type": "sensitive_data_scanner_configuration",
+            "relationships": {"groups": {"data": groups}},
+        },
+        "meta": {"version": version},
+    })
+}
+
+/// Fetches the scanner configuration ID and version. Deletes and reorders
+/// must carry the current version to avoid overwriting concurrent changes.
+#[cfg(not(target_arch = "wasm32"))]
+async fn scanner_config_version(api: &SensitiveDataScannerAPI) -> Result<(String, Option<i64>)> {
+    let resp = api
+        .list_scanning_groups()
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to get scanner configuration: {e:?}"))?;
+    let id = resp.data.and_then(|d| d.id).unwrap_or_default();
+    let version = resp.meta.and_then(|m| m.version);
+    Ok((id, version))
+}
+
+#[cfg(target_arch = "wasm32")]
+async fn scanner_config_version(cfg: &Config) -> Result<(String, Option<i64>)> {
+    let data = crate::api::get(cfg, "/api/v2/sensitive-data-scanner/config", &[]).await?;
+    let id = data["data"]["id"].as_str().unwrap_or_default().to_string();
+    Ok((id, data["meta"]["version"].as_i64()))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn scanner_rules_list(cfg: &Config) -> Result<()> {
+    let api = make_api(cfg);
     let resp = api
         .list_scanning_groups()
         .await
@@ -26,3 +96,161 @@ pub async fn scanner_rules_list(cfg: &Config) -> Result<()> {
     let data = crate::api::get(cfg, "/api/v2/sensitive-data-scanner/config", &[]).await?;
     crate::formatter::output(cfg, &data)
 }
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn scanner_rules_create(cfg: &Config, file: &str) -> Result<()> {
+    let body: SensitiveDataScannerRuleCreateRequest = util::read_json_file(file)?;
+    let api = make_api(cfg);
+    let resp = api
+        .create_scanning_rule(body)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to create scanner rule: {e:?}"))?;
+    formatter::output(cfg, &resp)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn scanner_rules_create(cfg: &Config, file: &str) -> Result<()> {
+    let body: Value = util::read_json_file(file)?;
+    let data = crate::api::post(cfg, "/api/v2/sensitive-data-scanner/config/rules", &body)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to create scanner rule: {e:?}"))?;
+    formatter::output(cfg, &data)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn scanner_rules_update(cfg: &Config, rule_id: &str, file: &str) -> Result<()> {
+    let body: SensitiveDataScannerRuleUpdateRequest = util::read_json_file(file)?;
+    let api = make_api(cfg);
+    let resp = api
+        .update_scanning_rule(rule_id.to_string(), body)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to update scanner rule: {e:?}"))?;
+    formatter::output(cfg, &resp)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn scanner_rules_update(cfg: &Config, rule_id: &str, file: &str) -> Result<()> {
+    let body: Value = util::read_json_file(file)?;
+    let path = format!("/api/v2/sensitive-data-scanner/config/rules/{rule_id}");
+    let data = crate::api::patch(cfg, &path, &body)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to update scanner rule: {e:?}"))?;
+    formatter::output(cfg, &data)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn scanner_rules_delete(cfg: &Config, rule_id: &str) -> Result<()> {
+    let api = make_api(cfg);
+    let (_, version) = scanner_config_version(&api).await?;
+    let mut meta = SensitiveDataScannerMetaVersionOnly::new();
+    meta.version = version;
+    api.delete_scanning_rule(
+        rule_id.to_string(),
+        SensitiveDataScannerRuleDeleteRequest::new(meta),
+    )
+    .await
+    .map_err(|e| anyhow::anyhow!("failed to delete scanner rule: {e:?}"))?;
+    println!("Scanner rule {rule_id} deleted.");
+    Ok(())
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn scanner_rules_delete(cfg: &Config, rule_id: &str) -> Result<()> {
+    let (_, version) = scanner_config_version(cfg).await?;
+    let path = format!("/api/v2/sensitive-data-scanner/config/rules/{rule_id}");
+    let body = serde_json::json!({"meta": {"version": version}});
+    crate::api::delete_with_body(cfg, &path, &body)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to delete scanner rule: {e:?}"))?;
+    println!("Scanner rule {rule_id} deleted.");
+    Ok(())
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn scanner_groups_list(cfg: &Config) -> Result<()> {
+    let api = make_api(cfg);
+    let resp = api
+        .list_scanning_groups()
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to list scanning groups: {e:?}"))?;
+    formatter::output(cfg, &scanner_groups(&serde_json::to_value(&resp)?))
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn scanner_groups_list(cfg: &Config) -> Result<()> {
+    let data = crate::api::get(cfg, "/api/v2/sensitive-data-scanner/config", &[])
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to list scanning groups: {e:?}"))?;
+    formatter::output(cfg, &scanner_groups(&data))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn scanner_groups_reorder(cfg: &Config, ids: Vec<String>) -> Result<()> {
+    let api = make_api(cfg);
+    let (config_id, version) = scanner_config_version(&api).await?;
+    let groups = ids
+        .into_iter()
+        .map(|id| {
+            SensitiveDataScannerGroupItem::new()
+                .id(id)
+                .type_(SensitiveDataScannerGroupType::SENSITIVE_DATA_SCANNER_GROUP)
+        })
+        .collect();
+    let data = SensitiveDataScannerReorderConfig::new()
+        .id(config_id)
+        .type_(SensitiveDataScannerConfigurationType::SENSITIVE_DATA_SCANNER_CONFIGURATIONS)
+        .relationships(
+            SensitiveDataScannerConfigurationRelationships::new()
+                .groups(SensitiveDataScannerGroupList::new().data(groups)),
+        );
+    let mut meta = SensitiveDataScannerMetaVersionOnly::new();
+    meta.version = version;
+    let resp = api
+        .reorder_scanning_groups(SensitiveDataScannerConfigRequest::new(data, meta))
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to reorder scanning groups: {e:?}"))?;
+    formatter::output(cfg, &resp)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn scanner_groups_reorder(cfg: &Config, ids: Vec<String>) -> Result<()> {
+    let (config_id, version) = scanner_config_version(cfg).await?;
+    let body = reorder_body(&config_id, version, &ids);
+    let data = crate::api::patch(cfg, "/api/v2/sensitive-data-scanner/config", &body)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to reorder scanning groups: {e:?}"))?;
+    formatter::output(cfg, &data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scanner_groups_filters_included() {
+        let config = serde_json::json!({
+            "data": {"id": "cfg"},
+            "included": [
+                {"id": "g1", "type": "sensitive_data_scanner_group"},
+                {"id": "r1", "type": "sensitive_data_scanner_rule"},
+                {"id": "g2", "type": "sensitive_data_scanner_group"},
+            ],
+        });
+        let groups = scanner_groups(&config);
+        let ids: Vec<&str> = groups
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|g| g["id"].as_str().unwrap())
+            .collect();
+        assert_eq!(ids, vec!["g1", "g2"]);
+    }
+
+    #[test]
+    fn test_scanner_groups_missing_included() {
+        assert_eq!(
+            scanner_groups(&serde_json::json!({})),
+            serde_json::json!([])
+        );
+    }
+}
@@ -102,3 +102,172 @@ pub async fn issues_get(cfg: &Config, issue_id: &str) -> Result<()> {
     .await?;
     crate::formatter::output(cfg, &data)
 }
+
+/// Issue states accepted by the error tracking state endpoint.
+const ISSUE_STATES: &[&str] = &["OPEN", "ACKNOWLEDGED", "RESOLVED", "IGNORED", "EXCLUDED"];
+
+fn parse_issue_state(status: &str) -> Result<String> {
+    let state = status.trim().to_uppercase();
+    if !ISSUE_STATES.contains(&state.as_str()) {
+        bail!(
+            "invalid issue status: {status} (use {})",
+            ISSUE_STATES.join(", ").to_lowercase()
+        );
+    }
+    Ok(state)
+}
+
+fn issue_state_body(issue_id: &str, state: &str) -> serde_json::Value {
+    serde_json::json!({
+        "data": {
+            "attributes": { "state": state },
+            "id": issue_id,
+            "type": "error_tracking_issue"
+        }
+    })
+}
+
+async fn update_issue_state(cfg: &Config, issue_id: &str, state: &str) -> Result<()> {
+    let path = format!("/api/v2/error-tracking/issues/{issue_id}/state");
+    crate::api::put(cfg, &path, &issue_state_body(issue_id, state))
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to update issue {issue_id}: {e:?}"))?;
+    Ok(())
+}
+
+/// The issue search endpoint returns at most this many issues and has no
+/// pagination, so a full page may mean results were cut off.
+const ISSUE_SEARCH_MAX_RESULTS: usize = 100;
+
+/// IDs of the issues matching `query` in the given time range. Fails when the
+/// search returns a full page, since the remaining issues cannot be fetched.
+async fn search_issue_ids(cfg: &Config, query: &str, from: &str, to: &str) -> Result<Vec<String>> {
+    let body = serde_json::json!({
+        "data": {
+            "attributes": {
+                "from": crate::util::parse_time_to_unix_millis(from)?,
+                "query": query,
+                "to": crate::util::parse_time_to_unix_millis(to)?,
+            },
+            "type": "search_request",
+        }
+    });
+    let resp = crate::api::post(cfg, "/api/v2/error-tracking/issues/search", &body)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to search issues: {e:?}"))?;
+    let ids: Vec<String> = resp
+        .get("data")
+        .and_then(|d| d.as_array())
+        .map(|a| {
+            a.iter()
+                .filter_map(|i| Some(i.get("id")?.as_str()?.to_string()))
+                .collect()
+        })
+        .unwrap_or_default();
+    if ids.len() >= ISSUE_SEARCH_MAX_RESULTS {
+        bail!(
+            "search returned {} issues, the most the API returns in one request, so more \
+             may match; nothing was updated. Narrow --query or --from/--to and retry",
+            ids.len()
+        );
+    }
+    Ok(ids)
+}
+
+/// Set the state of every issue matching `query` in the given time range,
+/// after confirming the number of matching issues unless auto-approve is set.
+/// Every issue is attempted; failures are reported at the end.
+pub async fn issues_bulk_update(
+    cfg: &Config,
+    query: &str,
+    status: &str,
+    from: &str,
+    to: &str,
+) -> Result<()> {
+    if !cfg.has_api_keys() {
+        bail!("error tracking requires API key authentication (DD_API_KEY + DD_APP_KEY)");
+    }
+    let state = parse_issue_state(status)?;
+    let issue_ids = search_issue_ids(cfg, query, from, to).await?;
+    if issue_ids.is_empty() {
+        println!("No error tracking issues found matching the specified criteria.");
+        return Ok(());
+    }
+    if !cfg.auto_approve {
+        eprint!(
+            "Set {} issue(s) to {state}? Type 'yes' to confirm: ",
+            issue_ids.len()
+        );
+        let mut input = String::new();
+        std::io::stdin().read_line(&mut input)?;
+        if input.trim() != "yes" {
+            println!("Operation cancelled.");
+            return Ok(());
+        }
+    }
+    let mut failed = 0;
+    for id in &issue_ids {
+        if let Err(e) = update_issue_state(cfg, id, &state).await {
+            eprintln!("{e}");
+            failed += 1;
+        }
+    }
+    let updated = issue_ids.len() - failed;
+    println!("Updated {updated} issue(s) to {state}.");
+    if failed > 0 {
+        bail!("{failed} of {} issue(s) failed to update", issue_ids.len());
+    }
+    Ok(())
+}
+
+#[derive(serde::Deserialize)]
+struct IssueStatusUpdate {
+    issue_id: String,
+    status: String,
+}
+
+/// Apply status updates from a JSON file of `[{"issue_id": ..., "status": ...}]`.
+/// Every entry is attempted; failures are reported at the end.
+pub async fn issues_bulk(cfg: &Config, file: &str) -> Result<()> {
+    if !cfg.has_api_keys() {
+        bail!("error tracking requires API key authentication (DD_API_KEY + DD_APP_KEY)");
+    }
+    let updates: Vec<IssueStatusUpdate> = crate::util::read_json_file(file)?;
+    let states = updates
+        .iter()
+        .map(|u| parse_issue_state(&u.status))
+        .collect::<Result<Vec<_>>>()?;
+    let mut failed = 0;
+    for (update, state) in updates.iter().zip(&states) {
+        if let Err(e) = update_issue_state(cfg, &update.issue_id, state).await {
+            eprintln!("{e}");
+            failed += 1;
+        }
+    }
+    let updated = updates.len() - failed;
+    println!("Updated {updated} issue(s).");
+    if failed > 0 {
+        bail!("{failed} of {} issue(s) failed to update", updates.len());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_issue_state() {
+        assert_eq!(parse_issue_state("resolved").unwrap(), "RESOLVED");
+        assert_eq!(parse_issue_state(" Ignored ").unwrap(), "IGNORED");
+        assert!(parse_issue_state("closed").is_err());
+    }
+
+    #[test]
+    fn test_issue_state_body() {
+        let body = issue_state_body("abc", "RESOLVED");
+        assert_eq!(body["data"]["id"], "abc");
+        assert_eq!(body["data"]["attributes"]["state"], "RESOLVED");
+        assert_eq!(body["data"]["type"], "error_tracking_issue");
+    }
+}
@@ -39,7 +39,7 @@ pub async fn list(cfg: &Config, start: i64, end: i64, tags: Option<String>) -> R
         .list_events(start, end, params)
         .await
         .map_err(|e| anyhow::anyhow!("failed to list events: {e:?}"))?;
-    formatter::output(cfg, &resp)
+    print_events(cfg, &resp)
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -54,7 +54,49 @@ pub async fn list(cfg: &Config, start: i64, end: i64, tags: Option<String>) -> R
         query_params.push(("tags", t));
     }
     let data = crate::api::get(cfg, "/api/v1/events", &query_params).await?;
-    crate::formatter::output(cfg, &data)
+    print_events(cfg, &data)
+}
+
+/// Longest title or text shown in an events table cell.
+const EVENT_TEXT_MAX_CHARS: usize = 60;
+
+fn event_rows(resp: &serde_json::Value) -> Vec<Vec<String>> {
+    let text_at = |e: &serde_json::Value, key: &str| {
+        let s = e[key].as_str().unwrap_or_default().replace('\n', " ");
+        formatter::truncate(s, Some(EVENT_TEXT_MAX_CHARS))
+    };
+    resp.get("events")
+        .and_then(|d| d.as_array())
+        .into_iter()
+        .flatten()
+        .map(|e| {
+            vec![
+                e["id_str"]
+                    .as_str()
+                    .map(str::to_string)
+                    .or_else(|| e["id"].as_i64().map(|id| id.to_string()))
+                    .unwrap_or_default(),
+                text_at(e, "title"),
+                text_at(e, "text"),
+                formatter::str_at(e, "/source_type_name"),
+                formatter::join_tags(e, "/tags"),
+                e["date_happened"]
+                    .as_i64()
+                    .and_then(|ts| chrono::DateTime::from_timestamp(ts, 0))
+                    .map(|dt| dt.format("%Y-%m-%d %H:%M:%S UTC").to_string())
+                    .unwrap_or_default(),
+            ]
+        })
+        .collect()
+}
+
+fn print_events<T: serde::Serialize>(cfg: &Config, resp: &T) -> Result<()> {
+    formatter::output_rows(
+        cfg,
+        resp,
+        &["id", "title", "text", "source", "tags", "date_happened"],
+        event_rows,
+    )
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -155,3 +197,40 @@ pub async fn get(cfg: &Config, id: i64) -> Result<()> {
     let data = crate::api::get(cfg, &path, &[]).await?;
     crate::formatter::output(cfg, &data)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_rows() {
+        let resp = serde_json::json!({
+            "events": [{
+                "id": 7,
+                "id_str": "7000000000000000007",
+                "title": "Deploy of web-app finished with a very long title that keeps going on",
+                "text": "line one\nline two",
+                "source_type_name": "jenkins",
+                "tags": ["env:prod", "service:web-app"],
+                "date_happened": 1_767_225_600
+            }, {
+                "id": 8
+            }]
+        });
+        let rows = event_rows(&resp);
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0][0], "7000000000000000007");
+        assert_eq!(rows[0][1].chars().count(), EVENT_TEXT_MAX_CHARS);
+        assert!(rows[0][1].ends_with("..."));
+        assert_eq!(
+            rows[0][2..],
+            [
+                "line one line two",
+                "jenkins",
+                "env:prod,service:web-app",
+                "2026-01-01 00:00:00 UTC"
+            ]
+        );
+        assert_eq!(rows[1], ["8", "", "", "", "", ""]);
+    }
+}
@@ -242,24 +242,354 @@ pub async fn deployments_cancel(cfg: &Config, deployment_id: &str) -> Result<()>
 
 #[cfg(not(target_arch = "wasm32"))]
 pub async fn deployments_configure(cfg: &Config, file: &str) -> Result<()> {
+    let body: serde_json::Value = util::read_json_file(file)?;
+    deployments_configure_payload(cfg, body).await
+}
+
+/// Create a configure deployment and return the API response.
+#[cfg(not(target_arch = "wasm32"))]
+async fn create_configure_deployment(
+    cfg: &Config,
+    body: serde_json::Value,
+) -> Result<serde_json::Value> {
     let dd_cfg = client::make_dd_config(cfg);
     let api = match client::make_bearer_client(cfg) {
         Some(c) => FleetAutomationAPI::with_client_and_config(dd_cfg, c),
         None => FleetAutomationAPI::with_config(dd_cfg),
     };
-    let body = util::read_json_file(file)?;
+    let body = serde_json::from_value(body)
+        .map_err(|e| anyhow::anyhow!("invalid deployment configuration: {e}"))?;
     let resp = api
         .create_fleet_deployment_configure(body)
         .await
         .map_err(|e| anyhow::anyhow!("failed to configure deployment: {e:?}"))?;
-    formatter::output(cfg, &resp)
+    Ok(serde_json::to_value(resp)?)
 }
 
 #[cfg(target_arch = "wasm32")]
 pub async fn deployments_configure(cfg: &Config, file: &str) -> Result<()> {
     let body: serde_json::Value = crate::util::read_json_file(file)?;
-    let data = crate::api::post(cfg, "/api/v2/fleet/deployments/configure", &body).await?;
-    crate::formatter::output(cfg, &data)
+    deployments_configure_payload(cfg, body).await
+}
+
+#[cfg(target_arch = "wasm32")]
+async fn create_configure_deployment(
+    cfg: &Config,
+    body: serde_json::Value,
+) -> Result<serde_json::Value> {
+    crate::api::post(cfg, "/api/v2/fleet/deployments/configure", &body)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to configure deployment: {e:?}"))
+}
+
+async fn deployments_configure_payload(cfg: &Config, body: serde_json::Value) -> Result<()> {
+    let data = create_configure_deployment(cfg, body).await?;
+    formatter::output(cfg, &data)
+}
+
+/// Build a configure deployment that merge-patches a single `datadog.yaml` key
+/// on every agent matching `filter_tags`. Dotted keys (`apm_config.enabled`)
+/// become nested objects; values are parsed as JSON when possible (`true`,
+/// `5`) and otherwise sent as strings. `null` is rejected because merge-patch
+/// would delete the key.
+fn config_update_body(
+    filter_tags: Option<&str>,
+    config_key: &str,
+    config_value: &str,
+) -> Result<serde_json::Value> {
+    let segments: Vec<&str> = config_key.split('.').map(str::trim).collect();
+    if segments.iter().any(|s| s.is_empty()) {
+        anyhow::bail!("invalid config key: {config_key:?}");
+    }
+    let mut patch = serde_json::from_str(config_value)
+        .unwrap_or_else(|_| serde_json::Value::String(config_value.to_string()));
+    if patch.is_null() {
+        anyhow::bail!(
+            "--config-value=null would delete {config_key} from datadog.yaml, which is not \
+             supported; pass '\"null\"' to set the literal string"
+        );
+    }
+    for segment in segments.iter().rev() {
+        let mut obj = serde_json::Map::new();
+        obj.insert(segment.to_string(), patch);
+        patch = serde_json::Value::Object(obj);
+    }
+    Ok(datadog_yaml_patch_body(filter_tags, patch))
+}
+
+/// A configure deployment that merge-patches `datadog.yaml` on every agent
+/// matching `filter_query`.
+fn datadog_yaml_patch_body(
+    filter_query: Option<&str>,
+    patch: serde_json::Value,
+) -> serde_json::Value {
+    serde_json::json!({
+        "data": {
+            "type": "deployment",
+            "attributes": {
+                "filter_query": filter_query.unwrap_or_default(),
+                "config_operations": [{
+                    "file_op": "merge-patch",
+                    "file_path": "/datadog.yaml",
+                    "patch": patch
+                }]
+            }
+        }
+    })
+}
+
+/// Trim and validate the tags given to `bulk-update-tags`.
+fn parse_new_tags(new_tags: &[String]) -> Result<Vec<String>> {
+    let tags: Vec<String> = new_tags
+        .iter()
+        .map(|t| t.trim())
+        .filter(|t| !t.is_empty())
+        .map(str::to_string)
+        .collect();
+    if tags.is_empty() {
+        anyhow::bail!("--new-tags must list at least one tag");
+    }
+    if let Some(bad) = tags.iter().find(|t| t.chars().any(char::is_whitespace)) {
+        anyhow::bail!("invalid tag {bad:?}: tags cannot contain spaces");
+    }
+    Ok(tags)
+}
+
+/// Build the deployment that sets host tags in `datadog.yaml` on agents
+/// matching `filter_query`. Fleet has no per-agent tag endpoint and
+/// merge-patch replaces arrays, so `tags` is written in full and
+/// `extra_tags` is removed.
+fn tags_update_body(filter_query: &str, tags: &[String]) -> serde_json::Value {
+    datadog_yaml_patch_body(
+        Some(filter_query),
+        serde_json::json!({ "tags": tags, "extra_tags": null }),
+    )
+}
+
+/// Append the tags in `new_tags` that `current` does not already have.
+fn merge_tags(current: &[String], new_tags: &[String]) -> Vec<String> {
+    let mut merged = current.to_vec();
+    for tag in new_tags {
+        if !merged.contains(tag) {
+            merged.push(tag.clone());
+        }
+    }
+    merged
+}
+
+/// Page size used when listing agents whose tags are merged.
+const AGENT_TAGS_PAGE_SIZE: i64 = 100;
+
+/// Render a fleet agent tag as `key:value`, or `key` when it has no value.
+fn agent_tag(key: &str, value: Option<&str>) -> String {
+    match value {
+        Some(v) if !v.is_empty() => format!("{key}:{v}"),
+        _ => key.to_string(),
+    }
+}
+
+/// List the hostname and current tags of every agent matching `filter_tags`.
+#[cfg(not(target_arch = "wasm32"))]
+async fn list_agent_tags(cfg: &Config, filter_tags: &str) -> Result<Vec<(String, Vec<String>)>> {
+    let dd_cfg = client::make_dd_config(cfg);
+    let api = match client::make_bearer_client(cfg) {
+        Some(c) => FleetAutomationAPI::with_client_and_config(dd_cfg, c),
+        None => FleetAutomationAPI::with_config(dd_cfg),
+    };
+    let mut agents = Vec::new();
+    for page in 0.. {
+        let params = ListFleetAgentsOptionalParams::default()
+            .page_size(AGENT_TAGS_PAGE_SIZE)
+            .page_number(page)
+            .filter(filter_tags.to_string());
+        let resp = api
+            .list_fleet_agents(params)
+            .await
+            .map_err(|e| anyhow::anyhow!("failed to list fleet agents: {e:?}"))?;
+        let batch = resp.data.attributes.agents.unwrap_or_default();
+        let last_page = (batch.len() as i64) < AGENT_TAGS_PAGE_SIZE;
+        for agent in batch {
+            let Some(hostname) = agent.hostname else {
+                continue;
+            };
+            let tags = agent
+                .tags
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|t| Some(agent_tag(&t.key?, t.value.as_deref())))
+                .collect();
+            agents.push((hostname, tags));
+        }
+        if last_page {
+            break;
+        }
+    }
+    Ok(agents)
+}
+
+#[cfg(target_arch = "wasm32")]
+async fn list_agent_tags(cfg: &Config, filter_tags: &str) -> Result<Vec<(String, Vec<String>)>> {
+    let mut agents = Vec::new();
+    for page in 0.. {
+        let query = vec![
+            ("page_size", AGENT_TAGS_PAGE_SIZE.to_string()),
+            ("page_number", page.to_string()),
+            ("filter", filter_tags.to_string()),
+        ];
+        let data = crate::api::get(cfg, "/api/v2/fleet/agents", &query).await?;
+        let batch = data
+            .pointer("/data/attributes/agents")
+            .and_then(|a| a.as_array())
+            .cloned()
+            .unwrap_or_default();
+        let last_page = (batch.len() as i64) < AGENT_TAGS_PAGE_SIZE;
+        for agent in batch {
+            let Some(hostname) = agent.get("hostname").and_then(|h| h.as_str()) else {
+                continue;
+            };
+            let tags = agent
+                .get("tags")
+                .and_then(|t| t.as_array())
+                .map(|items| {
+                    items
+                        .iter()
+                        .filter_map(|t| {
+                            let key = t.get("key")?.as_str()?;
+                            Some(agent_tag(key, t.get("value").and_then(|v| v.as_str())))
+                        })
+                        .collect()
+                })
+                .unwrap_or_default();
+            agents.push((hostname.to_string(), tags));
+        }
+        if last_page {
+            break;
+        }
+    }
+    Ok(agents)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+async fn count_matching_agents(cfg: &Config, filter_tags: &str) -> Result<i64> {
+    let dd_cfg = client::make_dd_config(cfg);
+    let api = match client::make_bearer_client(cfg) {
+        Some(c) => FleetAutomationAPI::with_client_and_config(dd_cfg, c),
+        None => FleetAutomationAPI::with_config(dd_cfg),
+    };
+    let mut params = ListFleetAgentsOptionalParams::default().page_size(1);
+    if !filter_tags.is_empty() {
+        params = params.filter(filter_tags.to_string());
+    }
+    let resp = api
+        .list_fleet_agents(params)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to list fleet agents: {e:?}"))?;
+    let listed = resp.data.attributes.agents.map_or(0, |a| a.len() as i64);
+    Ok(resp
+        .meta
+        .and_then(|m| m.total_filtered_count)
+        .unwrap_or(listed))
+}
+
+#[cfg(target_arch = "wasm32")]
+async fn count_matching_agents(cfg: &Config, filter_tags: &str) -> Result<i64> {
+    let mut query = vec![("page_size", "1".to_string())];
+    if !filter_tags.is_empty() {
+        query.push(("filter", filter_tags.to_string()));
+    }
+    let data = crate::api::get(cfg, "/api/v2/fleet/agents", &query).await?;
+    let listed = data
+        .pointer("/data/attributes/agents")
+        .and_then(|a| a.as_array())
+        .map_or(0, |a| a.len() as i64);
+    Ok(data
+        .pointer("/meta/total_filtered_count")
+        .and_then(|n| n.as_i64())
+        .unwrap_or(listed))
+}
+
+/// Count the agents matching `filter_tags` (all agents when empty) and ask
+/// the user to confirm `action` on them. Returns false when nothing matches
+/// or the user declines.
+async fn confirm_agent_change(cfg: &Config, filter_tags: &str, action: &str) -> Result<bool> {
+    let matched = count_matching_agents(cfg, filter_tags).await?;
+    let target = if filter_tags.is_empty() {
+        "ALL agents in the org".to_string()
+    } else {
+        format!("agents matching {filter_tags:?}")
+    };
+    if matched == 0 {
+        println!("No fleet {target} found.");
+        return Ok(false);
+    }
+    if !cfg.auto_approve {
+        eprint!("{action} on {matched} {target}? Type 'yes' to confirm: ");
+        let mut input = String::new();
+        std::io::stdin().read_line(&mut input)?;
+        if input.trim() != "yes" {
+            println!("Operation cancelled.");
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+/// Set host tags on every agent matching `filter_tags`, after confirming how
+/// many agents will be affected. With `replace` a single deployment writes
+/// the same tags everywhere. Otherwise each agent's current tags are fetched
+/// and a deployment per host writes the union; every host is attempted and
+/// failures are reported at the end.
+pub async fn agents_bulk_update_tags(
+    cfg: &Config,
+    filter_tags: &str,
+    new_tags: &[String],
+    replace: bool,
+) -> Result<()> {
+    let filter_tags = filter_tags.trim();
+    if filter_tags.is_empty() {
+        anyhow::bail!("--filter-tags must not be empty");
+    }
+    let tags = parse_new_tags(new_tags)?;
+    let action = if replace { "Replace tags" } else { "Add tags" };
+    if !confirm_agent_change(cfg, filter_tags, action).await? {
+        return Ok(());
+    }
+    if replace {
+        return deployments_configure_payload(cfg, tags_update_body(filter_tags, &tags)).await;
+    }
+    let agents = list_agent_tags(cfg, filter_tags).await?;
+    let mut failed = 0;
+    for (hostname, current) in &agents {
+        let body = tags_update_body(&format!("hostname:{hostname}"), &merge_tags(current, &tags));
+        if let Err(e) = create_configure_deployment(cfg, body).await {
+            eprintln!("{hostname}: {e}");
+            failed += 1;
+        }
+    }
+    let updated = agents.len() - failed;
+    println!("Created tag deployments for {updated} agent(s).");
+    if failed > 0 {
+        anyhow::bail!("{failed} of {} agent(s) failed to update", agents.len());
+    }
+    Ok(())
+}
+
+/// Push a single config key to all agents matching `filter_tags` via a
+/// configure deployment, after confirming how many agents will be affected.
+/// Without `filter_tags` every agent in the org is targeted.
+pub async fn agents_update_config(
+    cfg: &Config,
+    filter_tags: Option<String>,
+    config_key: &str,
+    config_value: &str,
+) -> Result<()> {
+    let filter_tags = filter_tags.as_deref().map(str::trim).unwrap_or_default();
+    let body = config_update_body(Some(filter_tags), config_key, config_value)?;
+    let action = format!("Set {config_key}={config_value} in datadog.yaml");
+    if !confirm_agent_change(cfg, filter_tags, &action).await? {
+        return Ok(());
+    }
+    deployments_configure_payload(cfg, body).await
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -328,3 +658,87 @@ pub async fn schedules_trigger(cfg: &Config, schedule_id: &str) -> Result<()> {
     println!("Schedule {schedule_id} triggered.");
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_update_body_nested_key() {
+        let body = config_update_body(Some("env:prod"), "apm_config.enabled", "true").unwrap();
+        let attrs = &body["data"]["attributes"];
+        assert_eq!(attrs["filter_query"], "env:prod");
+        assert_eq!(
+            attrs["config_operations"][0]["patch"],
+            serde_json::json!({ "apm_config": { "enabled": true } })
+        );
+    }
+
+    #[test]
+    fn test_config_update_body_string_value() {
+        let body = config_update_body(None, "log_level", "debug").unwrap();
+        let attrs = &body["data"]["attributes"];
+        assert_eq!(attrs["filter_query"], "");
+        assert_eq!(
+            attrs["config_operations"][0]["patch"],
+            serde_json::json!({ "log_level": "debug" })
+        );
+    }
+
+    #[test]
+    fn test_config_update_body_rejects_empty_segment() {
+        assert!(config_update_body(None, "apm_config.", "true").is_err());
+    }
+
+    #[test]
+    fn test_config_update_body_rejects_null() {
+        let err = config_update_body(None, "log_level", "null")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("would delete log_level"), "{err}");
+        let body = config_update_body(None, "log_level", "\"null\"").unwrap();
+        assert_eq!(
+            body["data"]["attributes"]["config_operations"][0]["patch"],
+            serde_json::json!({ "log_level": "null" })
+        );
+    }
+
+    #[test]
+    fn test_tags_update_body() {
+        let tags = vec!["team:core".to_string(), "tier:1".to_string()];
+        let body = tags_update_body("env:prod", &tags);
+        let attrs = &body["data"]["attributes"];
+        assert_eq!(attrs["filter_query"], "env:prod");
+        assert_eq!(
+            attrs["config_operations"][0]["patch"],
+            serde_json::json!({ "tags": ["team:core", "tier:1"], "extra_tags": null })
+        );
+    }
+
+    #[test]
+    fn test_merge_tags_keeps_existing_tags() {
+        let current = vec!["env:prod".to_string(), "team:old".to_string()];
+        let new_tags = vec!["team:core".to_string(), "env:prod".to_string()];
+        assert_eq!(
+            merge_tags(&current, &new_tags),
+            vec!["env:prod", "team:old", "team:core"]
+        );
+    }
+
+    #[test]
+    fn test_agent_tag() {
+        assert_eq!(agent_tag("env", Some("prod")), "env:prod");
+        assert_eq!(agent_tag("canary", Some("")), "canary");
+        assert_eq!(agent_tag("canary", None), "canary");
+    }
+
+    #[test]
+    fn test_parse_new_tags() {
+        assert_eq!(
+            parse_new_tags(&[" team:core ".to_string(), String::new()]).unwrap(),
+            vec!["team:core"]
+        );
+        assert!(parse_new_tags(&[]).is_err());
+        assert!(parse_new_tags(&["team core".to_string()]).is_err());
+    }
+}
@@ -26,7 +26,7 @@ pub async fn connections_get(cfg: &Config) -> Result<()> {
 
 #[cfg(target_arch = "wasm32")]
 pub async fn connections_get(cfg: &Config) -> Result<()> {
-    let data = crate::api::get(cfg, "/api/v2/hamr/connections", &[]).await?;
+    let data = crate::api::get(cfg, "/api/v2/hamr", &[]).await?;
     crate::formatter::output(cfg, &data)
 }
 
@@ -48,6 +48,12 @@ pub async fn connections_create(cfg: &Config, file: &str) -> Result<()> {
 #[cfg(target_arch = "wasm32")]
 pub async fn connections_create(cfg: &Config, file: &str) -> Result<()> {
     let body: serde_json::Value = util::read_json_file(file)?;
-    let data = crate::api::post(cfg, "/api/v2/hamr/connections", &body).await?;
+    let data = crate::api::post(cfg, "/api/v2/hamr", &body).await?;
     crate::formatter::output(cfg, &data)
 }
+
+/// The HAMR API has no update operation; creating a connection replaces the
+/// existing one, so updates are sent as a create.
+pub async fn connections_update(cfg: &Config, file: &str) -> Result<()> {
+    connections_create(cfg, file).await
+}
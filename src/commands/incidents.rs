@@ -3,8 +3,10 @@ use anyhow::{bail, Result};
 use datadog_api_client::datadogV2::api_incidents::{
     CreateGlobalIncidentHandleOptionalParams, GetIncidentOptionalParams, IncidentsAPI,
     ListGlobalIncidentHandlesOptionalParams, ListIncidentAttachmentsOptionalParams,
-    ListIncidentsOptionalParams, UpdateGlobalIncidentHandleOptionalParams,
+    UpdateGlobalIncidentHandleOptionalParams,
 };
+#[cfg(not(target_arch = "wasm32"))]
+use datadog_api_client::datadogV2::model::IncidentIntegrationMetadataCreateRequest;
 
 #[cfg(not(target_arch = "wasm32"))]
 use crate::client;
@@ -29,23 +31,42 @@ fn make_api(cfg: &Config) -> IncidentsAPI {
 // Core incident operations
 // ---------------------------------------------------------------------------
 
-#[cfg(not(target_arch = "wasm32"))]
-pub async fn list(cfg: &Config, limit: i64) -> Result<()> {
-    let api = make_api(cfg);
-    let params = ListIncidentsOptionalParams::default().page_size(limit);
-    let resp = api
-        .list_incidents(params)
-        .await
-        .map_err(|e| anyhow::anyhow!("failed to list incidents: {:?}", e))?;
-    formatter::output(cfg, &resp)?;
-    Ok(())
+/// Maximum page size accepted by the v2 incidents list endpoint.
+const INCIDENTS_MAX_PAGE_SIZE: i64 = 25;
+
+fn incidents_next_offset(resp: &serde_json::Value) -> Option<i64> {
+    resp.pointer("/meta/pagination/next_offset")
+        .and_then(|o| o.as_i64())
 }
 
-#[cfg(target_arch = "wasm32")]
+/// List up to `limit` incidents, following the pagination offset when the limit
+/// exceeds the per-page maximum.
 pub async fn list(cfg: &Config, limit: i64) -> Result<()> {
-    let query_params = vec![("page[size]", limit.to_string())];
-    let data = crate::api::get(cfg, "/api/v2/incidents", &query_params).await?;
-    crate::formatter::output(cfg, &data)
+    let mut incidents: Vec<serde_json::Value> = Vec::new();
+    let mut offset = 0;
+    while (incidents.len() as i64) < limit {
+        let page_size = (limit - incidents.len() as i64).min(INCIDENTS_MAX_PAGE_SIZE);
+        let query = vec![
+            ("page[size]", page_size.to_string()),
+            ("page[offset]", offset.to_string()),
+        ];
+        let resp = crate::api::get(cfg, "/api/v2/incidents", &query)
+            .await
+            .map_err(|e| anyhow::anyhow!("failed to list incidents: {e:?}"))?;
+        let page = resp
+            .get("data")
+            .and_then(|d| d.as_array())
+            .cloned()
+            .unwrap_or_default();
+        let fetched = page.len() as i64;
+        incidents.extend(page);
+        match incidents_next_offset(&resp) {
+            Some(next) if fetched > 0 && next > offset => offset = next,
+            _ => break,
+        }
+    }
+    incidents.truncate(limit.max(0) as usize);
+    formatter::output(cfg, &serde_json::json!({ "data": incidents }))
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -351,3 +372,43 @@ pub async fn postmortem_templates_delete(cfg: &Config, template_id: &str) -> Res
     println!("Postmortem template {template_id} deleted.");
     Ok(())
 }
+
+// ---------------------------------------------------------------------------
+// Integrations
+// ---------------------------------------------------------------------------
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn integrations_list(cfg: &Config, incident_id: &str) -> Result<()> {
+    let api = make_api(cfg);
+    let resp = api
+        .list_incident_integrations(incident_id.to_string())
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to list incident integrations: {:?}", e))?;
+    formatter::output(cfg, &resp)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn integrations_list(cfg: &Config, incident_id: &str) -> Result<()> {
+    let path = format!("/api/v2/incidents/{incident_id}/relationships/integrations");
+    let data = crate::api::get(cfg, &path, &[]).await?;
+    crate::formatter::output(cfg, &data)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn integrations_create(cfg: &Config, incident_id: &str, file: &str) -> Result<()> {
+    let body: IncidentIntegrationMetadataCreateRequest = util::read_json_file(file)?;
+    let api = make_api(cfg);
+    let resp = api
+        .create_incident_integration(incident_id.to_string(), body)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to create incident integration: {:?}", e))?;
+    formatter::output(cfg, &resp)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn integrations_create(cfg: &Config, incident_id: &str, file: &str) -> Result<()> {
+    let body: serde_json::Value = util::read_json_file(file)?;
+    let path = format!("/api/v2/incidents/{incident_id}/relationships/integrations");
+    let data = crate::api::post(cfg, &path, &body).await?;
+    crate::formatter::output(cfg, &data)
+}
@@ -1,6 +1,8 @@
-use anyhow::Result;
+use anyhow::{bail, Result};
 #[cfg(not(target_arch = "wasm32"))]
 use datadog_api_client::datadogV1::api_hosts::{HostsAPI, ListHostsOptionalParams};
+#[cfg(not(target_arch = "wasm32"))]
+use datadog_api_client::datadogV2::api_containers::{ContainersAPI, ListContainersOptionalParams};
 
 #[cfg(not(target_arch = "wasm32"))]
 use crate::client;
@@ -72,3 +74,83 @@ pub async fn hosts_get(cfg: &Config, hostname: &str) -> Result<()> {
     let data = crate::api::get(cfg, "/api/v1/hosts", &query).await?;
     crate::formatter::output(cfg, &data)
 }
+
+#[cfg(not(target_arch = "wasm32"))]
+fn make_containers_api(cfg: &Config) -> ContainersAPI {
+    let dd_cfg = client::make_dd_config(cfg);
+    match client::make_bearer_client(cfg) {
+        Some(c) => ContainersAPI::with_client_and_config(dd_cfg, c),
+        None => ContainersAPI::with_config(dd_cfg),
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn containers_list(
+    cfg: &Config,
+    filter: Option<String>,
+    sort: Option<String>,
+    page_size: i32,
+) -> Result<()> {
+    let api = make_containers_api(cfg);
+    let mut params = ListContainersOptionalParams::default().page_size(page_size);
+    if let Some(f) = filter {
+        params = params.filter_tags(f);
+    }
+    if let Some(s) = sort {
+        params = params.sort(s);
+    }
+    let resp = api
+        .list_containers(params)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to list containers: {e:?}"))?;
+    formatter::output(cfg, &resp)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn containers_list(
+    cfg: &Config,
+    filter: Option<String>,
+    sort: Option<String>,
+    page_size: i32,
+) -> Result<()> {
+    let mut query = vec![("page[size]", page_size.to_string())];
+    if let Some(f) = filter {
+        query.push(("filter[tags]", f));
+    }
+    if let Some(s) = sort {
+        query.push(("sort", s));
+    }
+    let data = crate::api::get(cfg, "/api/v2/containers", &query).await?;
+    crate::formatter::output(cfg, &data)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn containers_get(cfg: &Config, container_id: &str) -> Result<()> {
+    // The containers API has no get-by-id endpoint; filter the list on the
+    // container_id tag instead.
+    let api = make_containers_api(cfg);
+    let params = ListContainersOptionalParams::default()
+        .filter_tags(format!("container_id:{container_id}"))
+        .page_size(1);
+    let resp = api
+        .list_containers(params)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to get container {container_id}: {e:?}"))?;
+    let Some(container) = resp.data.and_then(|d| d.into_iter().next()) else {
+        bail!("container {container_id} not found");
+    };
+    formatter::output(cfg, &container)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn containers_get(cfg: &Config, container_id: &str) -> Result<()> {
+    let query = vec![
+        ("filter[tags]", format!("container_id:{container_id}")),
+        ("page[size]", "1".to_string()),
+    ];
+    let data = crate::api::get(cfg, "/api/v2/containers", &query).await?;
+    let Some(container) = data["data"].as_array().and_then(|d| d.first()) else {
+        bail!("container {container_id} not found");
+    };
+    crate::formatter::output(cfg, container)
+}
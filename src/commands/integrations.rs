@@ -5,16 +5,28 @@ use crate::formatter;
 use crate::util;
 use anyhow::Result;
 #[cfg(not(target_arch = "wasm32"))]
+use datadog_api_client::datadogV1::api_pager_duty_integration::PagerDutyIntegrationAPI;
+#[cfg(not(target_arch = "wasm32"))]
 use datadog_api_client::datadogV1::api_slack_integration::SlackIntegrationAPI;
 #[cfg(not(target_arch = "wasm32"))]
 use datadog_api_client::datadogV1::api_webhooks_integration::WebhooksIntegrationAPI;
 #[cfg(not(target_arch = "wasm32"))]
+use datadog_api_client::datadogV1::model::{
+    PagerDutyService, PagerDutyServiceKey, SlackIntegrationChannel, WebhooksIntegration,
+    WebhooksIntegrationUpdateRequest,
+};
+#[cfg(not(target_arch = "wasm32"))]
 use datadog_api_client::datadogV2::api_jira_integration::JiraIntegrationAPI;
 #[cfg(not(target_arch = "wasm32"))]
+use datadog_api_client::datadogV2::api_microsoft_teams_integration::{
+    ListTenantBasedHandlesOptionalParams, MicrosoftTeamsIntegrationAPI,
+};
+#[cfg(not(target_arch = "wasm32"))]
 use datadog_api_client::datadogV2::api_service_now_integration::ServiceNowIntegrationAPI;
 #[cfg(not(target_arch = "wasm32"))]
 use datadog_api_client::datadogV2::model::{
     JiraIssueTemplateCreateRequest, JiraIssueTemplateUpdateRequest,
+    MicrosoftTeamsCreateTenantBasedHandleRequest, MicrosoftTeamsUpdateTenantBasedHandleRequest,
     ServiceNowTemplateCreateRequest, ServiceNowTemplateUpdateRequest,
 };
 
@@ -452,6 +464,266 @@ pub async fn slack_list(cfg: &Config) -> Result<()> {
     crate::formatter::output(cfg, &data)
 }
 
+#[cfg(not(target_arch = "wasm32"))]
+fn make_slack_api(cfg: &Config) -> SlackIntegrationAPI {
+    let dd_cfg = client::make_dd_config(cfg);
+    match client::make_bearer_client(cfg) {
+        Some(c) => SlackIntegrationAPI::with_client_and_config(dd_cfg, c),
+        None => SlackIntegrationAPI::with_config(dd_cfg),
+    }
+}
+
+pub async fn slack_workspaces_list(cfg: &Config) -> Result<()> {
+    let data = crate::api::get(cfg, "/api/v1/integration/slack", &[])
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to list Slack workspaces: {e:?}"))?;
+    formatter::output(cfg, &data)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn slack_channels_list(cfg: &Config, workspace_name: &str) -> Result<()> {
+    let resp = make_slack_api(cfg)
+        .get_slack_integration_channels(workspace_name.to_string())
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to list Slack channels: {e:?}"))?;
+    formatter::output(cfg, &resp)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn slack_channels_list(cfg: &Config, workspace_name: &str) -> Result<()> {
+    let path =
+        format!("/api/v1/integration/slack/configuration/accounts/{workspace_name}/channels");
+    let data = crate::api::get(cfg, &path, &[]).await?;
+    crate::formatter::output(cfg, &data)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn slack_channels_get(
+    cfg: &Config,
+    workspace_name: &str,
+    channel_name: &str,
+) -> Result<()> {
+    let resp = make_slack_api(cfg)
+        .get_slack_integration_channel(workspace_name.to_string(), channel_name.to_string())
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to get Slack channel: {e:?}"))?;
+    formatter::output(cfg, &resp)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn slack_channels_get(
+    cfg: &Config,
+    workspace_name: &str,
+    channel_name: &str,
+) -> Result<()> {
+    let path = format!(
+        "/api/v1/integration/slack/configuration/accounts/{workspace_name}/channels/{channel_name}"
+    );
+    let data = crate::api::get(cfg, &path, &[]).await?;
+    crate::formatter::output(cfg, &data)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn slack_channels_create(cfg: &Config, workspace_name: &str, file: &str) -> Result<()> {
+    let body: SlackIntegrationChannel = util::read_json_file(file)?;
+    let resp = make_slack_api(cfg)
+        .create_slack_integration_channel(workspace_name.to_string(), body)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to create Slack channel: {e:?}"))?;
+    formatter::output(cfg, &resp)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn slack_channels_create(cfg: &Config, workspace_name: &str, file: &str) -> Result<()> {
+    let body: serde_json::Value = util::read_json_file(file)?;
+    let path =
+        format!("/api/v1/integration/slack/configuration/accounts/{workspace_name}/channels");
+    let data = crate::api::post(cfg, &path, &body).await?;
+    crate::formatter::output(cfg, &data)
+}
+
+// ---- Microsoft Teams ----
+//
+// The API exposes channels as tenant-based handles; there is no dedicated
+// tenant resource, so tenants are derived from the handles that reference them.
+
+#[cfg(not(target_arch = "wasm32"))]
+fn make_ms_teams_api(cfg: &Config) -> MicrosoftTeamsIntegrationAPI {
+    let dd_cfg = client::make_dd_config(cfg);
+    match client::make_bearer_client(cfg) {
+        Some(c) => MicrosoftTeamsIntegrationAPI::with_client_and_config(dd_cfg, c),
+        None => MicrosoftTeamsIntegrationAPI::with_config(dd_cfg),
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+async fn ms_teams_handles(
+    cfg: &Config,
+    tenant_id: Option<String>,
+    name: Option<String>,
+) -> Result<serde_json::Value> {
+    let mut params = ListTenantBasedHandlesOptionalParams::default();
+    if let Some(tenant_id) = tenant_id {
+        params = params.tenant_id(tenant_id);
+    }
+    if let Some(name) = name {
+        params = params.name(name);
+    }
+    let resp = make_ms_teams_api(cfg)
+        .list_tenant_based_handles(params)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to list Microsoft Teams channels: {e:?}"))?;
+    Ok(serde_json::to_value(&resp)?)
+}
+
+#[cfg(target_arch = "wasm32")]
+async fn ms_teams_handles(
+    cfg: &Config,
+    tenant_id: Option<String>,
+    name: Option<String>,
+) -> Result<serde_json::Value> {
+    let mut query = vec![];
+    if let Some(tenant_id) = tenant_id {
+        query.push(("tenant_id", tenant_id));
+    }
+    if let Some(name) = name {
+        query.push(("name", name));
+    }
+    crate::api::get(
+        cfg,
+        "/api/v2/integration/ms-teams/configuration/tenant-based-handles",
+        &query,
+    )
+    .await
+    .map_err(|e| anyhow::anyhow!("failed to list Microsoft Teams channels: {e:?}"))
+}
+
+/// Group tenant-based handles by tenant, returning one entry per tenant with
+/// its name and the number of configured channels.
+fn ms_teams_tenants(handles: &serde_json::Value) -> Vec<serde_json::Value> {
+    let mut tenants: std::collections::BTreeMap<String, (String, usize)> = Default::default();
+    for h in handles["data"].as_array().into_iter().flatten() {
+        let attrs = &h["attributes"];
+        let Some(id) = attrs["tenant_id"].as_str() else {
+            continue;
+        };
+        let entry = tenants.entry(id.to_string()).or_default();
+        if entry.0.is_empty() {
+            entry.0 = attrs["tenant_name"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string();
+        }
+        entry.1 += 1;
+    }
+    tenants
+        .into_iter()
+        .map(|(id, (name, channels))| {
+            serde_json::json!({"tenant_id": id, "tenant_name": name, "channels": channels})
+        })
+        .collect()
+}
+
+pub async fn ms_teams_tenants_list(cfg: &Config) -> Result<()> {
+    let handles = ms_teams_handles(cfg, None, None).await?;
+    formatter::output(cfg, &ms_teams_tenants(&handles))
+}
+
+pub async fn ms_teams_tenants_get(cfg: &Config, tenant_id: &str) -> Result<()> {
+    let handles = ms_teams_handles(cfg, Some(tenant_id.to_string()), None).await?;
+    let Some(mut tenant) = ms_teams_tenants(&handles).into_iter().next() else {
+        anyhow::bail!("no Microsoft Teams channels configured for tenant {tenant_id}");
+    };
+    tenant["handles"] = handles["data"].clone();
+    formatter::output(cfg, &tenant)
+}
+
+pub async fn ms_teams_channels_list(
+    cfg: &Config,
+    tenant_id: Option<String>,
+    name: Option<String>,
+) -> Result<()> {
+    let handles = ms_teams_handles(cfg, tenant_id, name).await?;
+    formatter::output(cfg, &handles)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn ms_teams_channels_get(cfg: &Config, handle_id: &str) -> Result<()> {
+    let resp = make_ms_teams_api(cfg)
+        .get_tenant_based_handle(handle_id.to_string())
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to get Microsoft Teams channel: {e:?}"))?;
+    formatter::output(cfg, &resp)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn ms_teams_channels_get(cfg: &Config, handle_id: &str) -> Result<()> {
+    let path =
+        format!("/api/v2/integration/ms-teams/configuration/tenant-based-handles/{handle_id}");
+    let data = crate::api::get(cfg, &path, &[]).await?;
+    crate::formatter::output(cfg, &data)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn ms_teams_channels_create(cfg: &Config, file: &str) -> Result<()> {
+    let body: MicrosoftTeamsCreateTenantBasedHandleRequest = util::read_json_file(file)?;
+    let resp = make_ms_teams_api(cfg)
+        .create_tenant_based_handle(body)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to create Microsoft Teams channel: {e:?}"))?;
+    formatter::output(cfg, &resp)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn ms_teams_channels_create(cfg: &Config, file: &str) -> Result<()> {
+    let body: serde_json::Value = util::read_json_file(file)?;
+    let data = crate::api::post(
+        cfg,
+        "/api/v2/integration/ms-teams/configuration/tenant-based-handles",
+        &body,
+    )
+    .await?;
+    crate::formatter::output(cfg, &data)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn ms_teams_channels_update(cfg: &Config, handle_id: &str, file: &str) -> Result<()> {
+    let body: MicrosoftTeamsUpdateTenantBasedHandleRequest = util::read_json_file(file)?;
+    let resp = make_ms_teams_api(cfg)
+        .update_tenant_based_handle(handle_id.to_string(), body)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to update Microsoft Teams channel: {e:?}"))?;
+    formatter::output(cfg, &resp)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn ms_teams_channels_update(cfg: &Config, handle_id: &str, file: &str) -> Result<()> {
+    let body: serde_json::Value = util::read_json_file(file)?;
+    let path =
+        format!("/api/v2/integration/ms-teams/configuration/tenant-based-handles/{handle_id}");
+    let data = crate::api::patch(cfg, &path, &body).await?;
+    crate::formatter::output(cfg, &data)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn ms_teams_channels_delete(cfg: &Config, handle_id: &str) -> Result<()> {
+    make_ms_teams_api(cfg)
+        .delete_tenant_based_handle(handle_id.to_string())
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to delete Microsoft Teams channel: {e:?}"))?;
+    println!("Microsoft Teams channel {handle_id} deleted.");
+    Ok(())
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn ms_teams_channels_delete(cfg: &Config, handle_id: &str) -> Result<()> {
+    let path =
+        format!("/api/v2/integration/ms-teams/configuration/tenant-based-handles/{handle_id}");
+    crate::api::delete(cfg, &path).await?;
+    println!("Microsoft Teams channel {handle_id} deleted.");
+    Ok(())
+}
+
 // ---- PagerDuty ----
 
 pub async fn pagerduty_list(_cfg: &Config) -> Result<()> {
@@ -461,15 +733,125 @@ pub async fn pagerduty_list(_cfg: &Config) -> Result<()> {
     )
 }
 
+#[cfg(not(target_arch = "wasm32"))]
+fn make_pagerduty_api(cfg: &Config) -> PagerDutyIntegrationAPI {
+    let dd_cfg = client::make_dd_config(cfg);
+    match client::make_bearer_client(cfg) {
+        Some(c) => PagerDutyIntegrationAPI::with_client_and_config(dd_cfg, c),
+        None => PagerDutyIntegrationAPI::with_config(dd_cfg),
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn pagerduty_get(cfg: &Config, service_name: &str) -> Result<()> {
+    let api = make_pagerduty_api(cfg);
+    let resp = api
+        .get_pager_duty_integration_service(service_name.to_string())
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to get PagerDuty service: {e:?}"))?;
+    formatter::output(cfg, &resp)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn pagerduty_get(cfg: &Config, service_name: &str) -> Result<()> {
+    let path = format!("/api/v1/integration/pagerduty/configuration/services/{service_name}");
+    let data = crate::api::get(cfg, &path, &[]).await?;
+    crate::formatter::output(cfg, &data)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn pagerduty_create(cfg: &Config, file: &str) -> Result<()> {
+    let body: PagerDutyService = util::read_json_file(file)?;
+    let api = make_pagerduty_api(cfg);
+    let resp = api
+        .create_pager_duty_integration_service(body)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to create PagerDuty service: {e:?}"))?;
+    formatter::output(cfg, &resp)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn pagerduty_create(cfg: &Config, file: &str) -> Result<()> {
+    let body: serde_json::Value = util::read_json_file(file)?;
+    let data = crate::api::post(
+        cfg,
+        "/api/v1/integration/pagerduty/configuration/services",
+        &body,
+    )
+    .await?;
+    crate::formatter::output(cfg, &data)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn pagerduty_update(cfg: &Config, service_name: &str, file: &str) -> Result<()> {
+    let body: PagerDutyServiceKey = util::read_json_file(file)?;
+    let api = make_pagerduty_api(cfg);
+    api.update_pager_duty_integration_service(service_name.to_string(), body)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to update PagerDuty service: {e:?}"))?;
+    println!("PagerDuty service {service_name} updated.");
+    Ok(())
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn pagerduty_update(cfg: &Config, service_name: &str, file: &str) -> Result<()> {
+    let body: serde_json::Value = util::read_json_file(file)?;
+    let path = format!("/api/v1/integration/pagerduty/configuration/services/{service_name}");
+    crate::api::put(cfg, &path, &body).await?;
+    println!("PagerDuty service {service_name} updated.");
+    Ok(())
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn pagerduty_delete(cfg: &Config, service_name: &str) -> Result<()> {
+    let api = make_pagerduty_api(cfg);
+    api.delete_pager_duty_integration_service(service_name.to_string())
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to delete PagerDuty service: {e:?}"))?;
+    println!("PagerDuty service {service_name} deleted.");
+    Ok(())
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn pagerduty_delete(cfg: &Config, service_name: &str) -> Result<()> {
+    let path = format!("/api/v1/integration/pagerduty/configuration/services/{service_name}");
+    crate::api::delete(cfg, &path).await?;
+    println!("PagerDuty service {service_name} deleted.");
+    Ok(())
+}
+
+// Account-level PagerDuty configuration (raw HTTP - not available in typed client)
+
+pub async fn pagerduty_account_get(cfg: &Config) -> Result<()> {
+    let data = crate::api::get(cfg, "/api/v1/integration/pagerduty", &[])
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to get PagerDuty account: {e:?}"))?;
+    formatter::output(cfg, &data)
+}
+
+pub async fn pagerduty_account_update(cfg: &Config, file: &str) -> Result<()> {
+    let body: serde_json::Value = util::read_json_file(file)?;
+    crate::api::put(cfg, "/api/v1/integration/pagerduty", &body)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to update PagerDuty account: {e:?}"))?;
+    println!("PagerDuty account configuration updated.");
+    Ok(())
+}
+
 // ---- Webhooks ----
 
 #[cfg(not(target_arch = "wasm32"))]
-pub async fn webhooks_list(cfg: &Config) -> Result<()> {
+fn make_webhooks_api(cfg: &Config) -> WebhooksIntegrationAPI {
     let dd_cfg = client::make_dd_config(cfg);
-    let api = match client::make_bearer_client(cfg) {
+    match client::make_bearer_client(cfg) {
         Some(c) => WebhooksIntegrationAPI::with_client_and_config(dd_cfg, c),
         None => WebhooksIntegrationAPI::with_config(dd_cfg),
-    };
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn webhooks_list(cfg: &Config) -> Result<()> {
+    let api = make_webhooks_api(cfg);
     let resp = api
         .get_webhooks_integration("main".to_string())
         .await
@@ -487,3 +869,133 @@ pub async fn webhooks_list(cfg: &Config) -> Result<()> {
     .await?;
     crate::formatter::output(cfg, &data)
 }
+
+/// Build a webhook create body from `--file`, or from `--name` and `--url`.
+fn webhook_create_body(
+    file: Option<String>,
+    name: Option<String>,
+    url: Option<String>,
+) -> Result<serde_json::Value> {
+    if let Some(f) = file {
+        return util::read_json_file(&f);
+    }
+    match (name, url) {
+        (Some(name), Some(url)) => Ok(serde_json::json!({ "name": name, "url": url })),
+        _ => anyhow::bail!("--name and --url are required when --file is not given"),
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn webhooks_get(cfg: &Config, webhook_name: &str) -> Result<()> {
+    let api = make_webhooks_api(cfg);
+    let resp = api
+        .get_webhooks_integration(webhook_name.to_string())
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to get webhook: {e:?}"))?;
+    formatter::output(cfg, &resp)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn webhooks_get(cfg: &Config, webhook_name: &str) -> Result<()> {
+    let path = format!("/api/v1/integration/webhooks/configuration/webhooks/{webhook_name}");
+    let data = crate::api::get(cfg, &path, &[]).await?;
+    crate::formatter::output(cfg, &data)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn webhooks_create(
+    cfg: &Config,
+    file: Option<String>,
+    name: Option<String>,
+    url: Option<String>,
+) -> Result<()> {
+    let body: WebhooksIntegration =
+        serde_json::from_value(webhook_create_body(file, name, url)?)
+            .map_err(|e| anyhow::anyhow!("invalid webhook definition: {e}"))?;
+    let api = make_webhooks_api(cfg);
+    let resp = api
+        .create_webhooks_integration(body)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to create webhook: {e:?}"))?;
+    formatter::output(cfg, &resp)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn webhooks_create(
+    cfg: &Config,
+    file: Option<String>,
+    name: Option<String>,
+    url: Option<String>,
+) -> Result<()> {
+    let body = webhook_create_body(file, name, url)?;
+    let data = crate::api::post(
+        cfg,
+        "/api/v1/integration/webhooks/configuration/webhooks",
+        &body,
+    )
+    .await?;
+    crate::formatter::output(cfg, &data)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn webhooks_update(cfg: &Config, webhook_name: &str, file: &str) -> Result<()> {
+    let body: WebhooksIntegrationUpdateRequest = util::read_json_file(file)?;
+    let api = make_webhooks_api(cfg);
+    let resp = api
+        .update_webhooks_integration(webhook_name.to_string(), body)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to update webhook: {e:?}"))?;
+    formatter::output(cfg, &resp)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn webhooks_update(cfg: &Config, webhook_name: &str, file: &str) -> Result<()> {
+    let body: serde_json::Value = util::read_json_file(file)?;
+    let path = format!("/api/v1/integration/webhooks/configuration/webhooks/{webhook_name}");
+    let data = crate::api::put(cfg, &path, &body).await?;
+    crate::formatter::output(cfg, &data)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn webhooks_delete(cfg: &Config, webhook_name: &str) -> Result<()> {
+    let api = make_webhooks_api(cfg);
+    api.delete_webhooks_integration(webhook_name.to_string())
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to delete webhook: {e:?}"))?;
+    println!("Webhook {webhook_name} deleted.");
+    Ok(())
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn webhooks_delete(cfg: &Config, webhook_name: &str) -> Result<()> {
+    let path = format!("/api/v1/integration/webhooks/configuration/webhooks/{webhook_name}");
+    crate::api::delete(cfg, &path).await?;
+    println!("Webhook {webhook_name} deleted.");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ms_teams_tenants_groups_handles() {
+        let handles = serde_json::json!({"data": [
+            {"id": "h1", "attributes": {"tenant_id": "t1", "tenant_name": "Acme"}},
+            {"id": "h2", "attributes": {"tenant_id": "t2", "tenant_name": "Globex"}},
+            {"id": "h3", "attributes": {"tenant_id": "t1", "tenant_name": "Acme"}},
+            {"id": "h4", "attributes": {}}
+        ]});
+        let tenants = ms_teams_tenants(&handles);
+        assert_eq!(tenants.len(), 2);
+        assert_eq!(tenants[0]["tenant_id"], "t1");
+        assert_eq!(tenants[0]["tenant_name"], "Acme");
+        assert_eq!(tenants[0]["channels"], 2);
+        assert_eq!(tenants[1]["tenant_id"], "t2");
+    }
+
+    #[test]
+    fn test_ms_teams_tenants_empty() {
+        assert!(ms_teams_tenants(&serde_json::json!({})).is_empty());
+    }
+}
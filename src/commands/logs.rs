@@ -1,5 +1,9 @@
 use anyhow::{bail, Result};
 #[cfg(not(target_arch = "wasm32"))]
+use datadog_api_client::datadogV1::api_logs_pipelines::LogsPipelinesAPI;
+#[cfg(not(target_arch = "wasm32"))]
+use datadog_api_client::datadogV1::model::{LogsPipeline, LogsPipelinesOrder};
+#[cfg(not(target_arch = "wasm32"))]
 use datadog_api_client::datadogV2::api_logs::{ListLogsOptionalParams, LogsAPI};
 #[cfg(not(target_arch = "wasm32"))]
 use datadog_api_client::datadogV2::api_logs_archives::LogsArchivesAPI;
@@ -9,7 +13,8 @@ use datadog_api_client::datadogV2::api_logs_custom_destinations::LogsCustomDesti
 use datadog_api_client::datadogV2::api_logs_metrics::LogsMetricsAPI;
 #[cfg(not(target_arch = "wasm32"))]
 use datadog_api_client::datadogV2::model::{
-    LogsAggregateRequest, LogsAggregationFunction, LogsCompute, LogsListRequest,
+    LogsAggregateRequest, LogsAggregationFunction, LogsArchiveOrder, LogsArchiveOrderAttributes,
+    LogsArchiveOrderDefinition, LogsArchiveOrderDefinitionType, LogsCompute, LogsListRequest,
     LogsListRequestPage, LogsQueryFilter, LogsSort,
 };
 
@@ -26,6 +31,7 @@ pub async fn search(
     from: String,
     to: String,
     limit: i32,
+    opts: &LogSearchOptions,
 ) -> Result<()> {
     // Logs search API doesn't support OAuth/bearer - force API keys
     if !cfg.has_api_keys() {
@@ -39,18 +45,13 @@ pub async fn search(
     // Force API key auth only - do NOT use bearer middleware
     let api = LogsAPI::with_config(dd_cfg);
 
-    let from_ms = util::parse_time_to_unix_millis(&from)?;
-    let to_ms = util::parse_time_to_unix_millis(&to)?;
+    let filter: LogsQueryFilter = serde_json::from_value(opts.filter(query, &from, &to)?)?;
+    let sort: LogsSort = serde_json::from_value(serde_json::json!(opts.sort_order()?))?;
 
     let body = LogsListRequest::new()
-        .filter(
-            LogsQueryFilter::new()
-                .query(query)
-                .from(from_ms.to_string())
-                .to(to_ms.to_string()),
-        )
+        .filter(filter)
         .page(LogsListRequestPage::new().limit(limit))
-        .sort(LogsSort::TIMESTAMP_DESCENDING);
+        .sort(sort);
 
     let params = ListLogsOptionalParams::default().body(body);
 
@@ -78,7 +79,13 @@ pub async fn search(
     } else {
         None
     };
-    formatter::format_and_print(&resp, &cfg.output_format, cfg.agent_mode, meta.as_ref())?;
+    formatter::format_and_print(
+        &resp,
+        &cfg.output_format,
+        cfg.agent_mode,
+        meta.as_ref(),
+        cfg.table_max_width,
+    )?;
     Ok(())
 }
 
@@ -89,25 +96,223 @@ pub async fn search(
     from: String,
     to: String,
     limit: i32,
+    opts: &LogSearchOptions,
 ) -> Result<()> {
-    let from_ms = util::parse_time_to_unix_millis(&from)?;
-    let to_ms = util::parse_time_to_unix_millis(&to)?;
     let body = serde_json::json!({
-        "filter": {
-            "query": query,
-            "from": from_ms.to_string(),
-            "to": to_ms.to_string()
-        },
+        "filter": opts.filter(query, &from, &to)?,
         "page": { "limit": limit },
-        "sort": "-timestamp"
+        "sort": opts.sort_order()?
     });
     let data = crate::api::post(cfg, "/api/v2/logs/events/search", &body).await?;
     crate::formatter::output(cfg, &data)
 }
 
+/// Maximum page size accepted by the v2 logs list endpoint.
+const LOGS_MAX_PAGE_SIZE: i32 = 1000;
+
+/// Write one item as a single JSON line and flush so consumers see it at once.
+fn write_ndjson<T: serde::Serialize>(item: &T) -> Result<()> {
+    use std::io::Write;
+    let mut out = std::io::stdout().lock();
+    serde_json::to_writer(&mut out, item)?;
+    writeln!(out)?;
+    out.flush()?;
+    Ok(())
+}
+
+/// Like `search`, but prints each log as NDJSON as soon as its page arrives,
+/// following the pagination cursor until `limit` logs have been written.
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn search_stream(
+    cfg: &Config,
+    query: String,
+    from: String,
+    to: String,
+    limit: i32,
+    opts: &LogSearchOptions,
+) -> Result<()> {
+    if !cfg.has_api_keys() {
+        bail!(
+            "logs search requires API+APP key authentication (DD_API_KEY + DD_APP_KEY).\n\
+             This endpoint does not support bearer token auth."
+        );
+    }
+
+    let dd_cfg = client::make_dd_config(cfg);
+    let api = LogsAPI::with_config(dd_cfg);
+
+    let filter: LogsQueryFilter = serde_json::from_value(opts.filter(query, &from, &to)?)?;
+    let sort: LogsSort = serde_json::from_value(serde_json::json!(opts.sort_order()?))?;
+
+    let mut remaining = limit.max(0);
+    let mut cursor: Option<String> = None;
+    while remaining > 0 {
+        let mut page = LogsListRequestPage::new().limit(remaining.min(LOGS_MAX_PAGE_SIZE));
+        if let Some(c) = cursor.take() {
+            page = page.cursor(c);
+        }
+        let body = LogsListRequest::new()
+            .filter(filter.clone())
+            .page(page)
+            .sort(sort.clone());
+        let resp = api
+            .list_logs(ListLogsOptionalParams::default().body(body))
+            .await
+            .map_err(|e| anyhow::anyhow!("failed to search logs: {:?}", e))?;
+        let logs = resp.data.unwrap_or_default();
+        for log in logs.iter().take(remaining as usize) {
+            write_ndjson(log)?;
+        }
+        remaining -= logs.len() as i32;
+        cursor = resp
+            .meta
+            .and_then(|m| m.page)
+            .and_then(|p| p.after)
+            .filter(|c| !c.is_empty());
+        if logs.is_empty() || cursor.is_none() {
+            break;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn search_stream(
+    cfg: &Config,
+    query: String,
+    from: String,
+    to: String,
+    limit: i32,
+    opts: &LogSearchOptions,
+) -> Result<()> {
+    let mut body = serde_json::json!({
+        "filter": opts.filter(query, &from, &to)?,
+        "page": {},
+        "sort": opts.sort_order()?
+    });
+
+    let mut remaining = limit.max(0);
+    while remaining > 0 {
+        body["page"]["limit"] = serde_json::json!(remaining.min(LOGS_MAX_PAGE_SIZE));
+        let resp = crate::api::post(cfg, "/api/v2/logs/events/search", &body).await?;
+        let logs = resp
+            .get("data")
+            .and_then(|d| d.as_array())
+            .cloned()
+            .unwrap_or_default();
+        for log in logs.iter().take(remaining as usize) {
+            write_ndjson(log)?;
+        }
+        remaining -= logs.len() as i32;
+        match resp
+            .pointer("/meta/page/after")
+            .and_then(|c| c.as_str())
+            .filter(|c| !c.is_empty())
+        {
+            Some(cursor) if !logs.is_empty() => body["page"]["cursor"] = serde_json::json!(cursor),
+            _ => break,
+        }
+    }
+    Ok(())
+}
+
+/// Optional filters and ordering shared by `logs search`, `list` and `query`.
+#[derive(Debug, Clone, Default)]
+pub struct LogSearchOptions {
+    /// Storage tier to search (`indexes`, `online-archives`, `flex`).
+    pub storage: Option<String>,
+    /// Comma-separated index names, searched within the storage tier.
+    pub index: Option<String>,
+    /// Sort order; defaults to newest first.
+    pub sort: Option<String>,
+}
+
+impl LogSearchOptions {
+    /// The v2 search `filter` for `query` between `from` and `to`, with the
+    /// storage tier and index names validated.
+    fn filter(&self, query: String, from: &str, to: &str) -> Result<serde_json::Value> {
+        let mut filter = serde_json::json!({
+            "query": query,
+            "from": util::parse_time_to_unix_millis(from)?.to_string(),
+            "to": util::parse_time_to_unix_millis(to)?.to_string(),
+        });
+        if let Some(tier) = storage_tier(self.storage.as_deref())? {
+            filter["storage_tier"] = serde_json::json!(tier);
+        }
+        if let Some(indexes) = log_indexes(self.index.as_deref())? {
+            filter["indexes"] = serde_json::json!(indexes);
+        }
+        Ok(filter)
+    }
+
+    fn sort_order(&self) -> Result<&'static str> {
+        log_sort(self.sort.as_deref())
+    }
+}
+
+/// Normalize `--sort` to the v2 `sort` values. `@timestamp` spellings and
+/// `asc`/`desc` are accepted as aliases.
+fn log_sort(sort: Option<&str>) -> Result<&'static str> {
+    match sort.map(str::trim) {
+        None | Some("-timestamp" | "-@timestamp" | "desc") => Ok("-timestamp"),
+        Some("timestamp" | "@timestamp" | "asc") => Ok("timestamp"),
+        Some(other) => {
+            bail!("invalid --sort: {other} (use timestamp, -timestamp, @timestamp, or -@timestamp)")
+        }
+    }
+}
+
+/// Storage tiers accepted by the v2 logs search `filter.storage_tier` field.
+const STORAGE_TIERS: &[&str] = &["indexes", "online-archives", "flex"];
+
+fn storage_tier(storage: Option<&str>) -> Result<Option<&str>> {
+    match storage {
+        Some(s) if !STORAGE_TIERS.contains(&s) => {
+            bail!("invalid --storage: {s} (use {})", STORAGE_TIERS.join(", "))
+        }
+        other => Ok(other),
+    }
+}
+
+/// Split `--index` into index names, rejecting values that are clearly not
+/// index names (whitespace, or an index URL pasted from the app).
+fn log_indexes(index: Option<&str>) -> Result<Option<Vec<String>>> {
+    let Some(index) = index else {
+        return Ok(None);
+    };
+    let mut names = Vec::new();
+    for name in index.split(',').map(str::trim).filter(|n| !n.is_empty()) {
+        if name.contains('/') || name.contains(':') {
+            let hint = name
+                .trim_end_matches('/')
+                .rsplit('/')
+                .next()
+                .unwrap_or(name);
+            bail!(
+                "invalid --index {name:?}: pass the index name, not its URL (e.g. --index={hint})"
+            );
+        }
+        if name.chars().any(char::is_whitespace) {
+            bail!("invalid --index {name:?}: index names cannot contain spaces (separate multiple indexes with commas)");
+        }
+        names.push(name.to_string());
+    }
+    if names.is_empty() {
+        bail!("--index must name at least one index");
+    }
+    Ok(Some(names))
+}
+
 /// Alias for `search` with the same interface.
-pub async fn list(cfg: &Config, query: String, from: String, to: String, limit: i32) -> Result<()> {
-    search(cfg, query, from, to, limit).await
+pub async fn list(
+    cfg: &Config,
+    query: String,
+    from: String,
+    to: String,
+    limit: i32,
+    opts: &LogSearchOptions,
+) -> Result<()> {
+    search(cfg, query, from, to, limit, opts).await
 }
 
 /// Alias for `search` with the same interface.
@@ -117,8 +322,9 @@ pub async fn query(
     from: String,
     to: String,
     limit: i32,
+    opts: &LogSearchOptions,
 ) -> Result<()> {
-    search(cfg, query, from, to, limit).await
+    search(cfg, query, from, to, limit, opts).await
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -253,6 +459,105 @@ pub async fn archives_delete(cfg: &Config, archive_id: &str) -> Result<()> {
     Ok(())
 }
 
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn archives_order_get(cfg: &Config) -> Result<()> {
+    if !cfg.has_api_keys() {
+        bail!(
+            "logs archives order requires API key authentication (DD_API_KEY + DD_APP_KEY).\n\
+             This endpoint does not support bearer token auth."
+        );
+    }
+
+    let dd_cfg = client::make_dd_config(cfg);
+    let api = LogsArchivesAPI::with_config(dd_cfg);
+
+    let resp = api
+        .get_logs_archive_order()
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to get log archive order: {:?}", e))?;
+
+    formatter::output(cfg, &resp)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn archives_order_get(cfg: &Config) -> Result<()> {
+    let data = crate::api::get(cfg, "/api/v2/logs/config/archive-order", &[]).await?;
+    crate::formatter::output(cfg, &data)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn archives_reorder(cfg: &Config, archive_ids: Vec<String>) -> Result<()> {
+    if !cfg.has_api_keys() {
+        bail!(
+            "logs archives reorder requires API key authentication (DD_API_KEY + DD_APP_KEY).\n\
+             This endpoint does not support bearer token auth."
+        );
+    }
+    if archive_ids.is_empty() {
+        bail!("at least one archive ID is required");
+    }
+
+    let dd_cfg = client::make_dd_config(cfg);
+    let api = LogsArchivesAPI::with_config(dd_cfg);
+
+    let body = LogsArchiveOrder::new().data(LogsArchiveOrderDefinition::new(
+        LogsArchiveOrderAttributes::new(archive_ids),
+        LogsArchiveOrderDefinitionType::ARCHIVE_ORDER,
+    ));
+    let resp = api
+        .update_logs_archive_order(body)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to reorder log archives: {:?}", e))?;
+
+    formatter::output(cfg, &resp)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn archives_reorder(cfg: &Config, archive_ids: Vec<String>) -> Result<()> {
+    if archive_ids.is_empty() {
+        bail!("at least one archive ID is required");
+    }
+    let body = serde_json::json!({
+        "data": {
+            "type": "archive_order",
+            "attributes": { "archive_ids": archive_ids }
+        }
+    });
+    let data = crate::api::put(cfg, "/api/v2/logs/config/archive-order", &body).await?;
+    crate::formatter::output(cfg, &data)
+}
+
+/// Request rehydration of an archive's logs for a time range. Times are sent
+/// as unix milliseconds.
+fn rehydrate_body(from_ms: i64, to_ms: i64, query: Option<String>) -> serde_json::Value {
+    let mut body = serde_json::json!({"from": from_ms, "to": to_ms});
+    if let Some(q) = query {
+        body["query"] = serde_json::Value::from(q);
+    }
+    body
+}
+
+/// Trigger rehydration. The job runs asynchronously on Datadog's side, so this
+/// returns as soon as the request is accepted.
+pub async fn archives_rehydrate(
+    cfg: &Config,
+    archive_id: &str,
+    from: String,
+    to: String,
+    query: Option<String>,
+) -> Result<()> {
+    let from_ms = util::parse_time_to_unix_millis(&from)?;
+    let to_ms = util::parse_time_to_unix_millis(&to)?;
+    if from_ms >= to_ms {
+        bail!("--from must be earlier than --to");
+    }
+    let path = format!("/api/v1/logs/config/archives/{archive_id}/logs/rehydrate");
+    let data = crate::api::post(cfg, &path, &rehydrate_body(from_ms, to_ms, query))
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to rehydrate log archive: {e:?}"))?;
+    formatter::output(cfg, &data)
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 pub async fn custom_destinations_list(cfg: &Config) -> Result<()> {
     if !cfg.has_api_keys() {
@@ -391,6 +696,127 @@ pub async fn metrics_delete(cfg: &Config, metric_id: &str) -> Result<()> {
     Ok(())
 }
 
+// ---------------------------------------------------------------------------
+// Pipelines (v1)
+// ---------------------------------------------------------------------------
+
+#[cfg(not(target_arch = "wasm32"))]
+fn make_pipelines_api(cfg: &Config, op: &str) -> Result<LogsPipelinesAPI> {
+    if !cfg.has_api_keys() {
+        bail!(
+            "logs pipelines {op} requires API key authentication (DD_API_KEY + DD_APP_KEY).\n\
+             This endpoint does not support bearer token auth."
+        );
+    }
+    Ok(LogsPipelinesAPI::with_config(client::make_dd_config(cfg)))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn pipelines_list(cfg: &Config) -> Result<()> {
+    let api = make_pipelines_api(cfg, "list")?;
+    let resp = api
+        .list_logs_pipelines()
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to list log pipelines: {:?}", e))?;
+    formatter::output(cfg, &resp)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn pipelines_list(cfg: &Config) -> Result<()> {
+    let data = crate::api::get(cfg, "/api/v1/logs/config/pipelines", &[]).await?;
+    crate::formatter::output(cfg, &data)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn pipelines_get(cfg: &Config, pipeline_id: &str) -> Result<()> {
+    let api = make_pipelines_api(cfg, "get")?;
+    let resp = api
+        .get_logs_pipeline(pipeline_id.to_string())
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to get log pipeline: {:?}", e))?;
+    formatter::output(cfg, &resp)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn pipelines_get(cfg: &Config, pipeline_id: &str) -> Result<()> {
+    let path = format!("/api/v1/logs/config/pipelines/{pipeline_id}");
+    let data = crate::api::get(cfg, &path, &[]).await?;
+    crate::formatter::output(cfg, &data)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn pipelines_create(cfg: &Config, file: &str) -> Result<()> {
+    let body: LogsPipeline = util::read_json_file(file)?;
+    let api = make_pipelines_api(cfg, "create")?;
+    let resp = api
+        .create_logs_pipeline(body)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to create log pipeline: {:?}", e))?;
+    formatter::output(cfg, &resp)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn pipelines_create(cfg: &Config, file: &str) -> Result<()> {
+    let body: serde_json::Value = util::read_json_file(file)?;
+    let data = crate::api::post(cfg, "/api/v1/logs/config/pipelines", &body).await?;
+    crate::formatter::output(cfg, &data)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn pipelines_update(cfg: &Config, pipeline_id: &str, file: &str) -> Result<()> {
+    let body: LogsPipeline = util::read_json_file(file)?;
+    let api = make_pipelines_api(cfg, "update")?;
+    let resp = api
+        .update_logs_pipeline(pipeline_id.to_string(), body)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to update log pipeline: {:?}", e))?;
+    formatter::output(cfg, &resp)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn pipelines_update(cfg: &Config, pipeline_id: &str, file: &str) -> Result<()> {
+    let body: serde_json::Value = util::read_json_file(file)?;
+    let path = format!("/api/v1/logs/config/pipelines/{pipeline_id}");
+    let data = crate::api::put(cfg, &path, &body).await?;
+    crate::formatter::output(cfg, &data)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn pipelines_delete(cfg: &Config, pipeline_id: &str) -> Result<()> {
+    let api = make_pipelines_api(cfg, "delete")?;
+    api.delete_logs_pipeline(pipeline_id.to_string())
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to delete log pipeline: {:?}", e))?;
+    println!("Log pipeline {pipeline_id} deleted.");
+    Ok(())
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn pipelines_delete(cfg: &Config, pipeline_id: &str) -> Result<()> {
+    let path = format!("/api/v1/logs/config/pipelines/{pipeline_id}");
+    crate::api::delete(cfg, &path).await?;
+    println!("Log pipeline {pipeline_id} deleted.");
+    Ok(())
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn pipelines_order(cfg: &Config, file: &str) -> Result<()> {
+    let body: LogsPipelinesOrder = util::read_json_file(file)?;
+    let api = make_pipelines_api(cfg, "order")?;
+    let resp = api
+        .update_logs_pipeline_order(body)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to update log pipeline order: {:?}", e))?;
+    formatter::output(cfg, &resp)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn pipelines_order(cfg: &Config, file: &str) -> Result<()> {
+    let body: serde_json::Value = util::read_json_file(file)?;
+    let data = crate::api::put(cfg, "/api/v1/logs/config/pipeline-order", &body).await?;
+    crate::formatter::output(cfg, &data)
+}
+
 // ---------------------------------------------------------------------------
 // Restriction Queries (raw HTTP - not available in typed client)
 // ---------------------------------------------------------------------------
@@ -420,3 +846,112 @@ pub async fn restriction_queries_get(cfg: &Config, query_id: &str) -> Result<()>
     let data = crate::api::get(cfg, &path, &[]).await?;
     crate::formatter::output(cfg, &data)
 }
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn restriction_queries_create(cfg: &Config, file: &str) -> Result<()> {
+    let body: serde_json::Value = util::read_json_file(file)?;
+    let data = client::raw_post(cfg, "/api/v2/logs/config/restriction_queries", body).await?;
+    formatter::output(cfg, &data)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn restriction_queries_create(cfg: &Config, file: &str) -> Result<()> {
+    let body: serde_json::Value = util::read_json_file(file)?;
+    let data = crate::api::post(cfg, "/api/v2/logs/config/restriction_queries", &body).await?;
+    crate::formatter::output(cfg, &data)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn restriction_queries_update(cfg: &Config, query_id: &str, file: &str) -> Result<()> {
+    let body: serde_json::Value = util::read_json_file(file)?;
+    let path = format!("/api/v2/logs/config/restriction_queries/{query_id}");
+    let data = client::raw_patch(cfg, &path, body).await?;
+    formatter::output(cfg, &data)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn restriction_queries_update(cfg: &Config, query_id: &str, file: &str) -> Result<()> {
+    let body: serde_json::Value = util::read_json_file(file)?;
+    let path = format!("/api/v2/logs/config/restriction_queries/{query_id}");
+    let data = crate::api::patch(cfg, &path, &body).await?;
+    crate::formatter::output(cfg, &data)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn restriction_queries_delete(cfg: &Config, query_id: &str) -> Result<()> {
+    let path = format!("/api/v2/logs/config/restriction_queries/{query_id}");
+    client::raw_delete(cfg, &path).await?;
+    println!("Restriction query {query_id} deleted.");
+    Ok(())
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn restriction_queries_delete(cfg: &Config, query_id: &str) -> Result<()> {
+    let path = format!("/api/v2/logs/config/restriction_queries/{query_id}");
+    crate::api::delete(cfg, &path).await?;
+    println!("Restriction query {query_id} deleted.");
+    Ok(())
+}
+
+fn restriction_query_role_body(role_id: &str) -> serde_json::Value {
+    serde_json::json!({
+        "data": {
+            "type": "roles",
+            "id": role_id
+        }
+    })
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn restriction_queries_add_role(
+    cfg: &Config,
+    query_id: &str,
+    role_id: &str,
+) -> Result<()> {
+    let path = format!("/api/v2/logs/config/restriction_queries/{query_id}/roles");
+    client::raw_post(cfg, &path, restriction_query_role_body(role_id)).await?;
+    println!("Role {role_id} added to restriction query {query_id}.");
+    Ok(())
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn restriction_queries_add_role(
+    cfg: &Config,
+    query_id: &str,
+    role_id: &str,
+) -> Result<()> {
+    let path = format!("/api/v2/logs/config/restriction_queries/{query_id}/roles");
+    crate::api::post(cfg, &path, &restriction_query_role_body(role_id)).await?;
+    println!("Role {role_id} added to restriction query {query_id}.");
+    Ok(())
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn restriction_queries_remove_role(
+    cfg: &Config,
+    query_id: &str,
+    role_id: &str,
+) -> Result<()> {
+    let path = format!("/api/v2/logs/config/restriction_queries/{query_id}/roles");
+    client::raw_request(
+        cfg,
+        reqwest::Method::DELETE,
+        &path,
+        Some(restriction_query_role_body(role_id)),
+    )
+    .await?;
+    println!("Role {role_id} removed from restriction query {query_id}.");
+    Ok(())
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn restriction_queries_remove_role(
+    cfg: &Config,
+    query_id: &str,
+    role_id: &str,
+) -> Result<()> {
+    let path = format!("/api/v2/logs/config/restriction_queries/{query_id}/roles");
+    crate::api::delete_with_body(cfg, &path, &restriction_query_role_body(role_id)).await?;
+    println!("Role {role_id} removed from restriction query {query_id}.");
+    Ok(())
+}
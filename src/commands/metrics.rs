@@ -16,6 +16,9 @@ use crate::config::Config;
 use crate::formatter;
 use crate::util;
 
+/// List metrics actively reporting since `from`. The v1 active metrics endpoint
+/// takes `from` in Unix seconds, so `from` is parsed to milliseconds (relative,
+/// RFC3339, or an all-digit millisecond timestamp) and divided by 1000.
 #[cfg(not(target_arch = "wasm32"))]
 pub async fn list(cfg: &Config, filter: Option<String>, from: String) -> Result<()> {
     let dd_cfg = client::make_dd_config(cfg);
@@ -51,7 +54,7 @@ pub async fn list(cfg: &Config, filter: Option<String>, from: String) -> Result<
 pub async fn list(cfg: &Config, filter: Option<String>, from: String) -> Result<()> {
     let from_ts = util::parse_time_to_unix(&from)?;
     let query_params = vec![("from", from_ts.to_string())];
-    let data = crate::api::get(cfg, "/api/v2/metrics", &query_params).await?;
+    let data = crate::api::get(cfg, "/api/v1/metrics", &query_params).await?;
 
     // Client-side filter if provided
     if let Some(pattern) = filter {
@@ -118,8 +121,139 @@ pub async fn metadata_get(cfg: &Config, metric_name: &str) -> Result<()> {
     crate::formatter::output(cfg, &data)
 }
 
+/// Maximum number of concurrent metadata fetches for `metadata list`.
 #[cfg(not(target_arch = "wasm32"))]
-pub async fn query(cfg: &Config, query: String, from: String, to: String) -> Result<()> {
+const METADATA_FETCH_CONCURRENCY: usize = 8;
+
+/// Keep metric names containing `filter`, ignoring case.
+fn filter_metric_names(names: Vec<String>, filter: Option<&str>) -> Vec<String> {
+    match filter {
+        Some(pattern) => {
+            let pattern = pattern.to_lowercase();
+            names
+                .into_iter()
+                .filter(|m| m.to_lowercase().contains(&pattern))
+                .collect()
+        }
+        None => names,
+    }
+}
+
+/// Per-metric metadata fetch results, keyed by metric name.
+type MetadataResults =
+    std::collections::BTreeMap<String, std::result::Result<serde_json::Value, String>>;
+
+/// Combine per-metric metadata into a list sorted by metric name, each entry
+/// tagged with its `metric_name`. Failed fetches become `{metric_name, error}`
+/// entries alongside the successes.
+fn metadata_entries(metadata: MetadataResults) -> Vec<serde_json::Value> {
+    metadata
+        .into_iter()
+        .map(|(name, meta)| {
+            let mut entry = serde_json::Map::new();
+            entry.insert("metric_name".into(), serde_json::json!(name));
+            match meta {
+                Ok(serde_json::Value::Object(fields)) => entry.extend(fields),
+                Ok(_) => {}
+                Err(error) => {
+                    entry.insert("error".into(), serde_json::json!(error));
+                }
+            }
+            serde_json::Value::Object(entry)
+        })
+        .collect()
+}
+
+/// Print every fetched entry, then fail with a summary if any metric failed.
+fn finish_metadata_list(cfg: &Config, metadata: MetadataResults) -> Result<()> {
+    let total = metadata.len();
+    let failed: Vec<String> = metadata
+        .iter()
+        .filter(|(_, meta)| meta.is_err())
+        .map(|(name, _)| name.clone())
+        .collect();
+    formatter::output(cfg, &metadata_entries(metadata))?;
+    if !failed.is_empty() {
+        anyhow::bail!(
+            "failed to get metadata for {} of {total} metric(s): {}",
+            failed.len(),
+            failed.join(", ")
+        );
+    }
+    Ok(())
+}
+
+/// List metadata for every metric active since `from`, optionally filtered by
+/// name. The API has no bulk endpoint, so metadata is fetched per metric.
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn metadata_list(cfg: &Config, filter: Option<String>, from: String) -> Result<()> {
+    let dd_cfg = client::make_dd_config(cfg);
+    let api = match client::make_bearer_client(cfg) {
+        Some(c) => MetricsV1API::with_client_and_config(dd_cfg, c),
+        None => MetricsV1API::with_config(dd_cfg),
+    };
+
+    let from_ts = util::parse_time_to_unix(&from)?;
+    let resp = api
+        .list_active_metrics(from_ts, ListActiveMetricsOptionalParams::default())
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to list metrics: {e:?}"))?;
+    let names = filter_metric_names(resp.metrics.unwrap_or_default(), filter.as_deref());
+
+    let semaphore = std::sync::Arc::new(tokio::sync::Semaphore::new(METADATA_FETCH_CONCURRENCY));
+    let mut tasks = tokio::task::JoinSet::new();
+    for name in names {
+        let api = api.clone();
+        let semaphore = semaphore.clone();
+        tasks.spawn(async move {
+            let meta = async {
+                let _permit = semaphore.acquire_owned().await?;
+                let meta = api
+                    .get_metric_metadata(name.clone())
+                    .await
+                    .map_err(|e| anyhow::anyhow!("{e:?}"))?;
+                Ok::<_, anyhow::Error>(serde_json::to_value(meta)?)
+            }
+            .await
+            .map_err(|e| e.to_string());
+            (name, meta)
+        });
+    }
+    let mut metadata = MetadataResults::new();
+    while let Some(joined) = tasks.join_next().await {
+        let (name, meta) =
+            joined.map_err(|e| anyhow::anyhow!("metadata fetch task failed: {e}"))?;
+        metadata.insert(name, meta);
+    }
+    finish_metadata_list(cfg, metadata)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn metadata_list(cfg: &Config, filter: Option<String>, from: String) -> Result<()> {
+    let from_ts = util::parse_time_to_unix(&from)?;
+    let query_params = vec![("from", from_ts.to_string())];
+    let data = crate::api::get(cfg, "/api/v1/metrics", &query_params).await?;
+    let names: Vec<String> = data
+        .get("metrics")
+        .and_then(|v| v.as_array())
+        .map(|ms| {
+            ms.iter()
+                .filter_map(|m| m.as_str().map(String::from))
+                .collect()
+        })
+        .unwrap_or_default();
+    let mut metadata = MetadataResults::new();
+    for name in filter_metric_names(names, filter.as_deref()) {
+        let meta = crate::api::get(cfg, &format!("/api/v1/metrics/{name}"), &[])
+            .await
+            .map_err(|e| e.to_string());
+        metadata.insert(name, meta);
+    }
+    finish_metadata_list(cfg, metadata)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn query(cfg: &Config, query: String, from: String, to: String, raw: bool) -> Result<()> {
     let dd_cfg = client::make_dd_config(cfg);
     let api = match client::make_bearer_client(cfg) {
         Some(c) => MetricsV1API::with_client_and_config(dd_cfg, c),
@@ -133,11 +267,15 @@ pub async fn query(cfg: &Config, query: String, from: String, to: String) -> Res
         .query_metrics(from_ts, to_ts, query)
         .await
         .map_err(|e| anyhow::anyhow!("failed to query metrics: {e:?}"))?;
+    if raw {
+        print!("{}", raw_points_csv(&serde_json::to_value(&resp)?));
+        return Ok(());
+    }
     formatter::output(cfg, &resp)
 }
 
 #[cfg(target_arch = "wasm32")]
-pub async fn query(cfg: &Config, query: String, from: String, to: String) -> Result<()> {
+pub async fn query(cfg: &Config, query: String, from: String, to: String, raw: bool) -> Result<()> {
     let from_ts = util::parse_time_to_unix(&from)?;
     let to_ts = util::parse_time_to_unix(&to)?;
     let body = serde_json::json!({
@@ -146,9 +284,92 @@ pub async fn query(cfg: &Config, query: String, from: String, to: String) -> Res
         "to": to_ts * 1000
     });
     let data = crate::api::post(cfg, "/api/v2/query/timeseries", &body).await?;
+    if raw {
+        print!("{}", raw_points_csv(&data));
+        return Ok(());
+    }
     crate::formatter::output(cfg, &data)
 }
 
+/// Render a metrics query response as newline-separated `<epoch_ms>,<value>` rows.
+///
+/// Grouped queries (e.g. `avg:system.cpu.user{*} by {host}`) prefix each row with
+/// the series group key: `<group>,<epoch_ms>,<value>`. Handles both the v1
+/// `series[].pointlist` shape and the v2 `data.attributes` timeseries shape.
+/// Null values are skipped.
+fn raw_points_csv(resp: &serde_json::Value) -> String {
+    let mut out = String::new();
+
+    if let Some(series) = resp.get("series").and_then(|s| s.as_array()) {
+        for s in series {
+            let group = s
+                .get("tag_set")
+                .and_then(|t| t.as_array())
+                .filter(|t| !t.is_empty())
+                .map(|tags| {
+                    tags.iter()
+                        .filter_map(|t| t.as_str())
+                        .collect::<Vec<_>>()
+                        .join(",")
+                });
+            let points = s.get("pointlist").and_then(|p| p.as_array());
+            for point in points.into_iter().flatten() {
+                let (Some(ts), Some(value)) = (
+                    point.get(0).and_then(|v| v.as_f64()),
+                    point.get(1).and_then(|v| v.as_f64()),
+                ) else {
+                    continue;
+                };
+                push_csv_row(&mut out, group.as_deref(), ts as i64, value);
+            }
+        }
+        return out;
+    }
+
+    if let Some(attrs) = resp.get("data").and_then(|d| d.get("attributes")) {
+        let times: Vec<i64> = attrs
+            .get("times")
+            .and_then(|t| t.as_array())
+            .map(|t| t.iter().filter_map(|v| v.as_i64()).collect())
+            .unwrap_or_default();
+        let series = attrs.get("series").and_then(|s| s.as_array());
+        let values = attrs.get("values").and_then(|v| v.as_array());
+        for (i, vals) in values.into_iter().flatten().enumerate() {
+            let group = series
+                .and_then(|s| s.get(i))
+                .and_then(|s| s.get("group_tags"))
+                .and_then(|t| t.as_array())
+                .filter(|t| !t.is_empty())
+                .map(|tags| {
+                    tags.iter()
+                        .filter_map(|t| t.as_str())
+                        .collect::<Vec<_>>()
+                        .join(",")
+                });
+            let vals = vals.as_array().map(|v| v.as_slice()).unwrap_or_default();
+            for (ts, value) in times.iter().zip(vals) {
+                if let Some(value) = value.as_f64() {
+                    push_csv_row(&mut out, group.as_deref(), *ts, value);
+                }
+            }
+        }
+    }
+
+    out
+}
+
+fn push_csv_row(out: &mut String, group: Option<&str>, ts: i64, value: f64) {
+    if let Some(group) = group {
+        if group.contains([',', '"']) {
+            out.push_str(&format!("\"{}\",", group.replace('"', "\"\"")));
+        } else {
+            out.push_str(group);
+            out.push(',');
+        }
+    }
+    out.push_str(&format!("{ts},{value}\n"));
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 pub async fn metadata_update(cfg: &Config, metric_name: &str, file: &str) -> Result<()> {
     let dd_cfg = client::make_dd_config(cfg);
@@ -172,14 +393,110 @@ pub async fn metadata_update(cfg: &Config, metric_name: &str, file: &str) -> Res
     crate::formatter::output(cfg, &data)
 }
 
-#[cfg(not(target_arch = "wasm32"))]
 pub async fn submit(cfg: &Config, file: &str) -> Result<()> {
+    let body: serde_json::Value = util::read_json_file(file)?;
+    submit_payload(cfg, body).await
+}
+
+/// Build a v2 series payload carrying a single point for `name`.
+pub fn series_payload(
+    name: &str,
+    value: f64,
+    tags: Option<&str>,
+    metric_type: &str,
+    host: Option<&str>,
+    interval: i64,
+    timestamp: Option<&str>,
+) -> Result<serde_json::Value> {
+    // v2 intake types: 0 = unspecified, 1 = count, 2 = rate, 3 = gauge
+    let type_id = match metric_type {
+        "gauge" => 3,
+        "count" => 1,
+        "rate" => 2,
+        other => anyhow::bail!("invalid --type {other:?}: expected one of: gauge, count, rate"),
+    };
+    let ts = util::parse_time_to_unix(timestamp.unwrap_or("now"))?;
+    let mut series = serde_json::json!({
+        "metric": name,
+        "type": type_id,
+        "points": [{ "timestamp": ts, "value": value }],
+    });
+    if let Some(tags) = tags {
+        let tags: Vec<&str> = tags
+            .split(',')
+            .map(str::trim)
+            .filter(|t| !t.is_empty())
+            .collect();
+        if !tags.is_empty() {
+            series["tags"] = serde_json::json!(tags);
+        }
+    }
+    if let Some(host) = host {
+        series["resources"] = serde_json::json!([{ "name": host, "type": "host" }]);
+    }
+    if interval > 0 {
+        series["interval"] = serde_json::json!(interval);
+    }
+    Ok(serde_json::json!({ "series": [series] }))
+}
+
+/// Maximum number of series sent in a single `--many` intake request.
+const SUBMIT_BATCH_SIZE: usize = 500;
+
+pub async fn submit_payload(cfg: &Config, body: serde_json::Value) -> Result<()> {
+    let resp = send_series(cfg, body).await?;
+    formatter::output(cfg, &resp)
+}
+
+/// Submit every series in `file` (a JSON array of series, or a payload with a
+/// `series` array), splitting into requests of at most `SUBMIT_BATCH_SIZE`.
+pub async fn submit_many(cfg: &Config, file: &str) -> Result<()> {
+    let data: serde_json::Value = util::read_json_file(file)?;
+    let batches = series_batches(data, SUBMIT_BATCH_SIZE)?;
+    let total: usize = batches
+        .iter()
+        .map(|b| b["series"].as_array().map_or(0, Vec::len))
+        .sum();
+    let count = batches.len();
+    for (i, body) in batches.into_iter().enumerate() {
+        send_series(cfg, body)
+            .await
+            .map_err(|e| anyhow::anyhow!("batch {} of {count}: {e}", i + 1))?;
+    }
+    formatter::output(
+        cfg,
+        &serde_json::json!({ "series_submitted": total, "batches": count }),
+    )
+}
+
+/// Split a list of series into intake payloads of at most `size` series each.
+fn series_batches(data: serde_json::Value, size: usize) -> Result<Vec<serde_json::Value>> {
+    let series = match data {
+        serde_json::Value::Array(series) => series,
+        serde_json::Value::Object(mut obj) => match obj.remove("series") {
+            Some(serde_json::Value::Array(series)) => series,
+            _ => anyhow::bail!("metrics file must contain a \"series\" array"),
+        },
+        _ => anyhow::bail!("metrics file must be a JSON array of series"),
+    };
+    if series.is_empty() {
+        anyhow::bail!("metrics file contains no series");
+    }
+    Ok(series
+        .chunks(size)
+        .map(|chunk| serde_json::json!({ "series": chunk }))
+        .collect())
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+async fn send_series(cfg: &Config, body: serde_json::Value) -> Result<serde_json::Value> {
     let dd_cfg = client::make_dd_config(cfg);
     let api = match client::make_bearer_client(cfg) {
         Some(c) => MetricsV2API::with_client_and_config(dd_cfg, c),
         None => MetricsV2API::with_config(dd_cfg),
     };
-    let body: MetricPayload = util::read_json_file(file)?;
+    let body: MetricPayload = serde_json::from_value(body)
+        .map_err(|e| anyhow::anyhow!("invalid metrics payload: {e}"))?;
     let resp = api
         .submit_metrics(
             body,
@@ -187,14 +504,12 @@ pub async fn submit(cfg: &Config, file: &str) -> Result<()> {
         )
         .await
         .map_err(|e| anyhow::anyhow!("failed to submit metrics: {e:?}"))?;
-    formatter::output(cfg, &resp)
+    Ok(serde_json::to_value(&resp)?)
 }
 
 #[cfg(target_arch = "wasm32")]
-pub async fn submit(cfg: &Config, file: &str) -> Result<()> {
-    let body: serde_json::Value = util::read_json_file(file)?;
-    let data = crate::api::post(cfg, "/api/v2/series", &body).await?;
-    crate::formatter::output(cfg, &data)
+async fn send_series(cfg: &Config, body: serde_json::Value) -> Result<serde_json::Value> {
+    crate::api::post(cfg, "/api/v2/series", &body).await
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -222,3 +537,160 @@ pub async fn tags_list(cfg: &Config, metric_name: &str) -> Result<()> {
     let data = crate::api::get(cfg, &path, &[]).await?;
     crate::formatter::output(cfg, &data)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter_metric_names() {
+        let names = vec!["system.cpu.user".to_string(), "app.requests".to_string()];
+        assert_eq!(
+            filter_metric_names(names.clone(), Some("CPU")),
+            vec!["system.cpu.user".to_string()]
+        );
+        assert_eq!(filter_metric_names(names.clone(), None), names);
+    }
+
+    #[test]
+    fn test_metadata_entries_sorted_and_named() {
+        let mut metadata = MetadataResults::new();
+        metadata.insert(
+            "b.metric".to_string(),
+            Ok(serde_json::json!({"unit": "byte"})),
+        );
+        metadata.insert(
+            "a.metric".to_string(),
+            Ok(serde_json::json!({"type": "gauge"})),
+        );
+        metadata.insert("c.metric".to_string(), Err("HTTP 404".to_string()));
+        assert_eq!(
+            metadata_entries(metadata),
+            vec![
+                serde_json::json!({"metric_name": "a.metric", "type": "gauge"}),
+                serde_json::json!({"metric_name": "b.metric", "unit": "byte"}),
+                serde_json::json!({"metric_name": "c.metric", "error": "HTTP 404"}),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_series_batches_splits_array() {
+        let series: Vec<_> = (0..1201)
+            .map(|i| serde_json::json!({"metric": format!("m{i}")}))
+            .collect();
+        let batches = series_batches(serde_json::json!(series), 500).unwrap();
+        let sizes: Vec<usize> = batches
+            .iter()
+            .map(|b| b["series"].as_array().unwrap().len())
+            .collect();
+        assert_eq!(sizes, vec![500, 500, 201]);
+        assert_eq!(batches[2]["series"][200]["metric"], "m1200");
+    }
+
+    #[test]
+    fn test_series_batches_accepts_payload_object() {
+        let batches =
+            series_batches(serde_json::json!({"series": [{"metric": "a"}]}), 500).unwrap();
+        assert_eq!(
+            batches,
+            vec![serde_json::json!({"series": [{"metric": "a"}]})]
+        );
+    }
+
+    #[test]
+    fn test_series_batches_rejects_invalid() {
+        assert!(series_batches(serde_json::json!([]), 500).is_err());
+        assert!(series_batches(serde_json::json!({"metric": "a"}), 500).is_err());
+        assert!(series_batches(serde_json::json!("a"), 500).is_err());
+    }
+
+    #[test]
+    fn test_series_payload_gauge() {
+        let body = series_payload(
+            "custom.metric",
+            42.5,
+            Some("env:prod, team:backend"),
+            "gauge",
+            Some("web-1"),
+            0,
+            Some("1700000000000"),
+        )
+        .unwrap();
+        assert_eq!(
+            body,
+            serde_json::json!({
+                "series": [{
+                    "metric": "custom.metric",
+                    "type": 3,
+                    "points": [{ "timestamp": 1700000000, "value": 42.5 }],
+                    "tags": ["env:prod", "team:backend"],
+                    "resources": [{ "name": "web-1", "type": "host" }],
+                }]
+            })
+        );
+    }
+
+    #[test]
+    fn test_series_payload_count_with_interval() {
+        let body = series_payload("custom.count", 1.0, None, "count", None, 10, None).unwrap();
+        let series = &body["series"][0];
+        assert_eq!(series["type"], 1);
+        assert_eq!(series["interval"], 10);
+        assert!(series.get("tags").is_none());
+        assert!(series["points"][0]["timestamp"].as_i64().unwrap() > 0);
+    }
+
+    #[test]
+    fn test_series_payload_invalid_type() {
+        assert!(series_payload("m", 1.0, None, "distribution", None, 0, None).is_err());
+    }
+
+    #[test]
+    fn test_raw_points_csv_ungrouped() {
+        let resp = serde_json::json!({
+            "series": [{
+                "scope": "*",
+                "tag_set": [],
+                "pointlist": [[1700000000000.0, 1.5], [1700000060000.0, null], [1700000120000.0, 2.0]]
+            }]
+        });
+        assert_eq!(
+            raw_points_csv(&resp),
+            "1700000000000,1.5\n1700000120000,2\n"
+        );
+    }
+
+    #[test]
+    fn test_raw_points_csv_grouped() {
+        let resp = serde_json::json!({
+            "series": [
+                {"tag_set": ["host:a"], "pointlist": [[1000.0, 1.0]]},
+                {"tag_set": ["host:b", "env:prod"], "pointlist": [[1000.0, 2.0]]}
+            ]
+        });
+        assert_eq!(
+            raw_points_csv(&resp),
+            "host:a,1000,1\n\"host:b,env:prod\",1000,2\n"
+        );
+    }
+
+    #[test]
+    fn test_raw_points_csv_v2_timeseries() {
+        let resp = serde_json::json!({
+            "data": {
+                "attributes": {
+                    "times": [1000, 2000],
+                    "series": [{"group_tags": ["host:a"]}],
+                    "values": [[3.0, 4.5]]
+                }
+            }
+        });
+        assert_eq!(raw_points_csv(&resp), "host:a,1000,3\nhost:a,2000,4.5\n");
+    }
+
+    #[test]
+    fn test_raw_points_csv_empty() {
+        assert_eq!(raw_points_csv(&serde_json::json!({"series": []})), "");
+    }
+}
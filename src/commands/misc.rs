@@ -63,3 +63,28 @@ pub async fn status(cfg: &Config) -> Result<()> {
     });
     crate::formatter::output(cfg, &transformed)
 }
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn validate_api_key(cfg: &Config) -> Result<()> {
+    if cfg.api_key.is_none() {
+        anyhow::bail!("no API key configured (set DD_API_KEY)");
+    }
+    // /api/v1/validate only checks the API key, so skip the bearer client.
+    let api = AuthenticationAPI::with_config(client::make_dd_config(cfg));
+    let resp = api
+        .validate()
+        .await
+        .map_err(|e| anyhow::anyhow!("API key is not valid: {e:?}"))?;
+    formatter::output(cfg, &resp)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn validate_api_key(cfg: &Config) -> Result<()> {
+    if cfg.api_key.is_none() {
+        anyhow::bail!("no API key configured (set DD_API_KEY)");
+    }
+    let data = crate::api::get(cfg, "/api/v1/validate", &[])
+        .await
+        .map_err(|e| anyhow::anyhow!("API key is not valid: {e:?}"))?;
+    crate::formatter::output(cfg, &data)
+}
@@ -13,13 +13,105 @@ use crate::config::Config;
 use crate::formatter::{self, Metadata};
 use crate::util;
 
+/// Filters shared by `monitors list` and `monitors list --count`.
+#[derive(Debug, Clone, Default)]
+pub struct MonitorFilter {
+    pub name: Option<String>,
+    pub tags: Option<String>,
+    /// Drop monitors that carry a `deleted` timestamp. The list endpoint can
+    /// still return recently deleted monitors, so this is applied client-side.
+    pub no_deleted: bool,
+}
+
+impl MonitorFilter {
+    fn apply(&self, monitors: &mut Vec<serde_json::Value>) {
+        if self.no_deleted {
+            monitors.retain(|m| m["deleted"].is_null());
+        }
+    }
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 pub async fn list(
     cfg: &Config,
-    name: Option<String>,
-    tags: Option<String>,
+    filter: &MonitorFilter,
     limit: i32,
+    sort: Option<&str>,
+    json_path: Option<&str>,
+    exit_on_state: Option<&str>,
 ) -> Result<()> {
+    let monitors = list_limited(cfg, filter, limit, sort).await?;
+    if monitors.is_empty() {
+        eprintln!("No monitors found matching the specified criteria.");
+        return Ok(());
+    }
+    if let Some(expr) = json_path {
+        let monitors = serde_json::Value::Array(monitors);
+        formatter::output(cfg, &util::json_path_query(&monitors, expr)?)?;
+        return check_exit_state(&monitors, exit_on_state);
+    }
+    let meta = Metadata {
+        count: Some(monitors.len()),
+        truncated: false,
+        command: Some("monitors list".to_string()),
+        next_action: None,
+    };
+    formatter::format_and_print(
+        &monitors,
+        &cfg.output_format,
+        cfg.agent_mode,
+        Some(&meta),
+        cfg.table_max_width,
+    )?;
+    check_exit_state(&serde_json::Value::Array(monitors), exit_on_state)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn list(
+    cfg: &Config,
+    filter: &MonitorFilter,
+    limit: i32,
+    sort: Option<&str>,
+    json_path: Option<&str>,
+    exit_on_state: Option<&str>,
+) -> Result<()> {
+    let data = serde_json::Value::Array(list_limited(cfg, filter, limit, sort).await?);
+    match json_path {
+        Some(expr) => crate::formatter::output(cfg, &util::json_path_query(&data, expr)?)?,
+        None => crate::formatter::output(cfg, &data)?,
+    }
+    check_exit_state(&data, exit_on_state)
+}
+
+/// The first `limit` monitors matching the filters, in `sort` order. Sorting
+/// and client-side filters have to see every monitor before truncating, so
+/// those fetch all pages; otherwise one page of `limit` is enough.
+async fn list_limited(
+    cfg: &Config,
+    filter: &MonitorFilter,
+    limit: i32,
+    sort: Option<&str>,
+) -> Result<Vec<serde_json::Value>> {
+    let limit = limit.clamp(1, 1000);
+    if sort.is_none() && !filter.no_deleted {
+        return list_page(cfg, filter, limit).await;
+    }
+    let mut monitors = list_all(cfg, filter).await?;
+    if let Some(sort) = sort {
+        sort_monitors(&mut monitors, sort)?;
+    }
+    monitors.truncate(limit as usize);
+    Ok(monitors)
+}
+
+/// Fetch the first page of `page_size` monitors matching the server-side
+/// filters.
+#[cfg(not(target_arch = "wasm32"))]
+async fn list_page(
+    cfg: &Config,
+    filter: &MonitorFilter,
+    page_size: i32,
+) -> Result<Vec<serde_json::Value>> {
     let dd_cfg = client::make_dd_config(cfg);
     let api = if let Some(http_client) = client::make_bearer_client(cfg) {
         MonitorsAPI::with_client_and_config(dd_cfg, http_client)
@@ -27,57 +119,373 @@ pub async fn list(
         MonitorsAPI::with_config(dd_cfg)
     };
 
-    let mut params = ListMonitorsOptionalParams::default();
-    if let Some(name) = name {
-        params = params.name(name);
+    let mut params = ListMonitorsOptionalParams::default()
+        .page_size(page_size)
+        .page(0);
+    if let Some(name) = &filter.name {
+        params = params.name(name.clone());
     }
-    if let Some(tags) = tags {
-        params = params.monitor_tags(tags);
+    if let Some(tags) = &filter.tags {
+        params = params.monitor_tags(tags.clone());
     }
-
-    let limit = limit.clamp(1, 1000);
-    params = params.page_size(limit).page(0);
-
     let monitors = api
         .list_monitors(params)
         .await
         .map_err(|e| anyhow::anyhow!("failed to list monitors: {:?}", e))?;
+    Ok(monitors
+        .iter()
+        .take(page_size as usize)
+        .map(serde_json::to_value)
+        .collect::<Result<_, _>>()?)
+}
 
-    if monitors.is_empty() {
-        eprintln!("No monitors found matching the specified criteria.");
+#[cfg(target_arch = "wasm32")]
+async fn list_page(
+    cfg: &Config,
+    filter: &MonitorFilter,
+    page_size: i32,
+) -> Result<Vec<serde_json::Value>> {
+    let mut query = vec![
+        ("page_size", page_size.to_string()),
+        ("page", "0".to_string()),
+    ];
+    if let Some(n) = &filter.name {
+        query.push(("name", n.clone()));
+    }
+    if let Some(t) = &filter.tags {
+        query.push(("monitor_tags", t.clone()));
+    }
+    let data = crate::api::get(cfg, "/api/v1/monitor", &query).await?;
+    Ok(data.as_array().cloned().unwrap_or_default())
+}
+
+/// Page size used when fetching every matching monitor.
+const MONITOR_LIST_ALL_PAGE_SIZE: i32 = 1000;
+
+/// Fetch all monitors matching the filters, following pagination.
+#[cfg(not(target_arch = "wasm32"))]
+async fn list_all(cfg: &Config, filter: &MonitorFilter) -> Result<Vec<serde_json::Value>> {
+    let dd_cfg = client::make_dd_config(cfg);
+    let api = if let Some(http_client) = client::make_bearer_client(cfg) {
+        MonitorsAPI::with_client_and_config(dd_cfg, http_client)
+    } else {
+        MonitorsAPI::with_config(dd_cfg)
+    };
+
+    let mut all = Vec::new();
+    let mut page = 0;
+    loop {
+        let mut params = ListMonitorsOptionalParams::default()
+            .page_size(MONITOR_LIST_ALL_PAGE_SIZE)
+            .page(page);
+        if let Some(name) = &filter.name {
+            params = params.name(name.clone());
+        }
+        if let Some(tags) = &filter.tags {
+            params = params.monitor_tags(tags.clone());
+        }
+        let monitors = api
+            .list_monitors(params)
+            .await
+            .map_err(|e| anyhow::anyhow!("failed to list monitors: {:?}", e))?;
+        let n = monitors.len();
+        for m in &monitors {
+            all.push(serde_json::to_value(m)?);
+        }
+        if n < MONITOR_LIST_ALL_PAGE_SIZE as usize {
+            break;
+        }
+        page += 1;
+    }
+    filter.apply(&mut all);
+    Ok(all)
+}
+
+#[cfg(target_arch = "wasm32")]
+async fn list_all(cfg: &Config, filter: &MonitorFilter) -> Result<Vec<serde_json::Value>> {
+    let mut all = Vec::new();
+    let mut page = 0;
+    loop {
+        let mut query = vec![
+            ("page_size", MONITOR_LIST_ALL_PAGE_SIZE.to_string()),
+            ("page", page.to_string()),
+        ];
+        if let Some(n) = &filter.name {
+            query.push(("name", n.clone()));
+        }
+        if let Some(t) = &filter.tags {
+            query.push(("monitor_tags", t.clone()));
+        }
+        let data = crate::api::get(cfg, "/api/v1/monitor", &query).await?;
+        let monitors = data.as_array().cloned().unwrap_or_default();
+        let n = monitors.len();
+        all.extend(monitors);
+        if n < MONITOR_LIST_ALL_PAGE_SIZE as usize {
+            break;
+        }
+        page += 1;
+    }
+    filter.apply(&mut all);
+    Ok(all)
+}
+
+/// Fetch all monitors matching the filters and print only how many there are.
+pub async fn count(
+    cfg: &Config,
+    filter: &MonitorFilter,
+    exit_on_state: Option<&str>,
+) -> Result<()> {
+    let all = list_all(cfg, filter).await?;
+    println!("{}", all.len());
+    check_exit_state(&serde_json::Value::Array(all), exit_on_state)
+}
+
+/// Maximum number of mute requests in flight during `bulk-mute`.
+#[cfg(not(target_arch = "wasm32"))]
+const BULK_MUTE_CONCURRENCY: usize = 8;
+
+/// Resolve `--end` to RFC3339. Absolute times (RFC3339, Unix milliseconds)
+/// are used as-is; relative durations such as `2h` count forward from now.
+fn mute_end(input: &str) -> Result<String> {
+    let end = util::parse_future_time(input)?;
+    if end <= chrono::Utc::now().timestamp() {
+        anyhow::bail!("--end must be in the future: {input:?}");
+    }
+    chrono::DateTime::from_timestamp(end, 0)
+        .map(|dt| dt.to_rfc3339_opts(chrono::SecondsFormat::Secs, true))
+        .ok_or_else(|| anyhow::anyhow!("invalid --end: {input:?}"))
+}
+
+/// Build a v2 downtime that mutes a single monitor until `end` (or
+/// indefinitely when `end` is `None`).
+fn mute_downtime_body(
+    monitor_id: i64,
+    end: Option<&str>,
+    message: Option<&str>,
+) -> serde_json::Value {
+    let mut attributes = serde_json::json!({
+        "scope": "*",
+        "monitor_identifier": { "monitor_id": monitor_id },
+    });
+    if let Some(end) = end {
+        attributes["schedule"] = serde_json::json!({ "end": end });
+    }
+    if let Some(message) = message {
+        attributes["message"] = serde_json::json!(message);
+    }
+    serde_json::json!({ "data": { "type": "downtime", "attributes": attributes } })
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+async fn create_mute(
+    api: &datadog_api_client::datadogV2::api_downtimes::DowntimesAPI,
+    body: serde_json::Value,
+) -> Result<()> {
+    let body = serde_json::from_value(body)?;
+    api.create_downtime(body)
+        .await
+        .map_err(|e| anyhow::anyhow!("{e:?}"))?;
+    Ok(())
+}
+
+fn mute_summary(muted: usize, failed: Vec<(i64, String)>) -> serde_json::Value {
+    let failed: Vec<_> = failed
+        .into_iter()
+        .map(|(id, error)| serde_json::json!({ "monitor_id": id, "error": error }))
+        .collect();
+    serde_json::json!({ "muted": muted, "failed": failed })
+}
+
+/// Mute every monitor matching `tags` by creating one downtime per monitor.
+/// Fails after printing the summary if any monitor could not be muted.
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn bulk_mute(
+    cfg: &Config,
+    tags: String,
+    end: Option<String>,
+    message: Option<String>,
+) -> Result<()> {
+    use datadog_api_client::datadogV2::api_downtimes::DowntimesAPI;
+
+    let end = end.as_deref().map(mute_end).transpose()?;
+    let ids: Vec<i64> = list_all(
+        cfg,
+        &MonitorFilter {
+            tags: Some(tags),
+            ..Default::default()
+        },
+    )
+    .await?
+    .iter()
+    .filter_map(|m| m["id"].as_i64())
+    .collect();
+    if ids.is_empty() {
+        eprintln!("No monitors found matching the specified tags.");
         return Ok(());
     }
 
-    let monitors: Vec<_> = monitors.into_iter().take(limit as usize).collect();
-    let meta = Metadata {
-        count: Some(monitors.len()),
-        truncated: false,
-        command: Some("monitors list".to_string()),
-        next_action: None,
+    let dd_cfg = client::make_dd_config(cfg);
+    let api = match client::make_bearer_client(cfg) {
+        Some(c) => DowntimesAPI::with_client_and_config(dd_cfg, c),
+        None => DowntimesAPI::with_config(dd_cfg),
     };
-    formatter::format_and_print(&monitors, &cfg.output_format, cfg.agent_mode, Some(&meta))?;
+    let semaphore = std::sync::Arc::new(tokio::sync::Semaphore::new(BULK_MUTE_CONCURRENCY));
+    let mut tasks = tokio::task::JoinSet::new();
+    for id in &ids {
+        let (api, semaphore, id) = (api.clone(), semaphore.clone(), *id);
+        let body = mute_downtime_body(id, end.as_deref(), message.as_deref());
+        tasks.spawn(async move {
+            let _permit = semaphore
+                .acquire_owned()
+                .await
+                .map_err(|e| (id, anyhow::Error::from(e)))?;
+            create_mute(&api, body).await.map_err(|e| (id, e))
+        });
+    }
+
+    let total = ids.len();
+    let (mut muted, mut failed) = (0, Vec::new());
+    while let Some(joined) = tasks.join_next().await {
+        match joined.map_err(|e| anyhow::anyhow!("mute task failed: {e}"))? {
+            Ok(()) => muted += 1,
+            Err((id, e)) => failed.push((id, e.to_string())),
+        }
+        eprint!("\rMuting monitors: {}/{total}", muted + failed.len());
+    }
+    eprintln!();
+
+    let failures = failed.len();
+    formatter::output(cfg, &mute_summary(muted, failed))?;
+    if failures > 0 {
+        anyhow::bail!("{failures} of {total} monitor(s) could not be muted");
+    }
     Ok(())
 }
 
 #[cfg(target_arch = "wasm32")]
-pub async fn list(
+pub async fn bulk_mute(
     cfg: &Config,
-    name: Option<String>,
-    tags: Option<String>,
-    limit: i32,
+    tags: String,
+    end: Option<String>,
+    message: Option<String>,
 ) -> Result<()> {
-    let mut query = vec![];
-    if let Some(n) = &name {
-        query.push(("name", n.clone()));
+    let end = end.as_deref().map(mute_end).transpose()?;
+    let ids: Vec<i64> = list_all(
+        cfg,
+        &MonitorFilter {
+            tags: Some(tags),
+            ..Default::default()
+        },
+    )
+    .await?
+    .iter()
+    .filter_map(|m| m["id"].as_i64())
+    .collect();
+    if ids.is_empty() {
+        eprintln!("No monitors found matching the specified tags.");
+        return Ok(());
     }
-    if let Some(t) = &tags {
-        query.push(("monitor_tags", t.clone()));
+
+    let total = ids.len();
+    let (mut muted, mut failed) = (0, Vec::new());
+    for id in ids {
+        let body = mute_downtime_body(id, end.as_deref(), message.as_deref());
+        match crate::api::post(cfg, "/api/v2/downtime", &body).await {
+            Ok(_) => muted += 1,
+            Err(e) => failed.push((id, e.to_string())),
+        }
+        eprint!("\rMuting monitors: {}/{total}", muted + failed.len());
     }
-    let limit = limit.clamp(1, 1000);
-    query.push(("page_size", limit.to_string()));
-    query.push(("page", "0".to_string()));
-    let data = crate::api::get(cfg, "/api/v1/monitor", &query).await?;
-    crate::formatter::output(cfg, &data)
+    eprintln!();
+
+    let failures = failed.len();
+    formatter::output(cfg, &mute_summary(muted, failed))?;
+    if failures > 0 {
+        anyhow::bail!("{failures} of {total} monitor(s) could not be muted");
+    }
+    Ok(())
+}
+
+/// Sort monitors by a top-level field, descending when prefixed with `-`
+/// (e.g. `name`, `-status`, `priority`). `status` is an alias for
+/// `overall_state`. Monitors missing the field sort last.
+fn sort_monitors(monitors: &mut [serde_json::Value], sort: &str) -> Result<()> {
+    let (desc, field) = match sort.strip_prefix('-') {
+        Some(f) => (true, f.trim()),
+        None => (false, sort.trim()),
+    };
+    if field.is_empty() {
+        anyhow::bail!("invalid --sort value: {sort:?} (e.g. name, -name, status)");
+    }
+    let field = if field == "status" {
+        "overall_state"
+    } else {
+        field
+    };
+    monitors.sort_by(|a, b| {
+        match (
+            a.get(field).filter(|v| !v.is_null()),
+            b.get(field).filter(|v| !v.is_null()),
+        ) {
+            (Some(x), Some(y)) => {
+                let ord = compare_values(x, y);
+                if desc {
+                    ord.reverse()
+                } else {
+                    ord
+                }
+            }
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => std::cmp::Ordering::Equal,
+        }
+    });
+    Ok(())
+}
+
+fn compare_values(a: &serde_json::Value, b: &serde_json::Value) -> std::cmp::Ordering {
+    match (a.as_f64(), b.as_f64()) {
+        (Some(x), Some(y)) => x.total_cmp(&y),
+        _ => {
+            let x = a
+                .as_str()
+                .map(str::to_lowercase)
+                .unwrap_or_else(|| a.to_string());
+            let y = b
+                .as_str()
+                .map(str::to_lowercase)
+                .unwrap_or_else(|| b.to_string());
+            x.cmp(&y)
+        }
+    }
+}
+
+/// Count monitors whose `overall_state` matches `state` (case-insensitive).
+fn count_in_state(monitors: &serde_json::Value, state: &str) -> usize {
+    monitors
+        .as_array()
+        .map(|ms| {
+            ms.iter()
+                .filter(|m| {
+                    m.get("overall_state")
+                        .and_then(|s| s.as_str())
+                        .is_some_and(|s| s.eq_ignore_ascii_case(state))
+                })
+                .count()
+        })
+        .unwrap_or(0)
+}
+
+/// Fail (exit code 1) when any listed monitor is in `exit_on_state`.
+fn check_exit_state(monitors: &serde_json::Value, exit_on_state: Option<&str>) -> Result<()> {
+    let Some(state) = exit_on_state else {
+        return Ok(());
+    };
+    let n = count_in_state(monitors, state);
+    if n > 0 {
+        anyhow::bail!("{n} monitor(s) in {state} state");
+    }
+    Ok(())
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -92,21 +500,61 @@ pub async fn get(cfg: &Config, monitor_id: i64) -> Result<()> {
         .get_monitor(monitor_id, GetMonitorOptionalParams::default())
         .await
         .map_err(|e| anyhow::anyhow!("failed to get monitor: {:?}", e))?;
+    if cfg.output_format == crate::config::OutputFormat::Table {
+        return print_monitor_table(cfg, &serde_json::to_value(&resp)?);
+    }
     let meta = Metadata {
         count: None,
         truncated: false,
         command: Some("monitors get".to_string()),
         next_action: None,
     };
-    formatter::format_and_print(&resp, &cfg.output_format, cfg.agent_mode, Some(&meta))
+    formatter::format_and_print(
+        &resp,
+        &cfg.output_format,
+        cfg.agent_mode,
+        Some(&meta),
+        cfg.table_max_width,
+    )
 }
 
 #[cfg(target_arch = "wasm32")]
 pub async fn get(cfg: &Config, monitor_id: i64) -> Result<()> {
     let data = crate::api::get(cfg, &format!("/api/v1/monitor/{monitor_id}"), &[]).await?;
+    if cfg.output_format == crate::config::OutputFormat::Table {
+        return print_monitor_table(cfg, &data);
+    }
     crate::formatter::output(cfg, &data)
 }
 
+/// The monitor fields shown by `monitors get --output=table`, in order.
+fn monitor_fields(monitor: &serde_json::Value) -> Vec<(&'static str, String)> {
+    let text = |key: &str| match &monitor[key] {
+        serde_json::Value::String(s) => s.clone(),
+        serde_json::Value::Number(n) => n.to_string(),
+        _ => String::new(),
+    };
+    vec![
+        ("id", text("id")),
+        ("name", text("name")),
+        ("type", text("type")),
+        ("status", text("overall_state")),
+        ("message", text("message")),
+        ("query", text("query")),
+        ("tags", formatter::join_tags(monitor, "/tags")),
+        ("created", text("created")),
+        ("modified", text("modified")),
+    ]
+}
+
+fn print_monitor_table(cfg: &Config, monitor: &serde_json::Value) -> Result<()> {
+    let rows = monitor_fields(monitor)
+        .into_iter()
+        .map(|(key, value)| vec![key.to_string(), value])
+        .collect();
+    formatter::print_rows(cfg, &["key", "value"], rows)
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 pub async fn create(cfg: &Config, file: &str) -> Result<()> {
     let body: Monitor = util::read_json_file(file)?;
@@ -205,3 +653,196 @@ pub async fn delete(cfg: &Config, monitor_id: i64) -> Result<()> {
     let data = crate::api::delete(cfg, &format!("/api/v1/monitor/{monitor_id}")).await?;
     crate::formatter::output(cfg, &data)
 }
+
+/// Check whether monitors can be deleted. The API answers 409 with the same
+/// body when any monitor is still referenced (by composite monitors or SLOs),
+/// so both statuses are summarized as `can_delete` plus `blocked` with reasons.
+pub async fn check_can_delete(cfg: &Config, monitor_ids: &[i64]) -> Result<()> {
+    let ids = monitor_ids
+        .iter()
+        .map(i64::to_string)
+        .collect::<Vec<_>>()
+        .join(",");
+    let resp = crate::api::get_allowing(
+        cfg,
+        "/api/v1/monitor/can_delete",
+        &[("monitor_ids", ids)],
+        &[409],
+    )
+    .await
+    .map_err(|e| anyhow::anyhow!("failed to check monitors: {:?}", e))?;
+    formatter::output(cfg, &can_delete_summary(&resp))
+}
+
+fn can_delete_summary(resp: &serde_json::Value) -> serde_json::Value {
+    let can_delete = resp
+        .pointer("/data/ok")
+        .cloned()
+        .unwrap_or_else(|| serde_json::json!([]));
+    let blocked: Vec<serde_json::Value> = resp
+        .get("errors")
+        .and_then(|e| e.as_object())
+        .map(|errors| {
+            errors
+                .iter()
+                .map(|(id, reasons)| {
+                    let monitor_id = id
+                        .parse::<i64>()
+                        .map(serde_json::Value::from)
+                        .unwrap_or_else(|_| serde_json::json!(id));
+                    serde_json::json!({ "monitor_id": monitor_id, "reasons": reasons })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    serde_json::json!({ "can_delete": can_delete, "blocked": blocked })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_monitor_fields() {
+        let monitor = serde_json::json!({
+            "id": 12345678,
+            "name": "High CPU",
+            "type": "metric alert",
+            "overall_state": "No Data",
+            "message": "CPU is high @slack-ops",
+            "query": "avg(last_5m):avg:system.cpu.user{*} > 90",
+            "tags": ["team:backend", "env:prod"],
+            "created": "2026-01-01T00:00:00Z",
+            "options": { "thresholds": { "critical": 90.0 } }
+        });
+        let fields = monitor_fields(&monitor);
+        let keys: Vec<_> = fields.iter().map(|(k, _)| *k).collect();
+        assert_eq!(
+            keys,
+            ["id", "name", "type", "status", "message", "query", "tags", "created", "modified"]
+        );
+        let value = |key: &str| fields.iter().find(|(k, _)| *k == key).unwrap().1.clone();
+        assert_eq!(value("id"), "12345678");
+        assert_eq!(value("status"), "No Data");
+        assert_eq!(value("tags"), "team:backend,env:prod");
+        assert_eq!(value("modified"), "");
+    }
+
+    #[test]
+    fn test_mute_end_relative_counts_forward() {
+        let end = mute_end("2h").unwrap();
+        let end = chrono::DateTime::parse_from_rfc3339(&end)
+            .unwrap()
+            .timestamp();
+        let expected = chrono::Utc::now().timestamp() + 7200;
+        assert!((end - expected).abs() <= 2, "end {end} != {expected}");
+    }
+
+    #[test]
+    fn test_mute_end_absolute_and_past() {
+        assert_eq!(
+            mute_end("2099-01-01T00:00:00Z").unwrap(),
+            "2099-01-01T00:00:00Z"
+        );
+        assert!(mute_end("2001-01-01T00:00:00Z").is_err());
+        assert!(mute_end("now").is_err());
+    }
+
+    #[test]
+    fn test_mute_downtime_body() {
+        let body = mute_downtime_body(42, Some("2099-01-01T00:00:00Z"), Some("maintenance"));
+        assert_eq!(
+            body,
+            serde_json::json!({"data": {"type": "downtime", "attributes": {
+                "scope": "*",
+                "monitor_identifier": {"monitor_id": 42},
+                "schedule": {"end": "2099-01-01T00:00:00Z"},
+                "message": "maintenance"
+            }}})
+        );
+        let body = mute_downtime_body(42, None, None);
+        assert!(body["data"]["attributes"].get("schedule").is_none());
+    }
+
+    #[test]
+    fn test_can_delete_summary() {
+        let resp = serde_json::json!({
+            "data": { "ok": [1] },
+            "errors": { "2": ["monitor [2] is referenced in composite monitors: [3]"] }
+        });
+        let summary = can_delete_summary(&resp);
+        assert_eq!(summary["can_delete"], serde_json::json!([1]));
+        assert_eq!(summary["blocked"][0]["monitor_id"], 2);
+        assert_eq!(
+            summary["blocked"][0]["reasons"][0],
+            "monitor [2] is referenced in composite monitors: [3]"
+        );
+    }
+
+    #[test]
+    fn test_can_delete_summary_all_ok() {
+        let summary = can_delete_summary(&serde_json::json!({ "data": { "ok": [1, 2] } }));
+        assert_eq!(summary["can_delete"], serde_json::json!([1, 2]));
+        assert_eq!(summary["blocked"], serde_json::json!([]));
+    }
+
+    fn names(monitors: &[serde_json::Value]) -> Vec<&str> {
+        monitors
+            .iter()
+            .map(|m| m["name"].as_str().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_sort_monitors_by_name() {
+        let mut ms = vec![
+            serde_json::json!({"name": "beta"}),
+            serde_json::json!({"name": "Alpha"}),
+            serde_json::json!({"name": "gamma"}),
+        ];
+        sort_monitors(&mut ms, "name").unwrap();
+        assert_eq!(names(&ms), vec!["Alpha", "beta", "gamma"]);
+        sort_monitors(&mut ms, "-name").unwrap();
+        assert_eq!(names(&ms), vec!["gamma", "beta", "Alpha"]);
+    }
+
+    #[test]
+    fn test_sort_monitors_status_alias_and_missing_last() {
+        let mut ms = vec![
+            serde_json::json!({"name": "a", "overall_state": "OK"}),
+            serde_json::json!({"name": "b"}),
+            serde_json::json!({"name": "c", "overall_state": "Alert"}),
+        ];
+        sort_monitors(&mut ms, "status").unwrap();
+        assert_eq!(names(&ms), vec!["c", "a", "b"]);
+    }
+
+    #[test]
+    fn test_sort_monitors_numeric() {
+        let mut ms = vec![
+            serde_json::json!({"name": "a", "priority": 10}),
+            serde_json::json!({"name": "b", "priority": 2}),
+        ];
+        sort_monitors(&mut ms, "priority").unwrap();
+        assert_eq!(names(&ms), vec!["b", "a"]);
+    }
+
+    #[test]
+    fn test_sort_monitors_rejects_empty_field() {
+        assert!(sort_monitors(&mut [], "-").is_err());
+    }
+
+    #[test]
+    fn test_check_exit_state() {
+        let monitors = serde_json::json!([
+            { "id": 1, "overall_state": "OK" },
+            { "id": 2, "overall_state": "Alert" },
+            { "id": 3, "overall_state": "No Data" }
+        ]);
+        assert_eq!(count_in_state(&monitors, "alert"), 1);
+        assert_eq!(count_in_state(&monitors, "No Data"), 1);
+        assert!(check_exit_state(&monitors, None).is_ok());
+        assert!(check_exit_state(&monitors, Some("Warn")).is_ok());
+        assert!(check_exit_state(&monitors, Some("Alert")).is_err());
+    }
+}
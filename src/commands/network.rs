@@ -1,5 +1,11 @@
 use anyhow::Result;
+#[cfg(not(target_arch = "wasm32"))]
+use datadog_api_client::datadogV2::api_network_device_monitoring::{
+    ListDevicesOptionalParams, NetworkDeviceMonitoringAPI,
+};
 
+#[cfg(not(target_arch = "wasm32"))]
+use crate::client;
 use crate::config::Config;
 use crate::formatter;
 
@@ -17,12 +23,75 @@ pub async fn flows_list(cfg: &Config) -> Result<()> {
     formatter::output(cfg, &placeholder)
 }
 
+#[cfg(not(target_arch = "wasm32"))]
 pub async fn devices_list(cfg: &Config) -> Result<()> {
-    let placeholder = serde_json::json!({
-        "data": [],
-        "meta": {
-            "message": "Network devices list - API endpoint implementation pending"
-        }
-    });
-    formatter::output(cfg, &placeholder)
+    let dd_cfg = client::make_dd_config(cfg);
+    let api = match client::make_bearer_client(cfg) {
+        Some(c) => NetworkDeviceMonitoringAPI::with_client_and_config(dd_cfg, c),
+        None => NetworkDeviceMonitoringAPI::with_config(dd_cfg),
+    };
+    let resp = api
+        .list_devices(ListDevicesOptionalParams::default())
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to list network devices: {e:?}"))?;
+    formatter::output_rows(cfg, &resp, DEVICE_COLUMNS, device_rows)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn devices_list(cfg: &Config) -> Result<()> {
+    let data = crate::api::get(cfg, "/api/v2/ndm/devices", &[])
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to list network devices: {e:?}"))?;
+    formatter::output_rows(cfg, &data, DEVICE_COLUMNS, device_rows)
+}
+
+const DEVICE_COLUMNS: &[&str] = &["id", "name", "ip", "tags", "status"];
+
+fn device_rows(resp: &serde_json::Value) -> Vec<Vec<String>> {
+    resp.get("data")
+        .and_then(|d| d.as_array())
+        .into_iter()
+        .flatten()
+        .map(|d| {
+            vec![
+                formatter::str_at(d, "/id"),
+                formatter::str_at(d, "/attributes/name"),
+                formatter::str_at(d, "/attributes/ip_address"),
+                formatter::join_tags(d, "/attributes/tags"),
+                formatter::str_at(d, "/attributes/status"),
+            ]
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_device_rows() {
+        let resp = serde_json::json!({
+            "data": [{
+                "id": "default:10.0.0.1",
+                "type": "devices",
+                "attributes": {
+                    "name": "core-switch-1",
+                    "ip_address": "10.0.0.1",
+                    "status": "ok",
+                    "tags": ["site:nyc", "vendor:cisco"],
+                    "interface_statuses": { "up": 46, "down": 2 }
+                }
+            }, {
+                "id": "default:10.0.0.2",
+                "type": "devices"
+            }]
+        });
+        let rows = device_rows(&resp);
+        assert_eq!(rows.len(), 2);
+        assert_eq!(
+            rows[0][1..],
+            ["core-switch-1", "10.0.0.1", "site:nyc,vendor:cisco", "ok"]
+        );
+        assert_eq!(rows[1], ["default:10.0.0.2", "", "", "", ""]);
+    }
 }
@@ -114,3 +114,54 @@ pub async fn update(cfg: &Config, notebook_id: i64, file: &str) -> Result<()> {
     let data = crate::api::put(cfg, &format!("/api/v1/notebooks/{notebook_id}"), &body).await?;
     crate::formatter::output(cfg, &data)
 }
+
+/// Turn a fetched notebook into a create request for a copy named `name`.
+/// Server-assigned fields (`id`, `author`, `created`, `modified`, cell IDs)
+/// are dropped; cells, time, status, and metadata are kept.
+fn template_create_body(source: &serde_json::Value, name: &str) -> Result<serde_json::Value> {
+    let mut attributes = source["data"]["attributes"].clone();
+    let Some(attrs) = attributes.as_object_mut() else {
+        anyhow::bail!("source notebook has no attributes");
+    };
+    for field in ["author", "created", "modified"] {
+        attrs.remove(field);
+    }
+    if let Some(cells) = attrs.get_mut("cells").and_then(|c| c.as_array_mut()) {
+        for cell in cells.iter_mut().filter_map(|c| c.as_object_mut()) {
+            cell.remove("id");
+        }
+    }
+    attrs.insert("name".into(), serde_json::json!(name));
+    Ok(serde_json::json!({
+        "data": { "type": "notebooks", "attributes": attributes }
+    }))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn create_from_template(cfg: &Config, source_id: i64, name: &str) -> Result<()> {
+    let dd_cfg = client::make_dd_config(cfg);
+    let api = match client::make_bearer_client(cfg) {
+        Some(c) => NotebooksAPI::with_client_and_config(dd_cfg, c),
+        None => NotebooksAPI::with_config(dd_cfg),
+    };
+    let source = api
+        .get_notebook(source_id)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to get template notebook: {e:?}"))?;
+    let body = template_create_body(&serde_json::to_value(&source)?, name)?;
+    let body: NotebookCreateRequest = serde_json::from_value(body)
+        .map_err(|e| anyhow::anyhow!("failed to build notebook from template: {e}"))?;
+    let resp = api
+        .create_notebook(body)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to create notebook: {e:?}"))?;
+    formatter::output(cfg, &resp)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn create_from_template(cfg: &Config, source_id: i64, name: &str) -> Result<()> {
+    let source = crate::api::get(cfg, &format!("/api/v1/notebooks/{source_id}"), &[]).await?;
+    let body = template_create_body(&source, name)?;
+    let data = crate::api::post(cfg, "/api/v1/notebooks", &body).await?;
+    crate::formatter::output(cfg, &data)
+}
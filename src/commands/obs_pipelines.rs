@@ -1,9 +1,152 @@
-use anyhow::{bail, Result};
+use anyhow::Result;
+#[cfg(not(target_arch = "wasm32"))]
+use datadog_api_client::datadogV2::api_observability_pipelines::{
+    ListPipelinesOptionalParams, ObservabilityPipelinesAPI,
+};
+#[cfg(not(target_arch = "wasm32"))]
+use datadog_api_client::datadogV2::model::{ObservabilityPipeline, ObservabilityPipelineSpec};
 
-pub fn list() -> Result<()> {
-    bail!("obs-pipelines commands are not yet implemented (API endpoints pending)")
+#[cfg(not(target_arch = "wasm32"))]
+use crate::client;
+use crate::config::Config;
+use crate::formatter;
+
+#[cfg(target_arch = "wasm32")]
+const PIPELINES_PATH: &str = "/api/v2/obs-pipelines/pipelines";
+
+#[cfg(not(target_arch = "wasm32"))]
+fn make_api(cfg: &Config) -> ObservabilityPipelinesAPI {
+    let dd_cfg = client::make_dd_config(cfg);
+    match client::make_bearer_client(cfg) {
+        Some(c) => ObservabilityPipelinesAPI::with_client_and_config(dd_cfg, c),
+        None => ObservabilityPipelinesAPI::with_config(dd_cfg),
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn list(cfg: &Config, page_size: i64, page_number: i64) -> Result<()> {
+    let mut params = ListPipelinesOptionalParams::default();
+    if page_size > 0 {
+        params.page_size = Some(page_size);
+    }
+    if page_number > 0 {
+        params.page_number = Some(page_number);
+    }
+    let resp = make_api(cfg)
+        .list_pipelines(params)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to list pipelines: {e:?}"))?;
+    formatter::output(cfg, &resp)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn list(cfg: &Config, page_size: i64, page_number: i64) -> Result<()> {
+    let mut query: Vec<(&str, String)> = Vec::new();
+    if page_size > 0 {
+        query.push(("page[size]", page_size.to_string()));
+    }
+    if page_number > 0 {
+        query.push(("page[number]", page_number.to_string()));
+    }
+    let data = crate::api::get(cfg, PIPELINES_PATH, &query)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to list pipelines: {e:?}"))?;
+    formatter::output(cfg, &data)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn get(cfg: &Config, pipeline_id: &str) -> Result<()> {
+    let resp = make_api(cfg)
+        .get_pipeline(pipeline_id.to_string())
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to get pipeline: {e:?}"))?;
+    formatter::output(cfg, &resp)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn get(cfg: &Config, pipeline_id: &str) -> Result<()> {
+    let path = format!("{PIPELINES_PATH}/{pipeline_id}");
+    let data = crate::api::get(cfg, &path, &[])
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to get pipeline: {e:?}"))?;
+    formatter::output(cfg, &data)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn create(cfg: &Config, file: &str) -> Result<()> {
+    let body: ObservabilityPipelineSpec = crate::util::read_json_file(file)?;
+    let resp = make_api(cfg)
+        .create_pipeline(body)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to create pipeline: {e:?}"))?;
+    formatter::output(cfg, &resp)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn create(cfg: &Config, file: &str) -> Result<()> {
+    let body: serde_json::Value = crate::util::read_json_file(file)?;
+    let data = crate::api::post(cfg, PIPELINES_PATH, &body)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to create pipeline: {e:?}"))?;
+    formatter::output(cfg, &data)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn update(cfg: &Config, pipeline_id: &str, file: &str) -> Result<()> {
+    let body: ObservabilityPipeline = crate::util::read_json_file(file)?;
+    let resp = make_api(cfg)
+        .update_pipeline(pipeline_id.to_string(), body)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to update pipeline: {e:?}"))?;
+    formatter::output(cfg, &resp)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn update(cfg: &Config, pipeline_id: &str, file: &str) -> Result<()> {
+    let body: serde_json::Value = crate::util::read_json_file(file)?;
+    let path = format!("{PIPELINES_PATH}/{pipeline_id}");
+    let data = crate::api::put(cfg, &path, &body)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to update pipeline: {e:?}"))?;
+    formatter::output(cfg, &data)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn delete(cfg: &Config, pipeline_id: &str) -> Result<()> {
+    make_api(cfg)
+        .delete_pipeline(pipeline_id.to_string())
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to delete pipeline: {e:?}"))?;
+    println!("Pipeline {pipeline_id} deleted.");
+    Ok(())
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn delete(cfg: &Config, pipeline_id: &str) -> Result<()> {
+    let path = format!("{PIPELINES_PATH}/{pipeline_id}");
+    crate::api::delete(cfg, &path)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to delete pipeline: {e:?}"))?;
+    println!("Pipeline {pipeline_id} deleted.");
+    Ok(())
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn validate(cfg: &Config, file: &str) -> Result<()> {
+    let body: ObservabilityPipelineSpec = crate::util::read_json_file(file)?;
+    let resp = make_api(cfg)
+        .validate_pipeline(body)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to validate pipeline: {e:?}"))?;
+    formatter::output(cfg, &resp)
 }
 
-pub fn get(_pipeline_id: &str) -> Result<()> {
-    bail!("obs-pipelines get is not yet implemented (API endpoints pending)")
+#[cfg(target_arch = "wasm32")]
+pub async fn validate(cfg: &Config, file: &str) -> Result<()> {
+    let body: serde_json::Value = crate::util::read_json_file(file)?;
+    let path = format!("{PIPELINES_PATH}/validate");
+    let data = crate::api::post(cfg, &path, &body)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to validate pipeline: {e:?}"))?;
+    formatter::output(cfg, &data)
 }
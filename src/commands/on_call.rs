@@ -1,14 +1,18 @@
 use anyhow::Result;
 #[cfg(not(target_arch = "wasm32"))]
+use datadog_api_client::datadogV2::api_on_call::{
+    GetScheduleOnCallUserOptionalParams, GetTeamOnCallUsersOptionalParams, OnCallAPI,
+};
+#[cfg(not(target_arch = "wasm32"))]
 use datadog_api_client::datadogV2::api_teams::{
     GetTeamMembershipsOptionalParams, ListTeamsOptionalParams, TeamsAPI,
 };
 #[cfg(not(target_arch = "wasm32"))]
 use datadog_api_client::datadogV2::model::{
     RelationshipToUserTeamUser, RelationshipToUserTeamUserData, TeamCreate, TeamCreateAttributes,
-    TeamCreateRequest, TeamType, TeamUpdate, TeamUpdateAttributes, TeamUpdateRequest,
-    UserTeamAttributes, UserTeamCreate, UserTeamRelationships, UserTeamRequest, UserTeamRole,
-    UserTeamType, UserTeamUpdate, UserTeamUpdateRequest, UserTeamUserType,
+    TeamCreateRequest, TeamNotificationRuleRequest, TeamType, TeamUpdate, TeamUpdateAttributes,
+    TeamUpdateRequest, UserTeamAttributes, UserTeamCreate, UserTeamRelationships, UserTeamRequest,
+    UserTeamRole, UserTeamType, UserTeamUpdate, UserTeamUpdateRequest, UserTeamUserType,
 };
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -36,8 +40,34 @@ pub async fn teams_list(cfg: &Config) -> Result<()> {
     crate::formatter::output(cfg, &data)
 }
 
+/// Build the `include` query value for related team resources.
+fn team_includes(include_links: bool, include_memberships: bool) -> Option<String> {
+    let mut include = Vec::new();
+    if include_links {
+        include.push("links");
+    }
+    if include_memberships {
+        include.push("memberships");
+    }
+    (!include.is_empty()).then(|| include.join(","))
+}
+
 #[cfg(not(target_arch = "wasm32"))]
-pub async fn teams_get(cfg: &Config, team_id: &str) -> Result<()> {
+pub async fn teams_get(
+    cfg: &Config,
+    team_id: &str,
+    include_links: bool,
+    include_memberships: bool,
+) -> Result<()> {
+    // The typed client has no `include` parameter, so related resources go
+    // through the raw API.
+    if let Some(include) = team_includes(include_links, include_memberships) {
+        let path = format!("/api/v2/team/{team_id}");
+        let data = crate::api::get(cfg, &path, &[("include", include)])
+            .await
+            .map_err(|e| anyhow::anyhow!("failed to get team: {e:?}"))?;
+        return formatter::output(cfg, &data);
+    }
     let dd_cfg = client::make_dd_config(cfg);
     let api = match client::make_bearer_client(cfg) {
         Some(c) => TeamsAPI::with_client_and_config(dd_cfg, c),
@@ -51,8 +81,17 @@ pub async fn teams_get(cfg: &Config, team_id: &str) -> Result<()> {
 }
 
 #[cfg(target_arch = "wasm32")]
-pub async fn teams_get(cfg: &Config, team_id: &str) -> Result<()> {
-    let data = crate::api::get(cfg, &format!("/api/v2/teams/{team_id}"), &[]).await?;
+pub async fn teams_get(
+    cfg: &Config,
+    team_id: &str,
+    include_links: bool,
+    include_memberships: bool,
+) -> Result<()> {
+    let mut query: Vec<(&str, String)> = Vec::new();
+    if let Some(include) = team_includes(include_links, include_memberships) {
+        query.push(("include", include));
+    }
+    let data = crate::api::get(cfg, &format!("/api/v2/team/{team_id}"), &query).await?;
     crate::formatter::output(cfg, &data)
 }
 
@@ -141,6 +180,79 @@ pub async fn teams_update(cfg: &Config, team_id: &str, name: &str, handle: &str)
     crate::formatter::output(cfg, &data)
 }
 
+/// Format the team's current on-call responders as `Name <email>` lines,
+/// resolving users from the included records. Falls back to the user ID when
+/// a responder was not included.
+fn team_on_call_lines(resp: &serde_json::Value) -> Vec<String> {
+    let included = resp
+        .get("included")
+        .and_then(|i| i.as_array())
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+    resp.pointer("/data/relationships/responders/data")
+        .and_then(|d| d.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|r| r.get("id").and_then(|v| v.as_str()))
+        .map(|id| {
+            let user = included
+                .iter()
+                .find(|i| i.get("id").and_then(|v| v.as_str()) == Some(id));
+            let attr = |key: &str| {
+                user.and_then(|u| u.pointer(&format!("/attributes/{key}")))
+                    .and_then(|v| v.as_str())
+                    .filter(|v| !v.is_empty())
+            };
+            match (attr("name"), attr("email")) {
+                (Some(name), Some(email)) => format!("{name} <{email}>"),
+                (None, Some(email)) => email.to_string(),
+                (Some(name), None) => name.to_string(),
+                (None, None) => id.to_string(),
+            }
+        })
+        .collect()
+}
+
+fn print_team_on_call<T: serde::Serialize>(cfg: &Config, team_id: &str, resp: &T) -> Result<()> {
+    if cfg.output_format != crate::config::OutputFormat::Table {
+        return formatter::output(cfg, resp);
+    }
+    let lines = team_on_call_lines(&serde_json::to_value(resp)?);
+    if lines.is_empty() {
+        println!("No one is currently on call for team {team_id}");
+    }
+    for line in lines {
+        println!("{line}");
+    }
+    Ok(())
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn teams_get_on_call(cfg: &Config, team_id: &str) -> Result<()> {
+    let dd_cfg = client::make_dd_config(cfg);
+    let api = match client::make_bearer_client(cfg) {
+        Some(c) => OnCallAPI::with_client_and_config(dd_cfg, c),
+        None => OnCallAPI::with_config(dd_cfg),
+    };
+    let resp = api
+        .get_team_on_call_users(
+            team_id.to_string(),
+            GetTeamOnCallUsersOptionalParams::default().include("responders".to_string()),
+        )
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to get team on-call users: {e:?}"))?;
+    print_team_on_call(cfg, team_id, &resp)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn teams_get_on_call(cfg: &Config, team_id: &str) -> Result<()> {
+    let path = format!("/api/v2/on-call/teams/{team_id}/on-call");
+    let data = crate::api::get(cfg, &path, &[("include", "responders".to_string())])
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to get team on-call users: {e:?}"))?;
+    print_team_on_call(cfg, team_id, &data)
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 pub async fn memberships_list(cfg: &Config, team_id: &str, page_size: i64) -> Result<()> {
     let dd_cfg = client::make_dd_config(cfg);
@@ -302,3 +414,328 @@ pub async fn memberships_remove(cfg: &Config, team_id: &str, user_id: &str) -> R
     println!("Membership for user {user_id} removed from team {team_id}.");
     Ok(())
 }
+
+// ---------------------------------------------------------------------------
+// Team notification rules
+// ---------------------------------------------------------------------------
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn notification_rules_list(cfg: &Config, team_id: &str) -> Result<()> {
+    let dd_cfg = client::make_dd_config(cfg);
+    let api = match client::make_bearer_client(cfg) {
+        Some(c) => TeamsAPI::with_client_and_config(dd_cfg, c),
+        None => TeamsAPI::with_config(dd_cfg),
+    };
+    let resp = api
+        .get_team_notification_rules(team_id.to_string())
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to list notification rules: {e:?}"))?;
+    formatter::output(cfg, &resp)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn notification_rules_list(cfg: &Config, team_id: &str) -> Result<()> {
+    let path = format!("/api/v2/team/{team_id}/notification-rules");
+    let data = crate::api::get(cfg, &path, &[]).await?;
+    formatter::output(cfg, &data)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn notification_rules_get(cfg: &Config, team_id: &str, rule_id: &str) -> Result<()> {
+    let dd_cfg = client::make_dd_config(cfg);
+    let api = match client::make_bearer_client(cfg) {
+        Some(c) => TeamsAPI::with_client_and_config(dd_cfg, c),
+        None => TeamsAPI::with_config(dd_cfg),
+    };
+    let resp = api
+        .get_team_notification_rule(team_id.to_string(), rule_id.to_string())
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to get notification rule: {e:?}"))?;
+    formatter::output(cfg, &resp)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn notification_rules_get(cfg: &Config, team_id: &str, rule_id: &str) -> Result<()> {
+    let path = format!("/api/v2/team/{team_id}/notification-rules/{rule_id}");
+    let data = crate::api::get(cfg, &path, &[]).await?;
+    formatter::output(cfg, &data)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn notification_rules_create(cfg: &Config, team_id: &str, file: &str) -> Result<()> {
+    let body: TeamNotificationRuleRequest = crate::util::read_json_file(file)?;
+    let dd_cfg = client::make_dd_config(cfg);
+    let api = match client::make_bearer_client(cfg) {
+        Some(c) => TeamsAPI::with_client_and_config(dd_cfg, c),
+        None => TeamsAPI::with_config(dd_cfg),
+    };
+    let resp = api
+        .create_team_notification_rule(team_id.to_string(), body)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to create notification rule: {e:?}"))?;
+    formatter::output(cfg, &resp)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn notification_rules_create(cfg: &Config, team_id: &str, file: &str) -> Result<()> {
+    let body: serde_json::Value = crate::util::read_json_file(file)?;
+    let path = format!("/api/v2/team/{team_id}/notification-rules");
+    let data = crate::api::post(cfg, &path, &body).await?;
+    formatter::output(cfg, &data)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn notification_rules_update(
+    cfg: &Config,
+    team_id: &str,
+    rule_id: &str,
+    file: &str,
+) -> Result<()> {
+    let body: TeamNotificationRuleRequest = crate::util::read_json_file(file)?;
+    let dd_cfg = client::make_dd_config(cfg);
+    let api = match client::make_bearer_client(cfg) {
+        Some(c) => TeamsAPI::with_client_and_config(dd_cfg, c),
+        None => TeamsAPI::with_config(dd_cfg),
+    };
+    let resp = api
+        .update_team_notification_rule(team_id.to_string(), rule_id.to_string(), body)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to update notification rule: {e:?}"))?;
+    formatter::output(cfg, &resp)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn notification_rules_update(
+    cfg: &Config,
+    team_id: &str,
+    rule_id: &str,
+    file: &str,
+) -> Result<()> {
+    let body: serde_json::Value = crate::util::read_json_file(file)?;
+    let path = format!("/api/v2/team/{team_id}/notification-rules/{rule_id}");
+    let data = crate::api::put(cfg, &path, &body).await?;
+    formatter::output(cfg, &data)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn notification_rules_delete(cfg: &Config, team_id: &str, rule_id: &str) -> Result<()> {
+    let dd_cfg = client::make_dd_config(cfg);
+    let api = match client::make_bearer_client(cfg) {
+        Some(c) => TeamsAPI::with_client_and_config(dd_cfg, c),
+        None => TeamsAPI::with_config(dd_cfg),
+    };
+    api.delete_team_notification_rule(team_id.to_string(), rule_id.to_string())
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to delete notification rule: {e:?}"))?;
+    println!("Notification rule {rule_id} deleted from team {team_id}.");
+    Ok(())
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn notification_rules_delete(cfg: &Config, team_id: &str, rule_id: &str) -> Result<()> {
+    let path = format!("/api/v2/team/{team_id}/notification-rules/{rule_id}");
+    crate::api::delete(cfg, &path).await?;
+    println!("Notification rule {rule_id} deleted from team {team_id}.");
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Team links
+// ---------------------------------------------------------------------------
+
+fn team_link_body(label: &str, url: &str) -> serde_json::Value {
+    serde_json::json!({
+        "data": {
+            "type": "team_links",
+            "attributes": { "label": label, "url": url }
+        }
+    })
+}
+
+pub async fn links_list(cfg: &Config, team_id: &str) -> Result<()> {
+    let path = format!("/api/v2/team/{team_id}/links");
+    let data = crate::api::get(cfg, &path, &[]).await?;
+    formatter::output(cfg, &data)
+}
+
+pub async fn links_create(cfg: &Config, team_id: &str, label: &str, url: &str) -> Result<()> {
+    let path = format!("/api/v2/team/{team_id}/links");
+    let data = crate::api::post(cfg, &path, &team_link_body(label, url)).await?;
+    formatter::output(cfg, &data)
+}
+
+/// Update a team link. The API replaces both label and URL, so any value not
+/// given is carried over from the current link.
+pub async fn links_update(
+    cfg: &Config,
+    team_id: &str,
+    link_id: &str,
+    label: Option<String>,
+    url: Option<String>,
+) -> Result<()> {
+    if label.is_none() && url.is_none() {
+        anyhow::bail!("at least one of --label or --url is required");
+    }
+    let path = format!("/api/v2/team/{team_id}/links/{link_id}");
+    let (label, url) = match (label, url) {
+        (Some(label), Some(url)) => (label, url),
+        (label, url) => {
+            let current = crate::api::get(cfg, &path, &[]).await?;
+            let attr = |key: &str| {
+                current
+                    .pointer(&format!("/data/attributes/{key}"))
+                    .and_then(|v| v.as_str())
+                    .unwrap_or_default()
+                    .to_string()
+            };
+            (
+                label.unwrap_or_else(|| attr("label")),
+                url.unwrap_or_else(|| attr("url")),
+            )
+        }
+    };
+    let data = crate::api::patch(cfg, &path, &team_link_body(&label, &url)).await?;
+    formatter::output(cfg, &data)
+}
+
+pub async fn links_delete(cfg: &Config, team_id: &str, link_id: &str) -> Result<()> {
+    let path = format!("/api/v2/team/{team_id}/links/{link_id}");
+    crate::api::delete(cfg, &path).await?;
+    println!("Link {link_id} deleted from team {team_id}.");
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Schedules
+// ---------------------------------------------------------------------------
+
+/// Extract the on-call user(s) from a schedule shift response, resolving
+/// names and emails from the included user records.
+fn on_call_user_rows(resp: &serde_json::Value) -> Vec<Vec<String>> {
+    let Some(shift) = resp.get("data").filter(|d| !d.is_null()) else {
+        return Vec::new();
+    };
+    let user_id = shift
+        .pointer("/relationships/user/data/id")
+        .and_then(|v| v.as_str())
+        .unwrap_or_default();
+    let user = resp
+        .get("included")
+        .and_then(|i| i.as_array())
+        .and_then(|items| {
+            items
+                .iter()
+                .find(|i| i.get("id").and_then(|v| v.as_str()) == Some(user_id))
+        });
+    let attr = |key: &str| {
+        user.and_then(|u| u.pointer(&format!("/attributes/{key}")))
+            .and_then(|v| v.as_str())
+            .unwrap_or_default()
+            .to_string()
+    };
+    let name = match attr("name") {
+        n if n.is_empty() => user_id.to_string(),
+        n => n,
+    };
+    vec![vec![
+        name,
+        attr("email"),
+        formatter::str_at(shift, "/attributes/end"),
+    ]]
+}
+
+fn print_on_call_users<T: serde::Serialize>(cfg: &Config, resp: &T) -> Result<()> {
+    formatter::output_rows(
+        cfg,
+        resp,
+        &["name", "email", "shift_end"],
+        on_call_user_rows,
+    )
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn schedules_on_call_users(cfg: &Config, schedule_id: &str) -> Result<()> {
+    let dd_cfg = client::make_dd_config(cfg);
+    let api = match client::make_bearer_client(cfg) {
+        Some(c) => OnCallAPI::with_client_and_config(dd_cfg, c),
+        None => OnCallAPI::with_config(dd_cfg),
+    };
+    let resp = api
+        .get_schedule_on_call_user(
+            schedule_id.to_string(),
+            GetScheduleOnCallUserOptionalParams::default().include("user".to_string()),
+        )
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to get on-call user: {e:?}"))?;
+    print_on_call_users(cfg, &resp)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn schedules_on_call_users(cfg: &Config, schedule_id: &str) -> Result<()> {
+    let path = format!("/api/v2/on-call/schedules/{schedule_id}/on-call");
+    let data = crate::api::get(cfg, &path, &[("include", "user".to_string())])
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to get on-call user: {e:?}"))?;
+    print_on_call_users(cfg, &data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_team_on_call_lines() {
+        let resp = serde_json::json!({
+            "data": {
+                "id": "team-1",
+                "type": "team_oncall_responders",
+                "relationships": {"responders": {"data": [
+                    {"id": "u1", "type": "users"},
+                    {"id": "u2", "type": "users"},
+                    {"id": "u3", "type": "users"}
+                ]}}
+            },
+            "included": [
+                {"id": "u1", "type": "users", "attributes": {"name": "Ada", "email": "ada@example.com"}},
+                {"id": "u2", "type": "users", "attributes": {"name": "", "email": "grace@example.com"}}
+            ]
+        });
+        assert_eq!(
+            team_on_call_lines(&resp),
+            vec!["Ada <ada@example.com>", "grace@example.com", "u3"]
+        );
+        assert!(team_on_call_lines(&serde_json::json!({"data": {}})).is_empty());
+    }
+
+    #[test]
+    fn test_on_call_user_rows_resolves_included_user() {
+        let resp = serde_json::json!({
+            "data": {
+                "id": "shift-1",
+                "type": "shifts",
+                "attributes": {"start": "2026-10-15T08:00:00Z", "end": "2026-10-16T08:00:00Z"},
+                "relationships": {"user": {"data": {"id": "u1", "type": "users"}}}
+            },
+            "included": [
+                {"id": "u1", "type": "users", "attributes": {"name": "Ada", "email": "ada@example.com"}}
+            ]
+        });
+        let rows = on_call_user_rows(&resp);
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0], ["Ada", "ada@example.com", "2026-10-16T08:00:00Z"]);
+    }
+
+    #[test]
+    fn test_on_call_user_rows_without_included_falls_back_to_id() {
+        let resp = serde_json::json!({
+            "data": {"relationships": {"user": {"data": {"id": "u1"}}}}
+        });
+        let rows = on_call_user_rows(&resp);
+        assert_eq!(rows[0], ["u1", "", ""]);
+    }
+
+    #[test]
+    fn test_on_call_user_rows_no_shift() {
+        assert!(on_call_user_rows(&serde_json::json!({"data": null})).is_empty());
+    }
+}
@@ -1,6 +1,12 @@
 use anyhow::Result;
 #[cfg(not(target_arch = "wasm32"))]
 use datadog_api_client::datadogV1::api_organizations::OrganizationsAPI;
+#[cfg(not(target_arch = "wasm32"))]
+use datadog_api_client::datadogV2::api_organizations::OrganizationsAPI as OrganizationsV2API;
+#[cfg(not(target_arch = "wasm32"))]
+use datadog_api_client::datadogV2::model::{
+    OrgConfigType, OrgConfigWrite, OrgConfigWriteAttributes, OrgConfigWriteRequest,
+};
 
 #[cfg(not(target_arch = "wasm32"))]
 use crate::client;
@@ -46,3 +52,101 @@ pub async fn get(cfg: &Config) -> Result<()> {
     let data = crate::api::get(cfg, "/api/v1/org/current", &[]).await?;
     crate::formatter::output(cfg, &data)
 }
+
+// ---------------------------------------------------------------------------
+// Org settings (v2 org configs)
+// ---------------------------------------------------------------------------
+
+#[cfg(not(target_arch = "wasm32"))]
+fn make_v2_api(cfg: &Config) -> OrganizationsV2API {
+    let dd_cfg = client::make_dd_config(cfg);
+    match client::make_bearer_client(cfg) {
+        Some(c) => OrganizationsV2API::with_client_and_config(dd_cfg, c),
+        None => OrganizationsV2API::with_config(dd_cfg),
+    }
+}
+
+/// Parse a setting value given on the command line. JSON literals such as
+/// `true`, `30` or `{"a":1}` keep their type; anything else is a string.
+fn parse_setting_value(value: &str) -> serde_json::Value {
+    serde_json::from_str(value).unwrap_or_else(|_| serde_json::Value::String(value.to_string()))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn settings_list(cfg: &Config) -> Result<()> {
+    let resp = make_v2_api(cfg)
+        .list_org_configs()
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to list org settings: {e:?}"))?;
+    formatter::output(cfg, &resp)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn settings_list(cfg: &Config) -> Result<()> {
+    let data = crate::api::get(cfg, "/api/v2/org_configs", &[])
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to list org settings: {e:?}"))?;
+    formatter::output(cfg, &data)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn settings_get(cfg: &Config, setting_name: &str) -> Result<()> {
+    let resp = make_v2_api(cfg)
+        .get_org_config(setting_name.to_string())
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to get org setting {setting_name}: {e:?}"))?;
+    formatter::output(cfg, &resp)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn settings_get(cfg: &Config, setting_name: &str) -> Result<()> {
+    let path = format!("/api/v2/org_configs/{setting_name}");
+    let data = crate::api::get(cfg, &path, &[])
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to get org setting {setting_name}: {e:?}"))?;
+    formatter::output(cfg, &data)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn settings_update(cfg: &Config, setting_name: &str, value: &str) -> Result<()> {
+    let body = OrgConfigWriteRequest::new(OrgConfigWrite::new(
+        OrgConfigWriteAttributes::new(parse_setting_value(value)),
+        OrgConfigType::ORG_CONFIGS,
+    ));
+    let resp = make_v2_api(cfg)
+        .update_org_config(setting_name.to_string(), body)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to update org setting {setting_name}: {e:?}"))?;
+    formatter::output(cfg, &resp)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn settings_update(cfg: &Config, setting_name: &str, value: &str) -> Result<()> {
+    let body = serde_json::json!({
+        "data": {
+            "attributes": { "value": parse_setting_value(value) },
+            "type": "org_configs"
+        }
+    });
+    let path = format!("/api/v2/org_configs/{setting_name}");
+    let data = crate::api::patch(cfg, &path, &body)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to update org setting {setting_name}: {e:?}"))?;
+    formatter::output(cfg, &data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_setting_value() {
+        assert_eq!(parse_setting_value("true"), serde_json::json!(true));
+        assert_eq!(parse_setting_value("30"), serde_json::json!(30));
+        assert_eq!(parse_setting_value("UTC"), serde_json::json!("UTC"));
+        assert_eq!(
+            parse_setting_value(r#"{"enabled": false}"#),
+            serde_json::json!({"enabled": false})
+        );
+    }
+}
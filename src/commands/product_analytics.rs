@@ -2,6 +2,8 @@ use anyhow::Result;
 #[cfg(not(target_arch = "wasm32"))]
 use datadog_api_client::datadogV2::api_product_analytics::ProductAnalyticsAPI;
 #[cfg(not(target_arch = "wasm32"))]
+use datadog_api_client::datadogV2::api_rum::{ListRUMEventsOptionalParams, RUMAPI};
+#[cfg(not(target_arch = "wasm32"))]
 use datadog_api_client::datadogV2::model::ProductAnalyticsServerSideEventItem;
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -31,3 +33,106 @@ pub async fn events_send(cfg: &Config, file: &str) -> Result<()> {
     let data = crate::api::post(cfg, "/api/v2/product-analytics/events", &body).await?;
     crate::formatter::output(cfg, &data)
 }
+
+/// Product analytics events are stored as RUM events, so reads go through the
+/// RUM APIs scoped to the application.
+fn app_query(app_id: &str, query: Option<&str>) -> String {
+    match query.map(str::trim).filter(|q| !q.is_empty() && *q != "*") {
+        Some(q) => format!("@application.id:{app_id} {q}"),
+        None => format!("@application.id:{app_id}"),
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn events_list(
+    cfg: &Config,
+    app_id: &str,
+    from: String,
+    to: String,
+    limit: i32,
+) -> Result<()> {
+    let dd_cfg = client::make_dd_config(cfg);
+    let api = match client::make_bearer_client(cfg) {
+        Some(c) => RUMAPI::with_client_and_config(dd_cfg, c),
+        None => RUMAPI::with_config(dd_cfg),
+    };
+    let from_dt = chrono::DateTime::from_timestamp_millis(util::parse_time_to_unix_millis(&from)?)
+        .ok_or_else(|| anyhow::anyhow!("invalid start time: {from}"))?;
+    let to_dt = chrono::DateTime::from_timestamp_millis(util::parse_time_to_unix_millis(&to)?)
+        .ok_or_else(|| anyhow::anyhow!("invalid end time: {to}"))?;
+    let params = ListRUMEventsOptionalParams::default()
+        .filter_query(app_query(app_id, None))
+        .filter_from(from_dt)
+        .filter_to(to_dt)
+        .page_limit(limit);
+    let resp = api
+        .list_rum_events(params)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to list product analytics events: {e:?}"))?;
+    formatter::output(cfg, &resp)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn events_list(
+    cfg: &Config,
+    app_id: &str,
+    from: String,
+    to: String,
+    limit: i32,
+) -> Result<()> {
+    let rfc3339 = |input: &str| -> Result<String> {
+        chrono::DateTime::from_timestamp_millis(util::parse_time_to_unix_millis(input)?)
+            .map(|dt| dt.to_rfc3339())
+            .ok_or_else(|| anyhow::anyhow!("invalid time: {input}"))
+    };
+    let query = vec![
+        ("filter[query]", app_query(app_id, None)),
+        ("filter[from]", rfc3339(&from)?),
+        ("filter[to]", rfc3339(&to)?),
+        ("page[limit]", limit.to_string()),
+    ];
+    let data = crate::api::get(cfg, "/api/v2/rum/events", &query)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to list product analytics events: {e:?}"))?;
+    formatter::output(cfg, &data)
+}
+
+/// Groups returned per `--group-by` facet.
+const AGGREGATE_GROUP_LIMIT: i32 = 10;
+
+pub async fn events_aggregate(
+    cfg: &Config,
+    app_id: &str,
+    query: Option<String>,
+    from: String,
+    to: String,
+    compute: String,
+    group_by: Option<String>,
+) -> Result<()> {
+    let query = app_query(app_id, query.as_deref());
+    crate::commands::rum::events_aggregate(
+        cfg,
+        Some(query),
+        from,
+        to,
+        compute,
+        group_by,
+        AGGREGATE_GROUP_LIMIT,
+    )
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_app_query() {
+        assert_eq!(app_query("app-1", None), "@application.id:app-1");
+        assert_eq!(app_query("app-1", Some("*")), "@application.id:app-1");
+        assert_eq!(
+            app_query("app-1", Some("@type:action")),
+            "@application.id:app-1 @type:action"
+        );
+    }
+}
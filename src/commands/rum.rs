@@ -16,7 +16,8 @@ use datadog_api_client::datadogV2::api_rum_retention_filters::RumRetentionFilter
 #[cfg(not(target_arch = "wasm32"))]
 use datadog_api_client::datadogV2::model::{
     RUMApplicationCreate, RUMApplicationCreateAttributes, RUMApplicationCreateRequest,
-    RUMApplicationCreateType, RUMApplicationUpdateRequest, RUMQueryFilter, RUMSearchEventsRequest,
+    RUMApplicationCreateType, RUMApplicationUpdate, RUMApplicationUpdateAttributes,
+    RUMApplicationUpdateRequest, RUMApplicationUpdateType, RUMQueryFilter, RUMSearchEventsRequest,
     RUMSort, RumMetricCreateRequest, RumMetricUpdateRequest, RumRetentionFilterCreateRequest,
     RumRetentionFilterUpdateRequest,
 };
@@ -128,6 +129,63 @@ pub async fn apps_delete(cfg: &Config, app_id: &str) -> Result<()> {
     Ok(())
 }
 
+/// Server-assigned attributes dropped when cloning a RUM application.
+const RUM_APP_SERVER_FIELDS: &[&str] = &[
+    "application_id",
+    "client_token",
+    "hash",
+    "org_id",
+    "created_at",
+    "created_by_handle",
+    "updated_at",
+    "updated_by_handle",
+];
+
+/// Build a create request from a fetched RUM application, keeping its settings
+/// but dropping the ID, client token, and timestamps and applying the new name.
+fn clone_app_body(source: &serde_json::Value, name: &str) -> Result<serde_json::Value> {
+    let mut attrs = source
+        .pointer("/data/attributes")
+        .and_then(|a| a.as_object())
+        .cloned()
+        .ok_or_else(|| anyhow::anyhow!("RUM application response has no attributes"))?;
+    for field in RUM_APP_SERVER_FIELDS {
+        attrs.remove(*field);
+    }
+    attrs.insert("name".into(), serde_json::json!(name));
+    Ok(serde_json::json!({
+        "data": {
+            "attributes": attrs,
+            "type": "rum_application_create"
+        }
+    }))
+}
+
+pub async fn apps_clone(cfg: &Config, app_id: &str, name: &str) -> Result<()> {
+    if !cfg.has_api_keys() {
+        bail!("RUM apps requires API key authentication (DD_API_KEY + DD_APP_KEY)");
+    }
+    let path = format!("/api/v2/rum/applications/{app_id}");
+    let source = crate::api::get(cfg, &path, &[])
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to get RUM app: {e:?}"))?;
+    let body = clone_app_body(&source, name)?;
+    let resp = crate::api::post(cfg, "/api/v2/rum/applications", &body)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to create RUM app: {e:?}"))?;
+    let new_id = resp
+        .pointer("/data/id")
+        .and_then(|v| v.as_str())
+        .unwrap_or_default();
+    let client_token = resp
+        .pointer("/data/attributes/client_token")
+        .and_then(|v| v.as_str())
+        .unwrap_or_default();
+    println!("Cloned RUM application {app_id} to {new_id}");
+    println!("Client token: {client_token}");
+    Ok(())
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 pub async fn events_list(cfg: &Config, from: String, to: String, limit: i32) -> Result<()> {
     let dd_cfg = client::make_dd_config(cfg);
@@ -172,6 +230,80 @@ pub async fn events_list(cfg: &Config, from: String, to: String, limit: i32) ->
     crate::formatter::output(cfg, &data)
 }
 
+/// Aggregation functions accepted by the RUM analytics API.
+const RUM_AGGREGATIONS: &[&str] = &[
+    "count",
+    "cardinality",
+    "pc75",
+    "pc90",
+    "pc95",
+    "pc98",
+    "pc99",
+    "sum",
+    "min",
+    "max",
+    "avg",
+    "median",
+];
+
+/// Parse a `--compute` value such as `count` or `pc95:@view.loading_time`
+/// into a RUM analytics compute object.
+fn parse_rum_compute(compute: &str) -> Result<serde_json::Value> {
+    let (aggregation, metric) = match compute.split_once(':') {
+        Some((agg, metric)) => (agg.trim().to_lowercase(), Some(metric.trim())),
+        None => (compute.trim().to_lowercase(), None),
+    };
+    if !RUM_AGGREGATIONS.contains(&aggregation.as_str()) {
+        bail!(
+            "invalid compute aggregation: {aggregation} (use {})",
+            RUM_AGGREGATIONS.join(", ")
+        );
+    }
+    let mut obj = serde_json::json!({ "aggregation": aggregation, "type": "total" });
+    match metric {
+        Some(m) if !m.is_empty() => obj["metric"] = serde_json::json!(m),
+        _ if aggregation != "count" => {
+            bail!(
+                "--compute={aggregation} requires a metric (e.g. {aggregation}:@view.loading_time)"
+            )
+        }
+        _ => {}
+    }
+    Ok(obj)
+}
+
+pub async fn events_aggregate(
+    cfg: &Config,
+    query: Option<String>,
+    from: String,
+    to: String,
+    compute: String,
+    group_by: Option<String>,
+    limit: i32,
+) -> Result<()> {
+    let from_ms = crate::util::parse_time_to_unix_millis(&from)?;
+    let to_ms = crate::util::parse_time_to_unix_millis(&to)?;
+    let mut body = serde_json::json!({
+        "filter": {
+            "query": query.unwrap_or_else(|| "*".into()),
+            "from": from_ms.to_string(),
+            "to": to_ms.to_string()
+        },
+        "compute": [parse_rum_compute(&compute)?]
+    });
+    if let Some(group_by) = group_by {
+        let facets: Vec<serde_json::Value> = group_by
+            .split(',')
+            .map(str::trim)
+            .filter(|f| !f.is_empty())
+            .map(|f| serde_json::json!({ "facet": f, "limit": limit }))
+            .collect();
+        body["group_by"] = serde_json::json!(facets);
+    }
+    let data = crate::api::post(cfg, "/api/v2/rum/analytics/aggregate", &body).await?;
+    formatter::output(cfg, &data)
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 pub async fn sessions_search(
     cfg: &Config,
@@ -237,6 +369,106 @@ pub async fn sessions_search(
     crate::formatter::output(cfg, &data)
 }
 
+/// Columns written by `rum sessions export`, as dotted RUM attribute paths.
+const SESSION_EXPORT_COLUMNS: &[&str] = &[
+    "session.id",
+    "session.duration",
+    "session.page_count",
+    "session.error_count",
+    "usr.id",
+];
+
+/// Page size used when exporting sessions (the search API maximum).
+const SESSION_EXPORT_PAGE_SIZE: i32 = 1000;
+
+/// Quote a CSV field when it contains a delimiter, quote, or line break.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Render one RUM session event as a CSV row of `SESSION_EXPORT_COLUMNS`.
+fn session_csv_row(event: &serde_json::Value) -> String {
+    SESSION_EXPORT_COLUMNS
+        .iter()
+        .map(|col| {
+            let ptr = format!("/attributes/attributes/{}", col.replace('.', "/"));
+            let value = match event.pointer(&ptr) {
+                Some(serde_json::Value::String(s)) => s.clone(),
+                Some(serde_json::Value::Null) | None => String::new(),
+                Some(v) => v.to_string(),
+            };
+            csv_field(&value)
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Export every session matching `query` to a CSV file, following the search
+/// cursor page by page and writing rows as they arrive.
+pub async fn sessions_export(
+    cfg: &Config,
+    query: Option<String>,
+    from: String,
+    to: String,
+    output_file: &str,
+) -> Result<()> {
+    use std::io::Write;
+
+    let to_rfc3339 = |t: &str| -> Result<String> {
+        let ms = crate::util::parse_time_to_unix_millis(t)?;
+        Ok(chrono::DateTime::from_timestamp_millis(ms)
+            .ok_or_else(|| anyhow::anyhow!("invalid time: {t}"))?
+            .to_rfc3339())
+    };
+    let query = match query {
+        Some(q) => format!("@type:session ({q})"),
+        None => "@type:session".to_string(),
+    };
+    let mut body = serde_json::json!({
+        "filter": { "query": query, "from": to_rfc3339(&from)?, "to": to_rfc3339(&to)? },
+        "sort": "-timestamp",
+        "page": { "limit": SESSION_EXPORT_PAGE_SIZE }
+    });
+
+    let file = std::fs::File::create(output_file)
+        .map_err(|e| anyhow::anyhow!("failed to create {output_file:?}: {e}"))?;
+    let mut out = std::io::BufWriter::new(file);
+    writeln!(out, "{}", SESSION_EXPORT_COLUMNS.join(","))?;
+
+    let mut count = 0usize;
+    loop {
+        let resp = crate::api::post(cfg, "/api/v2/rum/events/search", &body)
+            .await
+            .map_err(|e| anyhow::anyhow!("failed to search RUM sessions: {e:?}"))?;
+        if let Some(events) = resp.get("data").and_then(|d| d.as_array()) {
+            for event in events {
+                writeln!(out, "{}", session_csv_row(event))?;
+            }
+            count += events.len();
+        }
+        match resp
+            .pointer("/meta/page/after")
+            .and_then(|c| c.as_str())
+            .filter(|c| !c.is_empty())
+        {
+            // A cursor that does not advance would page forever.
+            Some(cursor) if body["page"]["cursor"] == cursor => bail!(
+                "RUM search returned cursor {cursor:?} again after {count} session(s); \
+                 {output_file} is incomplete"
+            ),
+            Some(cursor) => body["page"]["cursor"] = serde_json::json!(cursor),
+            None => break,
+        }
+    }
+    out.flush()?;
+    eprintln!("Exported {count} session(s) to {output_file}");
+    Ok(())
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 pub async fn apps_update(cfg: &Config, app_id: &str, file: &str) -> Result<()> {
     if !cfg.has_api_keys() {
@@ -260,6 +492,71 @@ pub async fn apps_update(cfg: &Config, app_id: &str, file: &str) -> Result<()> {
     crate::formatter::output(cfg, &data)
 }
 
+/// Update a RUM application's name and/or type, sending only the fields
+/// that were given.
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn apps_update_fields(
+    cfg: &Config,
+    app_id: &str,
+    name: Option<String>,
+    app_type: Option<String>,
+) -> Result<()> {
+    if !cfg.has_api_keys() {
+        bail!("RUM apps requires API key authentication (DD_API_KEY + DD_APP_KEY)");
+    }
+    if name.is_none() && app_type.is_none() {
+        bail!("nothing to update: pass --name, --type or --file");
+    }
+    let dd_cfg = client::make_dd_config(cfg);
+    let api = RUMAPI::with_config(dd_cfg);
+    let mut attrs = RUMApplicationUpdateAttributes::new();
+    if let Some(n) = name {
+        attrs = attrs.name(n);
+    }
+    if let Some(t) = app_type {
+        attrs = attrs.type_(t);
+    }
+    let data = RUMApplicationUpdate::new(
+        app_id.to_string(),
+        RUMApplicationUpdateType::RUM_APPLICATION_UPDATE,
+    )
+    .attributes(attrs);
+    let resp = api
+        .update_rum_application(app_id.to_string(), RUMApplicationUpdateRequest::new(data))
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to update RUM app: {e:?}"))?;
+    formatter::output(cfg, &resp)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn apps_update_fields(
+    cfg: &Config,
+    app_id: &str,
+    name: Option<String>,
+    app_type: Option<String>,
+) -> Result<()> {
+    if name.is_none() && app_type.is_none() {
+        bail!("nothing to update: pass --name, --type or --file");
+    }
+    let mut attrs = serde_json::json!({});
+    if let Some(n) = name {
+        attrs["name"] = serde_json::Value::String(n);
+    }
+    if let Some(t) = app_type {
+        attrs["type"] = serde_json::Value::String(t);
+    }
+    let body = serde_json::json!({
+        "data": {
+            "attributes": attrs,
+            "id": app_id,
+            "type": "rum_application_update"
+        }
+    });
+    let path = format!("/api/v2/rum/applications/{app_id}");
+    let data = crate::api::patch(cfg, &path, &body).await?;
+    crate::formatter::output(cfg, &data)
+}
+
 // ---- RUM Metrics ----
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -619,3 +916,78 @@ pub async fn heatmaps_query(cfg: &Config, view_name: &str) -> Result<()> {
     let data = crate::api::get(cfg, "/api/v2/rum/replay/heatmap/snapshots", &query).await?;
     crate::formatter::output(cfg, &data)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_session_csv_row() {
+        let event = serde_json::json!({
+            "attributes": {
+                "attributes": {
+                    "session": { "id": "s1", "duration": 1500, "page_count": 3 },
+                    "usr": { "id": "user, \"one\"" }
+                }
+            }
+        });
+        assert_eq!(session_csv_row(&event), r#"s1,1500,3,,"user, ""one""""#);
+    }
+
+    #[test]
+    fn test_csv_field_plain() {
+        assert_eq!(csv_field("abc"), "abc");
+    }
+
+    #[test]
+    fn test_clone_app_body_strips_server_fields() {
+        let source = serde_json::json!({
+            "data": {
+                "id": "abc",
+                "attributes": {
+                    "application_id": "abc",
+                    "client_token": "pub123",
+                    "name": "web",
+                    "type": "browser",
+                    "created_at": 1700000000000i64,
+                    "updated_at": 1700000000000i64
+                }
+            }
+        });
+        let body = clone_app_body(&source, "web-copy").unwrap();
+        assert_eq!(
+            body["data"]["attributes"],
+            serde_json::json!({ "name": "web-copy", "type": "browser" })
+        );
+        assert_eq!(body["data"]["type"], "rum_application_create");
+    }
+
+    #[test]
+    fn test_clone_app_body_requires_attributes() {
+        assert!(clone_app_body(&serde_json::json!({ "data": {} }), "x").is_err());
+    }
+
+    #[test]
+    fn test_parse_rum_compute_count() {
+        let c = parse_rum_compute("count").unwrap();
+        assert_eq!(c["aggregation"], "count");
+        assert!(c.get("metric").is_none());
+    }
+
+    #[test]
+    fn test_parse_rum_compute_with_metric() {
+        let c = parse_rum_compute("PC95:@view.loading_time").unwrap();
+        assert_eq!(c["aggregation"], "pc95");
+        assert_eq!(c["metric"], "@view.loading_time");
+    }
+
+    #[test]
+    fn test_parse_rum_compute_requires_metric() {
+        assert!(parse_rum_compute("avg").is_err());
+    }
+
+    #[test]
+    fn test_parse_rum_compute_invalid() {
+        assert!(parse_rum_compute("p95:@view.loading_time").is_err());
+    }
+}
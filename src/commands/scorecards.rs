@@ -1,9 +1,82 @@
 use anyhow::{bail, Result};
 
-pub fn list() -> Result<()> {
-    bail!("scorecards commands are not yet implemented (API endpoints pending)")
+use crate::config::Config;
+use crate::formatter;
+
+/// Page size used when paging through scorecards.
+const SCORECARDS_PAGE_SIZE: usize = 100;
+
+fn include_query(include_rules: bool) -> Vec<(&'static str, String)> {
+    if include_rules {
+        vec![("include", "rules".to_string())]
+    } else {
+        Vec::new()
+    }
+}
+
+/// List up to `limit` scorecards, following `page[offset]` until a short page
+/// is returned.
+pub async fn list(cfg: &Config, limit: usize, include_rules: bool) -> Result<()> {
+    let mut scorecards: Vec<serde_json::Value> = Vec::new();
+    let mut included: Vec<serde_json::Value> = Vec::new();
+    while scorecards.len() < limit {
+        let page_size = (limit - scorecards.len()).min(SCORECARDS_PAGE_SIZE);
+        let mut query = include_query(include_rules);
+        query.push(("page[size]", page_size.to_string()));
+        query.push(("page[offset]", scorecards.len().to_string()));
+        let resp = crate::api::get(cfg, "/api/v2/scorecard/scorecards", &query)
+            .await
+            .map_err(|e| anyhow::anyhow!("failed to list scorecards: {e:?}"))?;
+        let page = resp
+            .get("data")
+            .and_then(|d| d.as_array())
+            .cloned()
+            .unwrap_or_default();
+        if let Some(inc) = resp.get("included").and_then(|i| i.as_array()) {
+            included.extend(inc.iter().cloned());
+        }
+        let fetched = page.len();
+        scorecards.extend(page);
+        if fetched < page_size {
+            break;
+        }
+    }
+    let mut data = serde_json::json!({ "data": scorecards });
+    if include_rules {
+        data["included"] = serde_json::Value::Array(included);
+    }
+    formatter::output(cfg, &data)
+}
+
+pub async fn get(cfg: &Config, scorecard_id: &str, include_rules: bool) -> Result<()> {
+    if scorecard_id.trim().is_empty() {
+        bail!("scorecard ID must not be empty");
+    }
+    let path = format!("/api/v2/scorecard/scorecards/{scorecard_id}");
+    let data = crate::api::get(cfg, &path, &include_query(include_rules))
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to get scorecard: {e:?}"))?;
+    formatter::output(cfg, &data)
+}
+
+pub async fn rules_list(cfg: &Config, filter_query: Option<String>) -> Result<()> {
+    let mut query = Vec::new();
+    if let Some(q) = filter_query {
+        query.push(("filter[rule][name]", q));
+    }
+    let data = crate::api::get(cfg, "/api/v2/scorecard/rules", &query)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to list scorecard rules: {e:?}"))?;
+    formatter::output(cfg, &data)
 }
 
-pub fn get(_scorecard_id: &str) -> Result<()> {
-    bail!("scorecards get is not yet implemented (API endpoints pending)")
+pub async fn outcomes_list(cfg: &Config, service_name: Option<String>) -> Result<()> {
+    let mut query = Vec::new();
+    if let Some(s) = service_name {
+        query.push(("filter[outcome][service_name]", s));
+    }
+    let data = crate::api::get(cfg, "/api/v2/scorecard/outcomes", &query)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to list scorecard outcomes: {e:?}"))?;
+    formatter::output(cfg, &data)
 }
@@ -157,6 +157,94 @@ pub async fn findings_search(cfg: &Config, query: Option<String>, limit: i64) ->
     crate::formatter::output(cfg, &data)
 }
 
+// ---- Signal Correlation ----
+
+/// One-line description of a signal: its title (falling back to the first
+/// line of the message) and severity.
+fn signal_summary(id: &str, signal: &serde_json::Value) -> String {
+    let attrs = &signal["data"]["attributes"];
+    let title = attrs["attributes"]["title"]
+        .as_str()
+        .or_else(|| attrs["custom"]["title"].as_str())
+        .or_else(|| {
+            attrs["message"]
+                .as_str()
+                .and_then(|m| m.lines().next())
+                .map(|l| l.trim_start_matches('#').trim())
+        })
+        .filter(|t| !t.is_empty())
+        .unwrap_or("untitled signal");
+    match attrs["attributes"]["severity"]
+        .as_str()
+        .or_else(|| attrs["custom"]["severity"].as_str())
+    {
+        Some(severity) => format!("- {id}: {title} ({severity})"),
+        None => format!("- {id}: {title}"),
+    }
+}
+
+fn correlation_description(summaries: &[String]) -> String {
+    format!("Correlated security signals:\n{}", summaries.join("\n"))
+}
+
+/// Group signals into a security case. There is no signal correlation
+/// endpoint, so the signals are fetched and listed in the description of a
+/// new case. Opening a case needs a project and case type, which come from
+/// `file` (a case create request). Without it, the case draft is printed.
+pub async fn signals_correlate(
+    cfg: &Config,
+    signal_ids: Vec<String>,
+    title: Option<String>,
+    file: Option<String>,
+) -> Result<()> {
+    if signal_ids.len() < 2 {
+        anyhow::bail!("at least two signal IDs are required to correlate");
+    }
+    let mut summaries = Vec::with_capacity(signal_ids.len());
+    for id in &signal_ids {
+        let path = format!("/api/v2/security_monitoring/signals/{id}");
+        let signal = crate::api::get(cfg, &path, &[])
+            .await
+            .map_err(|e| anyhow::anyhow!("failed to get signal {id}: {e:?}"))?;
+        summaries.push(signal_summary(id, &signal));
+    }
+    let description = correlation_description(&summaries);
+
+    let Some(file) = file else {
+        let draft = serde_json::json!({
+            "title": title.unwrap_or_else(|| "Correlated security signals".into()),
+            "description": description,
+            "signal_ids": signal_ids,
+        });
+        eprintln!("Pass --file with a case request (project and type_id) to open the case.");
+        return formatter::output(cfg, &draft);
+    };
+    let mut body: serde_json::Value = util::read_json_file(&file)?;
+    let Some(attrs) = body
+        .pointer_mut("/data/attributes")
+        .and_then(serde_json::Value::as_object_mut)
+    else {
+        anyhow::bail!("{file}: expected a case create request with a data.attributes object");
+    };
+    if let Some(t) = title {
+        attrs.insert("title".into(), serde_json::json!(t));
+    } else if attrs.get("title").is_none_or(serde_json::Value::is_null) {
+        attrs.insert(
+            "title".into(),
+            serde_json::json!("Correlated security signals"),
+        );
+    }
+    let description = match attrs.get("description").and_then(|d| d.as_str()) {
+        Some(existing) if !existing.is_empty() => format!("{existing}\n\n{description}"),
+        _ => description,
+    };
+    attrs.insert("description".into(), serde_json::json!(description));
+    let data = crate::api::post(cfg, "/api/v2/cases", &body)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to create case: {e:?}"))?;
+    formatter::output(cfg, &data)
+}
+
 // ---- Bulk Export ----
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -216,10 +304,48 @@ pub async fn content_packs_list(cfg: &Config) -> Result<()> {
 
 #[cfg(target_arch = "wasm32")]
 pub async fn content_packs_list(cfg: &Config) -> Result<()> {
-    let data = crate::api::get(cfg, "/api/v2/security_monitoring/content_packs", &[]).await?;
+    let data =
+        crate::api::get(cfg, "/api/v2/security_monitoring/content_packs/states", &[]).await?;
     crate::formatter::output(cfg, &data)
 }
 
+/// There is no per-pack endpoint, so the pack is picked out of the states list.
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn content_packs_get(cfg: &Config, pack_id: &str) -> Result<()> {
+    let dd_cfg = client::make_dd_config(cfg);
+    let api = match client::make_bearer_client(cfg) {
+        Some(c) => SecurityMonitoringAPI::with_client_and_config(dd_cfg, c),
+        None => SecurityMonitoringAPI::with_config(dd_cfg),
+    };
+    let resp = api
+        .get_content_packs_states()
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to get content pack: {e:?}"))?;
+    let pack = resp
+        .data
+        .into_iter()
+        .find(|p| p.id == pack_id)
+        .ok_or_else(|| anyhow::anyhow!("content pack {pack_id:?} not found"))?;
+    formatter::output(cfg, &pack)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn content_packs_get(cfg: &Config, pack_id: &str) -> Result<()> {
+    let data = crate::api::get(cfg, "/api/v2/security_monitoring/content_packs/states", &[])
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to get content pack: {e:?}"))?;
+    let pack = data
+        .get("data")
+        .and_then(|d| d.as_array())
+        .and_then(|packs| {
+            packs
+                .iter()
+                .find(|p| p.get("id").and_then(|v| v.as_str()) == Some(pack_id))
+        })
+        .ok_or_else(|| anyhow::anyhow!("content pack {pack_id:?} not found"))?;
+    crate::formatter::output(cfg, pack)
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 pub async fn content_packs_activate(cfg: &Config, pack_id: &str) -> Result<()> {
     let dd_cfg = client::make_dd_config(cfg);
@@ -237,7 +363,7 @@ pub async fn content_packs_activate(cfg: &Config, pack_id: &str) -> Result<()> {
 #[cfg(target_arch = "wasm32")]
 pub async fn content_packs_activate(cfg: &Config, pack_id: &str) -> Result<()> {
     let body = serde_json::json!({});
-    crate::api::post(
+    crate::api::put(
         cfg,
         &format!("/api/v2/security_monitoring/content_packs/{pack_id}/activate"),
         &body,
@@ -264,7 +390,7 @@ pub async fn content_packs_deactivate(cfg: &Config, pack_id: &str) -> Result<()>
 #[cfg(target_arch = "wasm32")]
 pub async fn content_packs_deactivate(cfg: &Config, pack_id: &str) -> Result<()> {
     let body = serde_json::json!({});
-    crate::api::post(
+    crate::api::put(
         cfg,
         &format!("/api/v2/security_monitoring/content_packs/{pack_id}/deactivate"),
         &body,
@@ -303,3 +429,39 @@ pub async fn risk_scores_list(cfg: &Config, query: Option<String>) -> Result<()>
     let data = crate::api::get(cfg, "/api/v2/entity_risk_scores", &q).await?;
     crate::formatter::output(cfg, &data)
 }
+
+pub async fn risk_scores_get(cfg: &Config, entity_id: &str) -> Result<()> {
+    let path = format!("/api/v2/entity_risk_scores/{entity_id}");
+    let data = crate::api::get(cfg, &path, &[])
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to get entity risk score: {e:?}"))?;
+    formatter::output(cfg, &data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_signal_summary() {
+        let signal = serde_json::json!({"data": {"attributes": {
+            "attributes": {"title": "Brute force on admin", "severity": "high"},
+            "message": "## ignored"
+        }}});
+        assert_eq!(
+            signal_summary("s1", &signal),
+            "- s1: Brute force on admin (high)"
+        );
+        let from_message = serde_json::json!({"data": {"attributes": {
+            "message": "## Impossible travel\nmore details"
+        }}});
+        assert_eq!(
+            signal_summary("s2", &from_message),
+            "- s2: Impossible travel"
+        );
+        assert_eq!(
+            signal_summary("s3", &serde_json::json!({})),
+            "- s3: untitled signal"
+        );
+    }
+}
@@ -56,3 +56,187 @@ pub async fn get(cfg: &Config, service_name: &str) -> Result<()> {
     .await?;
     crate::formatter::output(cfg, &data)
 }
+
+/// Name of the service described by a definition: `dd-service` for schema
+/// v2.x, `metadata.name` for v3.
+fn definition_service_name(definition: &serde_json::Value) -> Option<&str> {
+    definition
+        .get("dd-service")
+        .or_else(|| definition.pointer("/metadata/name"))
+        .and_then(|v| v.as_str())
+        .filter(|s| !s.is_empty())
+}
+
+/// Create or update a service definition. The definitions endpoint upserts on
+/// its own, so the existing definition is looked up first only to report
+/// which of the two happened.
+pub async fn upsert(cfg: &Config, file: &str) -> Result<()> {
+    let body: serde_json::Value = crate::util::read_json_file(file)?;
+    let Some(name) = definition_service_name(&body) else {
+        anyhow::bail!("service definition must set \"dd-service\" (v2) or \"metadata.name\" (v3)");
+    };
+    let path = format!("/api/v2/services/definitions/{name}");
+    let existing = crate::api::get_allowing(cfg, &path, &[], &[404])
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to look up service {name}: {e:?}"))?;
+    let exists = existing.get("data").is_some_and(|d| !d.is_null());
+    crate::api::post(cfg, "/api/v2/services/definitions", &body)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to save service definition: {e:?}"))?;
+    let action = if exists { "updated" } else { "created" };
+    println!("Service definition {name} {action}.");
+    Ok(())
+}
+
+/// Entity kinds accepted by the v2 software catalog schema.
+const ENTITY_KINDS: &[&str] = &["service", "queue", "datastore", "system", "custom"];
+
+pub async fn search(cfg: &Config, query: String, kind: Option<String>, limit: i32) -> Result<()> {
+    let mut params = vec![("query", query)];
+    if let Some(kind) = kind {
+        let kind = kind.to_lowercase();
+        if !ENTITY_KINDS.contains(&kind.as_str()) {
+            anyhow::bail!("invalid kind: {kind} (use {})", ENTITY_KINDS.join(", "));
+        }
+        params.push(("kind", kind));
+    }
+    params.push(("page[limit]", limit.to_string()));
+    let data = crate::api::get(cfg, "/api/v2/catalog/entity", &params).await?;
+    formatter::output(cfg, &data)
+}
+
+/// Relation type the catalog uses for service-to-service dependencies.
+const DEPENDS_ON_RELATION: &str = "RelationTypeDependsOn";
+
+/// One dependency edge relative to the requested service: `downstream` rows are
+/// services it depends on, `upstream` rows are services that depend on it.
+#[derive(serde::Serialize)]
+struct DependencyRow {
+    direction: &'static str,
+    kind: String,
+    name: String,
+    namespace: String,
+}
+
+fn dependency_rows(relations: &serde_json::Value, direction: &'static str) -> Vec<DependencyRow> {
+    // Downstream edges point from the service, so the dependency is the `to` side.
+    let side = if direction == "downstream" {
+        "to"
+    } else {
+        "from"
+    };
+    let str_at = formatter::str_at;
+    relations
+        .get("data")
+        .and_then(|d| d.as_array())
+        .map(|rels| {
+            rels.iter()
+                .map(|r| DependencyRow {
+                    direction,
+                    kind: str_at(r, &format!("/attributes/{side}/kind")),
+                    name: str_at(r, &format!("/attributes/{side}/name")),
+                    namespace: str_at(r, &format!("/attributes/{side}/namespace")),
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// List a service's dependencies from the catalog relation API. `direction`
+/// is `upstream` (services depending on it), `downstream` (services it depends
+/// on), or `both` (default).
+pub async fn dependencies(
+    cfg: &Config,
+    service_name: &str,
+    direction: Option<String>,
+) -> Result<()> {
+    let direction = direction
+        .unwrap_or_else(|| "both".to_string())
+        .to_lowercase();
+    let directions: &[&'static str] = match direction.as_str() {
+        "upstream" => &["upstream"],
+        "downstream" => &["downstream"],
+        "both" => &["upstream", "downstream"],
+        other => anyhow::bail!("invalid direction: {other} (use upstream, downstream, or both)"),
+    };
+    let entity_ref = format!("service:{service_name}");
+    let mut rows = Vec::new();
+    for &dir in directions {
+        let ref_filter = if dir == "downstream" {
+            "filter[from_ref]"
+        } else {
+            "filter[to_ref]"
+        };
+        let params = vec![
+            (ref_filter, entity_ref.clone()),
+            ("filter[type]", DEPENDS_ON_RELATION.to_string()),
+        ];
+        let data = crate::api::get(cfg, "/api/v2/catalog/relation", &params)
+            .await
+            .map_err(|e| anyhow::anyhow!("failed to list {dir} dependencies: {e:?}"))?;
+        rows.extend(dependency_rows(&data, dir));
+    }
+    formatter::output_rows(
+        cfg,
+        &rows,
+        &["direction", "kind", "name", "namespace"],
+        |_| {
+            rows.iter()
+                .map(|r| {
+                    vec![
+                        r.direction.to_string(),
+                        r.kind.clone(),
+                        r.name.clone(),
+                        r.namespace.clone(),
+                    ]
+                })
+                .collect()
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_definition_service_name() {
+        let v2 = serde_json::json!({"schema-version": "v2.2", "dd-service": "web-store"});
+        assert_eq!(definition_service_name(&v2), Some("web-store"));
+        let v3 = serde_json::json!({"apiVersion": "v3", "metadata": {"name": "checkout"}});
+        assert_eq!(definition_service_name(&v3), Some("checkout"));
+        assert_eq!(
+            definition_service_name(&serde_json::json!({"dd-service": ""})),
+            None
+        );
+    }
+
+    fn relations() -> serde_json::Value {
+        serde_json::json!({
+            "data": [{
+                "id": "rel-1",
+                "type": "relation",
+                "attributes": {
+                    "from": {"kind": "service", "name": "checkout", "namespace": "default"},
+                    "to": {"kind": "datastore", "name": "orders-db", "namespace": "default"},
+                    "type": "RelationTypeDependsOn"
+                }
+            }]
+        })
+    }
+
+    #[test]
+    fn test_dependency_rows_downstream() {
+        let rows = dependency_rows(&relations(), "downstream");
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].kind, "datastore");
+        assert_eq!(rows[0].name, "orders-db");
+    }
+
+    #[test]
+    fn test_dependency_rows_upstream() {
+        let rows = dependency_rows(&relations(), "upstream");
+        assert_eq!(rows[0].direction, "upstream");
+        assert_eq!(rows[0].name, "checkout");
+    }
+}
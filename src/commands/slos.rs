@@ -1,8 +1,8 @@
 use anyhow::Result;
 #[cfg(not(target_arch = "wasm32"))]
 use datadog_api_client::datadogV1::api_service_level_objectives::{
-    DeleteSLOOptionalParams, GetSLOOptionalParams, ListSLOsOptionalParams,
-    ServiceLevelObjectivesAPI,
+    DeleteSLOOptionalParams, GetSLOHistoryOptionalParams, GetSLOOptionalParams,
+    ListSLOsOptionalParams, ServiceLevelObjectivesAPI,
 };
 #[cfg(not(target_arch = "wasm32"))]
 use datadog_api_client::datadogV1::model::{ServiceLevelObjective, ServiceLevelObjectiveRequest};
@@ -97,6 +97,171 @@ pub async fn update(cfg: &Config, id: &str, file: &str) -> Result<()> {
     crate::formatter::output(cfg, &data)
 }
 
+/// Maximum number of SLOs created at once by `bulk_create`.
+#[cfg(not(target_arch = "wasm32"))]
+const BULK_CREATE_CONCURRENCY: usize = 8;
+
+const SLO_TYPES: &[&str] = &["metric", "monitor", "time_slice"];
+
+/// Check every definition for the fields the API requires (`name`, `type`,
+/// `thresholds`) and report all problems at once, by input index.
+fn validate_slo_definitions(defs: &[serde_json::Value]) -> Result<()> {
+    let mut problems = Vec::new();
+    for (i, def) in defs.iter().enumerate() {
+        if !def.is_object() {
+            problems.push(format!("SLO #{i}: not a JSON object"));
+            continue;
+        }
+        if def["name"].as_str().is_none_or(str::is_empty) {
+            problems.push(format!("SLO #{i}: missing name"));
+        }
+        match def["type"].as_str() {
+            Some(t) if SLO_TYPES.contains(&t) => {}
+            Some(t) => problems.push(format!(
+                "SLO #{i}: invalid type {t:?} (use {})",
+                SLO_TYPES.join(", ")
+            )),
+            None => problems.push(format!("SLO #{i}: missing type")),
+        }
+        if def["thresholds"].as_array().is_none_or(Vec::is_empty) {
+            problems.push(format!("SLO #{i}: missing thresholds"));
+        }
+    }
+    if !problems.is_empty() {
+        anyhow::bail!("invalid SLO definitions:\n  {}", problems.join("\n  "));
+    }
+    Ok(())
+}
+
+fn read_slo_definitions(file: &str) -> Result<Vec<serde_json::Value>> {
+    let defs: Vec<serde_json::Value> = util::read_json_file(file)?;
+    if defs.is_empty() {
+        anyhow::bail!("{file} contains no SLO definitions");
+    }
+    validate_slo_definitions(&defs)?;
+    Ok(defs)
+}
+
+/// Summary of a bulk create: input index to new SLO ID, plus failures, both
+/// ordered by input index.
+fn bulk_create_summary(
+    mut created: Vec<(usize, String)>,
+    mut failed: Vec<(usize, String)>,
+) -> serde_json::Value {
+    created.sort();
+    failed.sort();
+    let created: Vec<_> = created
+        .into_iter()
+        .map(|(index, id)| serde_json::json!({ "index": index, "id": id }))
+        .collect();
+    let failed: Vec<_> = failed
+        .into_iter()
+        .map(|(index, error)| serde_json::json!({ "index": index, "error": error }))
+        .collect();
+    serde_json::json!({ "created": created, "failed": failed })
+}
+
+fn finish_bulk_create(
+    cfg: &Config,
+    total: usize,
+    created: Vec<(usize, String)>,
+    failed: Vec<(usize, String)>,
+) -> Result<()> {
+    let failures = failed.len();
+    formatter::output(cfg, &bulk_create_summary(created, failed))?;
+    if failures > 0 {
+        anyhow::bail!("{failures} of {total} SLO(s) could not be created");
+    }
+    Ok(())
+}
+
+/// Create every SLO in a JSON array file. All definitions are validated
+/// before anything is created; with `dry_run` nothing is sent.
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn bulk_create(cfg: &Config, file: &str, dry_run: bool) -> Result<()> {
+    let defs = read_slo_definitions(file)?;
+    let bodies = defs
+        .into_iter()
+        .enumerate()
+        .map(|(i, def)| {
+            serde_json::from_value::<ServiceLevelObjectiveRequest>(def)
+                .map_err(|e| anyhow::anyhow!("SLO #{i}: {e}"))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    if dry_run {
+        eprintln!(
+            "{} SLO definition(s) are valid; nothing created.",
+            bodies.len()
+        );
+        return Ok(());
+    }
+
+    let dd_cfg = client::make_dd_config(cfg);
+    let api = match client::make_bearer_client(cfg) {
+        Some(c) => ServiceLevelObjectivesAPI::with_client_and_config(dd_cfg, c),
+        None => ServiceLevelObjectivesAPI::with_config(dd_cfg),
+    };
+    let total = bodies.len();
+    let semaphore = std::sync::Arc::new(tokio::sync::Semaphore::new(BULK_CREATE_CONCURRENCY));
+    let mut tasks = tokio::task::JoinSet::new();
+    for (index, body) in bodies.into_iter().enumerate() {
+        let (api, semaphore) = (api.clone(), semaphore.clone());
+        tasks.spawn(async move {
+            let _permit = semaphore
+                .acquire_owned()
+                .await
+                .map_err(|e| (index, e.to_string()))?;
+            let resp = api
+                .create_slo(body)
+                .await
+                .map_err(|e| (index, format!("{e:?}")))?;
+            let id = resp
+                .data
+                .and_then(|d| d.into_iter().next())
+                .and_then(|slo| slo.id)
+                .unwrap_or_default();
+            Ok::<_, (usize, String)>((index, id))
+        });
+    }
+
+    let (mut created, mut failed) = (Vec::new(), Vec::new());
+    while let Some(joined) = tasks.join_next().await {
+        match joined.map_err(|e| anyhow::anyhow!("create task failed: {e}"))? {
+            Ok(done) => created.push(done),
+            Err(failure) => failed.push(failure),
+        }
+        eprint!("\rCreating SLOs: {}/{total}", created.len() + failed.len());
+    }
+    eprintln!();
+    finish_bulk_create(cfg, total, created, failed)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn bulk_create(cfg: &Config, file: &str, dry_run: bool) -> Result<()> {
+    let defs = read_slo_definitions(file)?;
+    if dry_run {
+        eprintln!(
+            "{} SLO definition(s) are valid; nothing created.",
+            defs.len()
+        );
+        return Ok(());
+    }
+    let total = defs.len();
+    let (mut created, mut failed) = (Vec::new(), Vec::new());
+    for (index, def) in defs.iter().enumerate() {
+        match crate::api::post(cfg, "/api/v1/slo", def).await {
+            Ok(resp) => {
+                let id = resp["data"][0]["id"].as_str().unwrap_or_default();
+                created.push((index, id.to_string()));
+            }
+            Err(e) => failed.push((index, e.to_string())),
+        }
+        eprint!("\rCreating SLOs: {}/{total}", created.len() + failed.len());
+    }
+    eprintln!();
+    finish_bulk_create(cfg, total, created, failed)
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 pub async fn delete(cfg: &Config, id: &str) -> Result<()> {
     let dd_cfg = client::make_dd_config(cfg);
@@ -149,3 +314,194 @@ pub async fn status(cfg: &Config, id: &str, from_ts: i64, to_ts: i64) -> Result<
     let data = crate::api::get(cfg, &format!("/api/v2/slo/{id}/status"), &query).await?;
     crate::formatter::output(cfg, &data)
 }
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn history(
+    cfg: &Config,
+    id: &str,
+    from_ts: i64,
+    to_ts: i64,
+    target: Option<f64>,
+) -> Result<()> {
+    let dd_cfg = client::make_dd_config(cfg);
+    let api = match client::make_bearer_client(cfg) {
+        Some(c) => ServiceLevelObjectivesAPI::with_client_and_config(dd_cfg, c),
+        None => ServiceLevelObjectivesAPI::with_config(dd_cfg),
+    };
+    let mut params = GetSLOHistoryOptionalParams::default();
+    if let Some(t) = target {
+        params = params.target(t);
+    }
+    let resp = api
+        .get_slo_history(id.to_string(), from_ts, to_ts, params)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to get SLO history: {e:?}"))?;
+    print_history(cfg, &resp, target)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn history(
+    cfg: &Config,
+    id: &str,
+    from_ts: i64,
+    to_ts: i64,
+    target: Option<f64>,
+) -> Result<()> {
+    let mut query = vec![
+        ("from_ts", from_ts.to_string()),
+        ("to_ts", to_ts.to_string()),
+    ];
+    if let Some(t) = target {
+        query.push(("target", t.to_string()));
+    }
+    let data = crate::api::get(cfg, &format!("/api/v1/slo/{id}/history"), &query).await?;
+    print_history(cfg, &data, target)
+}
+
+fn format_percent(v: Option<f64>) -> String {
+    v.map(|v| format!("{v}%")).unwrap_or_default()
+}
+
+fn format_ts(ts: Option<i64>) -> String {
+    ts.and_then(|t| chrono::DateTime::from_timestamp(t, 0))
+        .map(|dt| dt.format("%Y-%m-%d %H:%M").to_string())
+        .unwrap_or_default()
+}
+
+/// One row per SLI measurement: overall, each group, then each monitor.
+fn history_rows(resp: &serde_json::Value, target: Option<f64>) -> Vec<Vec<String>> {
+    let Some(data) = resp.get("data") else {
+        return Vec::new();
+    };
+    let window = format!(
+        "{} - {}",
+        format_ts(data.get("from_ts").and_then(|v| v.as_i64())),
+        format_ts(data.get("to_ts").and_then(|v| v.as_i64()))
+    );
+    // Without an explicit --target, fall back to the SLO's first configured threshold.
+    let target = target.or_else(|| {
+        data.get("thresholds")
+            .and_then(|t| t.as_object())
+            .and_then(|t| t.values().next())
+            .and_then(|t| t.get("target"))
+            .and_then(|t| t.as_f64())
+    });
+    let row = |scope: &str, entry: &serde_json::Value| {
+        vec![
+            scope.to_string(),
+            formatter::str_at(entry, "/name"),
+            window.clone(),
+            format_percent(target),
+            format_percent(entry.get("sli_value").and_then(|v| v.as_f64())),
+        ]
+    };
+    let mut rows = Vec::new();
+    if let Some(overall) = data.get("overall").filter(|o| o.is_object()) {
+        rows.push(row("overall", overall));
+    }
+    for (scope, key) in [("group", "groups"), ("monitor", "monitors")] {
+        for entry in data
+            .get(key)
+            .and_then(|g| g.as_array())
+            .into_iter()
+            .flatten()
+        {
+            rows.push(row(scope, entry));
+        }
+    }
+    rows
+}
+
+fn print_history<T: serde::Serialize>(cfg: &Config, resp: &T, target: Option<f64>) -> Result<()> {
+    formatter::output_rows(
+        cfg,
+        resp,
+        &["scope", "name", "window", "target", "sli"],
+        |v| history_rows(v, target),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_slo_definitions() {
+        let valid = serde_json::json!({
+            "name": "API availability",
+            "type": "metric",
+            "thresholds": [{"timeframe": "30d", "target": 99.9}],
+        });
+        assert!(validate_slo_definitions(std::slice::from_ref(&valid)).is_ok());
+
+        let defs = vec![
+            valid,
+            serde_json::json!({"type": "burn_rate", "thresholds": []}),
+            serde_json::json!("nope"),
+        ];
+        let err = validate_slo_definitions(&defs).unwrap_err().to_string();
+        assert!(err.contains("SLO #1: missing name"), "{err}");
+        assert!(err.contains("SLO #1: invalid type"), "{err}");
+        assert!(err.contains("SLO #1: missing thresholds"), "{err}");
+        assert!(err.contains("SLO #2: not a JSON object"), "{err}");
+        assert!(!err.contains("SLO #0"), "{err}");
+    }
+
+    #[test]
+    fn test_bulk_create_summary_orders_by_index() {
+        let summary = bulk_create_summary(
+            vec![(2, "c".into()), (0, "a".into())],
+            vec![(1, "boom".into())],
+        );
+        assert_eq!(
+            summary,
+            serde_json::json!({
+                "created": [{"index": 0, "id": "a"}, {"index": 2, "id": "c"}],
+                "failed": [{"index": 1, "error": "boom"}],
+            })
+        );
+    }
+
+    fn history_resp() -> serde_json::Value {
+        serde_json::json!({
+            "data": {
+                "from_ts": 1700000000,
+                "to_ts": 1700604800,
+                "thresholds": {"7d": {"target": 99.9, "timeframe": "7d"}},
+                "overall": {"name": "Checkout", "sli_value": 99.95},
+                "groups": [{"name": "env:prod", "sli_value": 99.5}],
+                "monitors": [{"name": "Checkout latency", "sli_value": 100.0}]
+            }
+        })
+    }
+
+    #[test]
+    fn test_history_rows() {
+        let rows = history_rows(&history_resp(), None);
+        assert_eq!(rows.len(), 3);
+        assert_eq!(
+            rows[0],
+            [
+                "overall",
+                "Checkout",
+                "2023-11-14 22:13 - 2023-11-21 22:13",
+                "99.9%",
+                "99.95%"
+            ]
+        );
+        assert_eq!(rows[1][..2], ["group", "env:prod"]);
+        assert_eq!(rows[2][0], "monitor");
+        assert_eq!(rows[2][4], "100%");
+    }
+
+    #[test]
+    fn test_history_rows_explicit_target() {
+        let rows = history_rows(&history_resp(), Some(99.0));
+        assert!(rows.iter().all(|r| r[3] == "99%"));
+    }
+
+    #[test]
+    fn test_history_rows_empty() {
+        assert!(history_rows(&serde_json::json!({}), None).is_empty());
+    }
+}
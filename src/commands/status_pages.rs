@@ -38,13 +38,33 @@ pub async fn pages_list(cfg: &Config) -> Result<()> {
         .list_status_pages(ListStatusPagesOptionalParams::default())
         .await
         .map_err(|e| anyhow::anyhow!("failed to list status pages: {e:?}"))?;
-    formatter::output(cfg, &resp)
+    formatter::output_rows(cfg, &resp, PAGE_COLUMNS, status_page_rows)
 }
 
 #[cfg(target_arch = "wasm32")]
 pub async fn pages_list(cfg: &Config) -> Result<()> {
     let data = crate::api::get(cfg, "/api/v2/status_pages", &[]).await?;
-    crate::formatter::output(cfg, &data)
+    formatter::output_rows(cfg, &data, PAGE_COLUMNS, status_page_rows)
+}
+
+const PAGE_COLUMNS: &[&str] = &["id", "name", "public_url", "created_at"];
+
+fn status_page_rows(resp: &serde_json::Value) -> Vec<Vec<String>> {
+    resp.get("data")
+        .and_then(|d| d.as_array())
+        .into_iter()
+        .flatten()
+        .map(|p| {
+            [
+                "/id",
+                "/attributes/name",
+                "/attributes/public_url",
+                "/attributes/created_at",
+            ]
+            .map(|ptr| formatter::str_at(p, ptr))
+            .to_vec()
+        })
+        .collect()
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -649,6 +669,36 @@ pub async fn third_party_list(cfg: &Config, search: Option<&str>, active: bool)
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_status_page_rows() {
+        let resp = serde_json::json!({
+            "data": [{
+                "id": "0a1b2c3d-0000-0000-0000-000000000000",
+                "type": "status_pages",
+                "attributes": {
+                    "name": "Acme Status",
+                    "public_url": "https://status.acme.test",
+                    "created_at": "2026-01-02T03:04:05Z",
+                    "components": [{ "name": "API" }]
+                }
+            }, {
+                "id": "missing-attrs",
+                "type": "status_pages"
+            }]
+        });
+        let rows = status_page_rows(&resp);
+        assert_eq!(rows.len(), 2);
+        assert_eq!(
+            rows[0][1..],
+            [
+                "Acme Status",
+                "https://status.acme.test",
+                "2026-01-02T03:04:05Z"
+            ]
+        );
+        assert_eq!(rows[1], ["missing-attrs", "", "", ""]);
+    }
+
     fn make_provider(
         name: &str,
         display: &str,
@@ -4,6 +4,10 @@ use datadog_api_client::datadogV1::api_synthetics::{
     ListTestsOptionalParams, SearchTestsOptionalParams, SyntheticsAPI,
 };
 #[cfg(not(target_arch = "wasm32"))]
+use datadog_api_client::datadogV1::model::{
+    SyntheticsTestPauseStatus, SyntheticsUpdateTestPauseStatusPayload,
+};
+#[cfg(not(target_arch = "wasm32"))]
 use datadog_api_client::datadogV2::api_synthetics::{
     SearchSuitesOptionalParams, SyntheticsAPI as SyntheticsV2API,
 };
@@ -59,6 +63,48 @@ pub async fn tests_get(cfg: &Config, public_id: &str) -> Result<()> {
     crate::formatter::output(cfg, &data)
 }
 
+#[cfg(not(target_arch = "wasm32"))]
+async fn set_test_status(cfg: &Config, public_id: &str, paused: bool) -> Result<()> {
+    let dd_cfg = client::make_dd_config(cfg);
+    let api = match client::make_bearer_client(cfg) {
+        Some(c) => SyntheticsAPI::with_client_and_config(dd_cfg, c),
+        None => SyntheticsAPI::with_config(dd_cfg),
+    };
+    let status = if paused {
+        SyntheticsTestPauseStatus::PAUSED
+    } else {
+        SyntheticsTestPauseStatus::LIVE
+    };
+    api.update_test_pause_status(
+        public_id.to_string(),
+        SyntheticsUpdateTestPauseStatusPayload::new().new_status(status),
+    )
+    .await
+    .map_err(|e| anyhow::anyhow!("failed to update test status: {e:?}"))?;
+    Ok(())
+}
+
+#[cfg(target_arch = "wasm32")]
+async fn set_test_status(cfg: &Config, public_id: &str, paused: bool) -> Result<()> {
+    let path = format!("/api/v1/synthetics/tests/{public_id}/status");
+    let status = if paused { "paused" } else { "live" };
+    let body = serde_json::json!({ "new_status": status });
+    crate::api::put(cfg, &path, &body).await?;
+    Ok(())
+}
+
+pub async fn tests_pause(cfg: &Config, public_id: &str) -> Result<()> {
+    set_test_status(cfg, public_id, true).await?;
+    println!("Test {public_id} paused.");
+    Ok(())
+}
+
+pub async fn tests_resume(cfg: &Config, public_id: &str) -> Result<()> {
+    set_test_status(cfg, public_id, false).await?;
+    println!("Test {public_id} resumed.");
+    Ok(())
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 pub async fn tests_search(
     cfg: &Config,
@@ -92,3 +92,43 @@ pub async fn hourly(cfg: &Config, start: String, end: Option<String>) -> Result<
     let data = crate::api::get(cfg, "/api/v1/usage/hourly-attribution", &query).await?;
     crate::formatter::output(cfg, &data)
 }
+
+/// Build `start_hr`/`end_hr` query params for the hourly v1 usage endpoints.
+fn hourly_range(start: &str, end: Option<&str>) -> Result<Vec<(&'static str, String)>> {
+    let to_hr = |s: &str| -> Result<String> {
+        let dt = chrono::DateTime::from_timestamp_millis(util::parse_time_to_unix_millis(s)?)
+            .ok_or_else(|| anyhow::anyhow!("time out of range: {s}"))?;
+        Ok(dt.format("%Y-%m-%dT%H").to_string())
+    };
+    let mut query = vec![("start_hr", to_hr(start)?)];
+    if let Some(e) = end {
+        query.push(("end_hr", to_hr(e)?));
+    }
+    Ok(query)
+}
+
+pub async fn timeseries(cfg: &Config, start: String, end: Option<String>) -> Result<()> {
+    let query = hourly_range(&start, end.as_deref())?;
+    let data = crate::api::get(cfg, "/api/v1/usage/timeseries", &query)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to get timeseries usage: {e:?}"))?;
+    formatter::output(cfg, &data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hourly_range_formats_hours() {
+        let q = hourly_range("1700000000000", Some("1700003600000")).unwrap();
+        assert_eq!(
+            q,
+            vec![
+                ("start_hr", "2023-11-14T22".to_string()),
+                ("end_hr", "2023-11-14T23".to_string()),
+            ]
+        );
+        assert_eq!(hourly_range("1d", None).unwrap().len(), 1);
+    }
+}
@@ -3,6 +3,11 @@ use anyhow::Result;
 use datadog_api_client::datadogV2::api_roles::{ListRolesOptionalParams, RolesAPI};
 #[cfg(not(target_arch = "wasm32"))]
 use datadog_api_client::datadogV2::api_users::{ListUsersOptionalParams, UsersAPI};
+#[cfg(not(target_arch = "wasm32"))]
+use datadog_api_client::datadogV2::model::{
+    PermissionsType, RelationshipToPermission, RelationshipToPermissionData, RelationshipToUser,
+    RelationshipToUserData, UsersType,
+};
 
 #[cfg(not(target_arch = "wasm32"))]
 use crate::client;
@@ -20,13 +25,71 @@ pub async fn list(cfg: &Config) -> Result<()> {
         .list_users(ListUsersOptionalParams::default())
         .await
         .map_err(|e| anyhow::anyhow!("failed to list users: {e:?}"))?;
-    formatter::output(cfg, &resp)
+    print_users(cfg, &resp)
 }
 
 #[cfg(target_arch = "wasm32")]
 pub async fn list(cfg: &Config) -> Result<()> {
     let data = crate::api::get(cfg, "/api/v2/users", &[]).await?;
-    crate::formatter::output(cfg, &data)
+    print_users(cfg, &data)
+}
+
+fn user_rows(resp: &serde_json::Value) -> Vec<Vec<String>> {
+    let str_at = formatter::str_at;
+    // Role names live in `included`; relationships only carry role IDs.
+    let role_name = |role_id: &str| {
+        resp.get("included")
+            .and_then(|i| i.as_array())
+            .and_then(|included| {
+                included
+                    .iter()
+                    .find(|r| r["type"] == "roles" && r["id"] == role_id)
+            })
+            .and_then(|r| r.pointer("/attributes/name"))
+            .and_then(|n| n.as_str())
+            .unwrap_or(role_id)
+            .to_string()
+    };
+    resp.get("data")
+        .and_then(|d| d.as_array())
+        .map(|users| {
+            users
+                .iter()
+                .map(|u| {
+                    let disabled = u
+                        .pointer("/attributes/disabled")
+                        .and_then(|d| d.as_bool())
+                        .unwrap_or(false);
+                    let status = if disabled {
+                        "Disabled".to_string()
+                    } else {
+                        str_at(u, "/attributes/status")
+                    };
+                    let role = u
+                        .pointer("/relationships/roles/data/0/id")
+                        .and_then(|id| id.as_str())
+                        .map(role_name)
+                        .unwrap_or_default();
+                    vec![
+                        str_at(u, "/id"),
+                        str_at(u, "/attributes/name"),
+                        str_at(u, "/attributes/email"),
+                        status,
+                        role,
+                    ]
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn print_users<T: serde::Serialize>(cfg: &Config, resp: &T) -> Result<()> {
+    formatter::output_rows(
+        cfg,
+        resp,
+        &["id", "name", "email", "status", "role"],
+        user_rows,
+    )
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -68,3 +131,151 @@ pub async fn roles_list(cfg: &Config) -> Result<()> {
     let data = crate::api::get(cfg, "/api/v2/roles", &[]).await?;
     crate::formatter::output(cfg, &data)
 }
+
+#[cfg(not(target_arch = "wasm32"))]
+fn make_roles_api(cfg: &Config) -> RolesAPI {
+    let dd_cfg = client::make_dd_config(cfg);
+    match client::make_bearer_client(cfg) {
+        Some(c) => RolesAPI::with_client_and_config(dd_cfg, c),
+        None => RolesAPI::with_config(dd_cfg),
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn user_relationship(user_id: &str) -> RelationshipToUser {
+    RelationshipToUser::new(RelationshipToUserData::new(
+        user_id.to_string(),
+        UsersType::USERS,
+    ))
+}
+
+#[cfg(target_arch = "wasm32")]
+fn user_relationship_body(user_id: &str) -> serde_json::Value {
+    serde_json::json!({"data": {"id": user_id, "type": "users"}})
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn roles_assign(cfg: &Config, user_id: &str, role_id: &str) -> Result<()> {
+    make_roles_api(cfg)
+        .add_user_to_role(role_id.to_string(), user_relationship(user_id))
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to assign role: {e:?}"))?;
+    println!("Role {role_id} assigned to user {user_id}.");
+    Ok(())
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn roles_assign(cfg: &Config, user_id: &str, role_id: &str) -> Result<()> {
+    crate::api::post(
+        cfg,
+        &format!("/api/v2/roles/{role_id}/users"),
+        &user_relationship_body(user_id),
+    )
+    .await?;
+    println!("Role {role_id} assigned to user {user_id}.");
+    Ok(())
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn roles_revoke(cfg: &Config, user_id: &str, role_id: &str) -> Result<()> {
+    make_roles_api(cfg)
+        .remove_user_from_role(role_id.to_string(), user_relationship(user_id))
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to revoke role: {e:?}"))?;
+    println!("Role {role_id} revoked from user {user_id}.");
+    Ok(())
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn roles_revoke(cfg: &Config, user_id: &str, role_id: &str) -> Result<()> {
+    crate::api::delete_with_body(
+        cfg,
+        &format!("/api/v2/roles/{role_id}/users"),
+        &user_relationship_body(user_id),
+    )
+    .await?;
+    println!("Role {role_id} revoked from user {user_id}.");
+    Ok(())
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn permissions_list(cfg: &Config) -> Result<()> {
+    let resp = make_roles_api(cfg)
+        .list_permissions()
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to list permissions: {e:?}"))?;
+    formatter::output(cfg, &resp)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn permissions_list(cfg: &Config) -> Result<()> {
+    let data = crate::api::get(cfg, "/api/v2/permissions", &[]).await?;
+    crate::formatter::output(cfg, &data)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn permissions_assign(cfg: &Config, role_id: &str, permission_id: &str) -> Result<()> {
+    let body = RelationshipToPermission::new().data(
+        RelationshipToPermissionData::new()
+            .id(permission_id.to_string())
+            .type_(PermissionsType::PERMISSIONS),
+    );
+    let resp = make_roles_api(cfg)
+        .add_permission_to_role(role_id.to_string(), body)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to assign permission: {e:?}"))?;
+    formatter::output(cfg, &resp)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn permissions_assign(cfg: &Config, role_id: &str, permission_id: &str) -> Result<()> {
+    let body = serde_json::json!({"data": {"id": permission_id, "type": "permissions"}});
+    let data =
+        crate::api::post(cfg, &format!("/api/v2/roles/{role_id}/permissions"), &body).await?;
+    crate::formatter::output(cfg, &data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_user_rows() {
+        let resp = serde_json::json!({
+            "data": [{
+                "id": "u1",
+                "type": "users",
+                "attributes": {
+                    "name": "Jane Doe",
+                    "email": "jane@example.com",
+                    "status": "Active",
+                    "disabled": false
+                },
+                "relationships": {
+                    "roles": { "data": [{ "id": "r1", "type": "roles" }, { "id": "r2", "type": "roles" }] }
+                }
+            }, {
+                "id": "u2",
+                "type": "users",
+                "attributes": { "email": "old@example.com", "status": "Active", "disabled": true },
+                "relationships": { "roles": { "data": [{ "id": "r9", "type": "roles" }] } }
+            }],
+            "included": [
+                { "id": "r1", "type": "roles", "attributes": { "name": "Datadog Admin Role" } },
+                { "id": "r2", "type": "roles", "attributes": { "name": "Datadog Standard Role" } }
+            ]
+        });
+        let rows = user_rows(&resp);
+        assert_eq!(rows.len(), 2);
+        assert_eq!(
+            rows[0][1..],
+            [
+                "Jane Doe",
+                "jane@example.com",
+                "Active",
+                "Datadog Admin Role"
+            ]
+        );
+        assert_eq!(rows[1][3..], ["Disabled", "r9"]);
+    }
+}
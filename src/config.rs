@@ -12,8 +12,18 @@ pub struct Config {
     pub output_format: OutputFormat,
     pub auto_approve: bool,
     pub agent_mode: bool,
+    /// Maximum table cell width before truncation; `None` disables truncation.
+    pub table_max_width: Option<usize>,
+    /// Warn on stderr when an API response reports a nearly exhausted rate limit.
+    pub rate_limit_warning: bool,
+    /// Browser only: base URL that API requests are sent to instead of the site host.
+    #[cfg(feature = "browser")]
+    pub proxy_url: Option<String>,
 }
 
+/// Default maximum width of a table cell before it is truncated with `...`.
+pub const DEFAULT_TABLE_MAX_COL_WIDTH: usize = 80;
+
 #[derive(Clone, Debug, PartialEq)]
 pub enum OutputFormat {
     Json,
@@ -81,6 +91,8 @@ impl Config {
                 || env_bool("DD_CLI_AUTO_APPROVE")
                 || file_cfg.auto_approve.unwrap_or(false),
             agent_mode: false, // set by caller from --agent flag or useragent detection
+            table_max_width: table_max_width_from_env(),
+            rate_limit_warning: true,
         };
 
         Ok(cfg)
@@ -103,6 +115,10 @@ impl Config {
             output_format: OutputFormat::Json,
             auto_approve: false,
             agent_mode: false,
+            table_max_width: Some(DEFAULT_TABLE_MAX_COL_WIDTH),
+            rate_limit_warning: false,
+            #[cfg(feature = "browser")]
+            proxy_url: None,
         }
     }
 
@@ -165,6 +181,12 @@ impl Config {
                 return mock;
             }
         }
+        #[cfg(feature = "browser")]
+        {
+            if let Some(proxy) = &self.proxy_url {
+                return proxy.trim_end_matches('/').to_string();
+            }
+        }
         format!("https://{}", self.api_host())
     }
 }
@@ -216,6 +238,20 @@ fn env_or(key: &str, fallback: Option<String>) -> Option<String> {
         .or(fallback)
 }
 
+/// Table cell width from PUPF_TABLE_MAX_COL_WIDTH (0 disables truncation).
+/// Falls back to the default width when unset or unparseable.
+#[cfg(not(feature = "browser"))]
+fn table_max_width_from_env() -> Option<usize> {
+    match std::env::var("PUPF_TABLE_MAX_COL_WIDTH")
+        .ok()
+        .and_then(|s| s.trim().parse::<usize>().ok())
+    {
+        Some(0) => None,
+        Some(width) => Some(width),
+        None => Some(DEFAULT_TABLE_MAX_COL_WIDTH),
+    }
+}
+
 #[cfg(not(feature = "browser"))]
 fn env_bool(key: &str) -> bool {
     matches!(
@@ -241,6 +277,8 @@ mod tests {
             output_format: OutputFormat::Json,
             auto_approve: false,
             agent_mode: false,
+            table_max_width: None,
+            rate_limit_warning: false,
         }
     }
 
@@ -318,6 +356,26 @@ mod tests {
         assert!(!make_cfg(None, None, None).has_bearer_token());
     }
 
+    #[test]
+    fn test_table_max_width_from_env() {
+        let _guard = ENV_LOCK.lock().unwrap_or_else(|p| p.into_inner());
+        std::env::remove_var("PUPF_TABLE_MAX_COL_WIDTH");
+        assert_eq!(
+            table_max_width_from_env(),
+            Some(DEFAULT_TABLE_MAX_COL_WIDTH)
+        );
+        std::env::set_var("PUPF_TABLE_MAX_COL_WIDTH", "120");
+        assert_eq!(table_max_width_from_env(), Some(120));
+        std::env::set_var("PUPF_TABLE_MAX_COL_WIDTH", "0");
+        assert_eq!(table_max_width_from_env(), None);
+        std::env::set_var("PUPF_TABLE_MAX_COL_WIDTH", "wide");
+        assert_eq!(
+            table_max_width_from_env(),
+            Some(DEFAULT_TABLE_MAX_COL_WIDTH)
+        );
+        std::env::remove_var("PUPF_TABLE_MAX_COL_WIDTH");
+    }
+
     #[test]
     fn test_api_host_standard() {
         let _guard = ENV_LOCK.lock().unwrap_or_else(|p| p.into_inner());
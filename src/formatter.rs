@@ -52,11 +52,13 @@ fn go_html_escape(json: &str) -> String {
 }
 
 /// Format and print data to stdout.
+/// `max_width` bounds table cell width (`None` disables truncation).
 pub fn format_and_print<T: Serialize>(
     data: &T,
     format: &OutputFormat,
     agent_mode: bool,
     meta: Option<&Metadata>,
+    max_width: Option<usize>,
 ) -> Result<()> {
     if agent_mode {
         // Sort inner data keys but preserve envelope field order (status first)
@@ -74,13 +76,19 @@ pub fn format_and_print<T: Serialize>(
     match format {
         OutputFormat::Json => print_json(data),
         OutputFormat::Yaml => print_yaml(data),
-        OutputFormat::Table => print_table(data),
+        OutputFormat::Table => print_table(data, max_width),
     }
 }
 
 /// Convenience: format and print using config settings (respects -o flag and agent mode).
 pub fn output<T: Serialize>(cfg: &crate::config::Config, data: &T) -> Result<()> {
-    format_and_print(data, &cfg.output_format, cfg.agent_mode, None)
+    format_and_print(
+        data,
+        &cfg.output_format,
+        cfg.agent_mode,
+        None,
+        cfg.table_max_width,
+    )
 }
 
 pub fn print_json<T: Serialize>(data: &T) -> Result<()> {
@@ -124,7 +132,7 @@ fn flatten_row(value: &serde_json::Value) -> serde_json::Value {
     }
 }
 
-fn print_table<T: Serialize>(data: &T) -> Result<()> {
+fn print_table<T: Serialize>(data: &T, max_width: Option<usize>) -> Result<()> {
     // Convert to serde_json::Value to inspect structure
     let value = serde_json::to_value(data)?;
     let raw_rows = extract_rows(&value);
@@ -191,7 +199,7 @@ fn print_table<T: Serialize>(data: &T) -> Result<()> {
             .iter()
             .map(|h| {
                 if let serde_json::Value::Object(map) = row {
-                    format_cell(map.get(h.as_str()))
+                    format_cell(map.get(h.as_str()), max_width)
                 } else {
                     String::new()
                 }
@@ -235,19 +243,158 @@ fn format_array_item(value: &serde_json::Value) -> String {
         }
         return format!("{{{} fields}}", map.len());
     }
-    format_cell(Some(value))
+    format_cell(Some(value), None)
 }
 
-fn format_cell(value: Option<&serde_json::Value>) -> String {
-    match value {
-        None | Some(serde_json::Value::Null) => String::new(),
-        Some(serde_json::Value::String(s)) => {
-            if s.len() > 50 {
-                format!("{}...", &s[..47])
-            } else {
-                s.clone()
+/// Whether table output may use color: stdout is a terminal and `NO_COLOR`
+/// is not set.
+pub(crate) fn color_enabled() -> bool {
+    use std::io::IsTerminal;
+    std::env::var_os("NO_COLOR").is_none() && std::io::stdout().is_terminal()
+}
+
+/// Truncate `s` to at most `max_width` characters, ending with "...". Widths
+/// too narrow for the ellipsis cut the text without one.
+pub(crate) fn truncate(s: String, max_width: Option<usize>) -> String {
+    match max_width {
+        Some(max) if s.chars().count() > max => {
+            if max < 3 {
+                return s.chars().take(max).collect();
             }
+            let kept: String = s.chars().take(max - 3).collect();
+            format!("{kept}...")
         }
+        _ => s,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Command-specific tables
+// ---------------------------------------------------------------------------
+
+/// String at JSON pointer `ptr` in `v`, or "" when missing or not a string.
+#[cfg(not(feature = "browser"))]
+pub(crate) fn str_at(v: &serde_json::Value, ptr: &str) -> String {
+    v.pointer(ptr)
+        .and_then(|s| s.as_str())
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// String elements of the array at JSON pointer `ptr`, comma-joined.
+#[cfg(not(feature = "browser"))]
+pub(crate) fn join_tags(v: &serde_json::Value, ptr: &str) -> String {
+    v.pointer(ptr)
+        .and_then(|t| t.as_array())
+        .map(|tags| {
+            tags.iter()
+                .filter_map(|t| t.as_str())
+                .collect::<Vec<_>>()
+                .join(",")
+        })
+        .unwrap_or_default()
+}
+
+/// Builds a table cell from its column index and text, e.g. to color a status.
+#[cfg(not(feature = "browser"))]
+pub(crate) type CellStyle = fn(usize, String) -> comfy_table::Cell;
+
+/// Print `data` in the configured format. Table output shows the `rows`
+/// extracted from it under `headers` instead of the generic table.
+#[cfg(not(feature = "browser"))]
+pub fn output_rows<T: Serialize>(
+    cfg: &crate::config::Config,
+    data: &T,
+    headers: &[&str],
+    rows: impl FnOnce(&serde_json::Value) -> Vec<Vec<String>>,
+) -> Result<()> {
+    output_table_rows(cfg, data, headers, rows, None)
+}
+
+/// Like `output_rows`, with cells built by `style` when color is enabled.
+#[cfg(not(feature = "browser"))]
+pub(crate) fn output_styled_rows<T: Serialize>(
+    cfg: &crate::config::Config,
+    data: &T,
+    headers: &[&str],
+    rows: impl FnOnce(&serde_json::Value) -> Vec<Vec<String>>,
+    style: CellStyle,
+) -> Result<()> {
+    output_table_rows(cfg, data, headers, rows, Some(style))
+}
+
+#[cfg(not(feature = "browser"))]
+fn output_table_rows<T: Serialize>(
+    cfg: &crate::config::Config,
+    data: &T,
+    headers: &[&str],
+    rows: impl FnOnce(&serde_json::Value) -> Vec<Vec<String>>,
+    style: Option<CellStyle>,
+) -> Result<()> {
+    if cfg.output_format != OutputFormat::Table {
+        return output(cfg, data);
+    }
+    let rows = rows(&serde_json::to_value(data)?);
+    print_table_rows(cfg, headers, &rows, style)
+}
+
+/// Print `rows` under `headers` as a table, or "No results found".
+#[cfg(not(feature = "browser"))]
+pub fn print_rows(
+    cfg: &crate::config::Config,
+    headers: &[&str],
+    rows: Vec<Vec<String>>,
+) -> Result<()> {
+    print_table_rows(cfg, headers, &rows, None)
+}
+
+#[cfg(not(feature = "browser"))]
+fn print_table_rows(
+    cfg: &crate::config::Config,
+    headers: &[&str],
+    rows: &[Vec<String>],
+    style: Option<CellStyle>,
+) -> Result<()> {
+    if rows.is_empty() {
+        println!("No results found");
+        return Ok(());
+    }
+    let style = style.filter(|_| color_enabled());
+    println!("{}", format_rows(headers, rows, cfg.table_max_width, style));
+    Ok(())
+}
+
+/// Render `rows` under `headers`, truncating each cell to `max_width`. With
+/// `style`, cells are built by it and styling is forced on.
+#[cfg(not(feature = "browser"))]
+pub(crate) fn format_rows(
+    headers: &[&str],
+    rows: &[Vec<String>],
+    max_width: Option<usize>,
+    style: Option<CellStyle>,
+) -> String {
+    let mut table = comfy_table::Table::new();
+    #[cfg(feature = "native")]
+    if style.is_some() {
+        table.enforce_styling();
+    }
+    table.set_header(headers);
+    for row in rows {
+        table.add_row(row.iter().enumerate().map(|(col, text)| {
+            let text = truncate(text.clone(), max_width);
+            match style {
+                Some(style) => style(col, text),
+                None => comfy_table::Cell::new(text),
+            }
+        }));
+    }
+    table.to_string()
+}
+
+fn format_cell(value: Option<&serde_json::Value>, max_width: Option<usize>) -> String {
+    match value {
+        None | Some(serde_json::Value::Null) => String::new(),
+        Some(serde_json::Value::String(s)) => truncate(s.clone(), max_width),
         Some(serde_json::Value::Number(n)) => n.to_string(),
         Some(serde_json::Value::Bool(b)) => b.to_string(),
         Some(serde_json::Value::Array(arr)) => {
@@ -258,12 +405,7 @@ fn format_cell(value: Option<&serde_json::Value>) -> String {
             if arr.len() > 4 {
                 parts.push(format!("+{} more", arr.len() - 4));
             }
-            let result = format!("[{}]", parts.join(", "));
-            if result.len() > 50 {
-                format!("{}...", &result[..47])
-            } else {
-                result
-            }
+            truncate(format!("[{}]", parts.join(", ")), max_width)
         }
         Some(serde_json::Value::Object(map)) => format!("{{{} fields}}", map.len()),
     }
@@ -306,37 +448,90 @@ pub fn format_api_error(operation: &str, status: Option<u16>, body: Option<&str>
 mod tests {
     use super::*;
 
+    const W: Option<usize> = Some(crate::config::DEFAULT_TABLE_MAX_COL_WIDTH);
+
+    #[test]
+    fn test_str_at_and_join_tags() {
+        let v = serde_json::json!({"attributes": {"name": "web", "port": 80, "tags": ["a:1", 2, "b:2"]}});
+        assert_eq!(str_at(&v, "/attributes/name"), "web");
+        assert_eq!(str_at(&v, "/attributes/port"), "");
+        assert_eq!(str_at(&v, "/missing"), "");
+        assert_eq!(join_tags(&v, "/attributes/tags"), "a:1,b:2");
+        assert_eq!(join_tags(&v, "/attributes/name"), "");
+    }
+
+    #[test]
+    fn test_format_rows() {
+        let rows = vec![vec!["p1".to_string(), "a".repeat(20)]];
+        let table = format_rows(&["id", "name"], &rows, Some(10), None);
+        let header = table.lines().nth(1).unwrap();
+        assert!(header.find("id").unwrap() < header.find("name").unwrap());
+        assert!(table.contains("aaaaaaa..."));
+        assert!(!table.contains(&"a".repeat(20)));
+    }
+
     #[test]
     fn test_format_cell_string() {
-        assert_eq!(format_cell(Some(&serde_json::json!("hello"))), "hello");
+        assert_eq!(format_cell(Some(&serde_json::json!("hello")), W), "hello");
     }
 
     #[test]
     fn test_format_cell_long_string() {
+        let long = "a".repeat(100);
+        let result = format_cell(Some(&serde_json::json!(long)), W);
+        assert_eq!(result.len(), 80);
+        assert!(result.ends_with("..."));
+    }
+
+    #[test]
+    fn test_format_cell_custom_width() {
         let long = "a".repeat(60);
-        let result = format_cell(Some(&serde_json::json!(long)));
+        let result = format_cell(Some(&serde_json::json!(long)), Some(50));
         assert_eq!(result.len(), 50);
         assert!(result.ends_with("..."));
     }
 
+    #[test]
+    fn test_format_cell_no_truncate() {
+        let long = "a".repeat(200);
+        let result = format_cell(Some(&serde_json::json!(long.clone())), None);
+        assert_eq!(result, long);
+    }
+
+    #[test]
+    fn test_format_cell_narrow_width() {
+        let result = format_cell(Some(&serde_json::json!("abcdef")), Some(2));
+        assert_eq!(result, "ab");
+        let result = format_cell(Some(&serde_json::json!("abcdef")), Some(0));
+        assert_eq!(result, "");
+    }
+
+    #[test]
+    fn test_format_cell_truncates_on_char_boundary() {
+        let long = "é".repeat(100);
+        let result = format_cell(Some(&serde_json::json!(long)), W);
+        assert_eq!(result.chars().count(), 80);
+        assert!(result.ends_with("..."));
+    }
+
     #[test]
     fn test_format_cell_number() {
-        assert_eq!(format_cell(Some(&serde_json::json!(42))), "42");
-        assert_eq!(format_cell(Some(&serde_json::json!(3.14))), "3.14");
+        assert_eq!(format_cell(Some(&serde_json::json!(42)), W), "42");
+        assert_eq!(format_cell(Some(&serde_json::json!(3.14)), W), "3.14");
     }
 
     #[test]
     fn test_format_cell_null() {
-        assert_eq!(format_cell(Some(&serde_json::Value::Null)), "");
-        assert_eq!(format_cell(None), "");
+        assert_eq!(format_cell(Some(&serde_json::Value::Null), W), "");
+        assert_eq!(format_cell(None, W), "");
     }
 
     #[test]
     fn test_format_cell_array() {
-        assert_eq!(format_cell(Some(&serde_json::json!([]))), "[]");
-        assert_eq!(format_cell(Some(&serde_json::json!([1, 2]))), "[1, 2]");
+        assert_eq!(format_cell(Some(&serde_json::json!([])), W), "[]");
+        assert_eq!(format_cell(Some(&serde_json::json!([1, 2])), W), "[1, 2]");
         assert_eq!(
-            format_cell(Some(&serde_json::json!([1, 2, 3, 4, 5]))),
+            format_cell(Some(&serde_json::json!([1, 2, 3, 4, 5])), W),
             "[1, 2, 3, 4, +1 more]"
         );
     }
@@ -351,7 +546,7 @@ mod tests {
             {"id": "jkl", "name": "Cache"},
             {"id": "mno", "name": "Queue"},
         ]);
-        let result = format_cell(Some(&arr));
+        let result = format_cell(Some(&arr), W);
         assert!(result.contains("API"), "got: {result}");
         assert!(result.contains("Web"), "got: {result}");
         assert!(result.contains("DB"), "got: {result}");
@@ -370,7 +565,7 @@ mod tests {
             {"name": "Cache"},
             {"name": "Queue"},
         ]);
-        let result = format_cell(Some(&arr));
+        let result = format_cell(Some(&arr), W);
         assert!(result.contains("API"), "got: {result}");
         assert!(result.contains("Web"), "got: {result}");
         assert!(result.contains("+1 more"), "got: {result}");
@@ -379,14 +574,14 @@ mod tests {
 
     #[test]
     fn test_format_cell_array_truncated() {
-        // Array whose rendered form exceeds 50 chars should be truncated with "..."
+        // Array whose rendered form exceeds the max width should be truncated with "..."
         let arr = serde_json::json!([
             {"name": "very-long-name-abc"},
             {"name": "very-long-name-def"},
             {"name": "very-long-name-ghi"},
             {"name": "very-long-name-jkl"},
         ]);
-        let result = format_cell(Some(&arr));
+        let result = format_cell(Some(&arr), Some(50));
         assert!(
             result.ends_with("..."),
             "expected truncation, got: {result}"
@@ -429,7 +624,7 @@ mod tests {
     #[test]
     fn test_format_cell_object() {
         assert_eq!(
-            format_cell(Some(&serde_json::json!({"a": 1, "b": 2}))),
+            format_cell(Some(&serde_json::json!({"a": 1, "b": 2})), W),
             "{2 fields}"
         );
     }
@@ -625,21 +820,21 @@ mod tests {
     #[test]
     fn test_format_and_print_json() {
         let data = serde_json::json!({"name": "test"});
-        let result = format_and_print(&data, &OutputFormat::Json, false, None);
+        let result = format_and_print(&data, &OutputFormat::Json, false, None, W);
         assert!(result.is_ok());
     }
 
     #[test]
     fn test_format_and_print_yaml() {
         let data = serde_json::json!({"name": "test"});
-        let result = format_and_print(&data, &OutputFormat::Yaml, false, None);
+        let result = format_and_print(&data, &OutputFormat::Yaml, false, None, W);
         assert!(result.is_ok());
     }
 
     #[test]
     fn test_format_and_print_table() {
         let data = serde_json::json!([{"id": 1, "name": "test"}]);
-        let result = format_and_print(&data, &OutputFormat::Table, false, None);
+        let result = format_and_print(&data, &OutputFormat::Table, false, None, W);
         assert!(result.is_ok());
     }
 
@@ -652,14 +847,14 @@ mod tests {
             command: Some("test".into()),
             next_action: None,
         };
-        let result = format_and_print(&data, &OutputFormat::Json, true, Some(&meta));
+        let result = format_and_print(&data, &OutputFormat::Json, true, Some(&meta), W);
         assert!(result.is_ok());
     }
 
     #[test]
     fn test_format_and_print_agent_mode_no_meta() {
         let data = serde_json::json!({"name": "test"});
-        let result = format_and_print(&data, &OutputFormat::Json, true, None);
+        let result = format_and_print(&data, &OutputFormat::Json, true, None, W);
         assert!(result.is_ok());
     }
 
@@ -672,13 +867,13 @@ mod tests {
     #[test]
     fn test_print_table_empty() {
         let data = serde_json::json!([]);
-        assert!(print_table(&data).is_ok());
+        assert!(print_table(&data, W).is_ok());
     }
 
     #[test]
     fn test_print_table_no_rows() {
         let data = serde_json::json!(42);
-        assert!(print_table(&data).is_ok());
+        assert!(print_table(&data, W).is_ok());
     }
 
     #[test]
@@ -688,15 +883,15 @@ mod tests {
 
     #[test]
     fn test_format_cell_bool() {
-        assert_eq!(format_cell(Some(&serde_json::json!(true))), "true");
-        assert_eq!(format_cell(Some(&serde_json::json!(false))), "false");
+        assert_eq!(format_cell(Some(&serde_json::json!(true)), W), "true");
+        assert_eq!(format_cell(Some(&serde_json::json!(false)), W), "false");
     }
 
     #[test]
     fn test_format_cell_three_item_array() {
-        // Three primitives: still shown in full (≤4 items, fits in 50 chars)
+        // Three primitives: still shown in full (≤4 items, fits in the default column width)
         assert_eq!(
-            format_cell(Some(&serde_json::json!([1, 2, 3]))),
+            format_cell(Some(&serde_json::json!([1, 2, 3])), W),
             "[1, 2, 3]"
         );
     }
@@ -711,6 +906,8 @@ mod tests {
             output_format: OutputFormat::Json,
             auto_approve: false,
             agent_mode: false,
+            table_max_width: None,
+            rate_limit_warning: false,
         };
         let data = serde_json::json!({"hello": "world"});
         assert!(output(&cfg, &data).is_ok());
@@ -721,7 +918,7 @@ mod tests {
         let data = serde_json::json!([
             {"id": 1, "name": "Test", "status": "ok", "type": "metric", "extra": "val"}
         ]);
-        assert!(print_table(&data).is_ok());
+        assert!(print_table(&data, W).is_ok());
     }
 
     #[test]
@@ -731,6 +928,6 @@ mod tests {
             obj.insert(format!("col_{i}"), serde_json::json!(i));
         }
         let data = serde_json::json!([obj]);
-        assert!(print_table(&data).is_ok());
+        assert!(print_table(&data, W).is_ok());
     }
 }
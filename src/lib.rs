@@ -8,9 +8,13 @@
 //!
 //! const opts = new PupClientOptions('datadoghq.com');
 //! opts.access_token = 'your-token';
+//! // Optional: route requests through a forwarding proxy
+//! // opts.proxy_url = 'http://localhost:8080';
 //! const pup = new PupClient(opts);
 //!
 //! const monitors = await pup.monitors_list(null, 'env:prod', 50);
+//! const now = Date.now() / 1000;
+//! const cpu = await pup.metrics_query('avg:system.cpu.user{*}', now - 3600, now);
 //! ```
 
 #[cfg(feature = "browser")]
@@ -39,6 +43,12 @@ pub struct PupClientOptions {
     pub api_key: Option<String>,
     #[wasm_bindgen(getter_with_clone)]
     pub app_key: Option<String>,
+    /// Base URL to send API requests to instead of `https://api.<site>`, e.g. a
+    /// local forwarding proxy in an Electron app or test harness. Browser `fetch`
+    /// has no forward-proxy setting, so the proxy must accept the Datadog API path
+    /// (`<proxy_url>/api/v1/...`) and relay it, auth headers included, to the site.
+    #[wasm_bindgen(getter_with_clone)]
+    pub proxy_url: Option<String>,
 }
 
 #[cfg(feature = "browser")]
@@ -51,6 +61,7 @@ impl PupClientOptions {
             access_token: None,
             api_key: None,
             app_key: None,
+            proxy_url: None,
         }
     }
 }
@@ -71,8 +82,9 @@ impl PupClient {
     /// Create a new PupClient from options.
     #[wasm_bindgen(constructor)]
     pub fn new(opts: PupClientOptions) -> Result<PupClient, JsError> {
-        let cfg =
+        let mut cfg =
             config::Config::from_params(opts.site, opts.access_token, opts.api_key, opts.app_key);
+        cfg.proxy_url = opts.proxy_url;
         cfg.validate_auth()
             .map_err(|e| JsError::new(&e.to_string()))?;
         Ok(PupClient { cfg })
@@ -139,6 +151,27 @@ impl PupClient {
             .await
     }
 
+    /// Create a dashboard from a JSON body.
+    pub async fn dashboards_create(&self, body_json: String) -> Result<JsValue, JsError> {
+        self.do_post("/api/v1/dashboard", &body_json).await
+    }
+
+    /// Replace a dashboard's definition with a JSON body.
+    pub async fn dashboards_update(
+        &self,
+        dashboard_id: String,
+        body_json: String,
+    ) -> Result<JsValue, JsError> {
+        self.do_put(&format!("/api/v1/dashboard/{dashboard_id}"), &body_json)
+            .await
+    }
+
+    /// Delete a dashboard by ID.
+    pub async fn dashboards_delete(&self, dashboard_id: String) -> Result<JsValue, JsError> {
+        self.do_delete(&format!("/api/v1/dashboard/{dashboard_id}"))
+            .await
+    }
+
     // -----------------------------------------------------------------------
     // Logs
     // -----------------------------------------------------------------------
@@ -148,17 +181,46 @@ impl PupClient {
         self.do_post("/api/v2/logs/events/search", &body_json).await
     }
 
+    /// List logs (v2 GET API). `from` and `to` accept ISO-8601 timestamps,
+    /// Unix milliseconds, or date math such as `now-15m`.
+    pub async fn logs_list(
+        &self,
+        query: Option<String>,
+        from: String,
+        to: String,
+        limit: Option<i64>,
+    ) -> Result<JsValue, JsError> {
+        let mut q: Vec<(&str, String)> = vec![("filter[from]", from), ("filter[to]", to)];
+        if let Some(query) = query {
+            q.push(("filter[query]", query));
+        }
+        if let Some(l) = limit {
+            q.push(("page[limit]", l.to_string()));
+        }
+        self.do_get("/api/v2/logs/events", &q).await
+    }
+
+    /// Aggregate logs with a JSON aggregate request body (compute, group_by, filter).
+    pub async fn logs_aggregate(&self, query_json: String) -> Result<JsValue, JsError> {
+        self.do_post("/api/v2/logs/analytics/aggregate", &query_json)
+            .await
+    }
+
     // -----------------------------------------------------------------------
     // Metrics
     // -----------------------------------------------------------------------
 
-    /// Query timeseries metrics.
+    /// Query timeseries metrics. `from` and `to` are Unix timestamps in seconds;
+    /// they are taken as `f64` so values computed from `Date.now() / 1000` can be
+    /// passed without rounding on the JS side.
     pub async fn metrics_query(
         &self,
         query: String,
-        from: i64,
-        to: i64,
+        from: f64,
+        to: f64,
     ) -> Result<JsValue, JsError> {
+        let from = unix_seconds("from", from)?;
+        let to = unix_seconds("to", to)?;
         let q = [
             ("query", query),
             ("from", from.to_string()),
@@ -214,6 +276,27 @@ impl PupClient {
         self.do_post("/api/v2/events/search", &body_json).await
     }
 
+    /// List events between two Unix timestamps (seconds), optionally filtered by tags.
+    pub async fn events_list(
+        &self,
+        from_ts: i64,
+        to_ts: i64,
+        tags: Option<String>,
+    ) -> Result<JsValue, JsError> {
+        let mut query: Vec<(&str, String)> =
+            vec![("start", from_ts.to_string()), ("end", to_ts.to_string())];
+        if let Some(t) = &tags {
+            query.push(("tags", t.clone()));
+        }
+        self.do_get("/api/v1/events", &query).await
+    }
+
+    /// Get a single event by ID.
+    pub async fn events_get(&self, event_id: i64) -> Result<JsValue, JsError> {
+        self.do_get(&format!("/api/v1/events/{event_id}"), &[])
+            .await
+    }
+
     // -----------------------------------------------------------------------
     // Generic raw HTTP methods — for any endpoint
     // -----------------------------------------------------------------------
@@ -292,6 +375,26 @@ impl PupClient {
     }
 }
 
+/// 2010-01-01T00:00:00Z and 2100-01-01T00:00:00Z in Unix seconds.
+#[cfg(feature = "browser")]
+const MIN_UNIX_SECONDS: i64 = 1_262_304_000;
+#[cfg(feature = "browser")]
+const MAX_UNIX_SECONDS: i64 = 4_102_444_800;
+
+/// Convert a JS number to whole Unix seconds, rejecting NaN and values outside
+/// 2010–2100 (which usually means milliseconds were passed instead of seconds).
+#[cfg(feature = "browser")]
+fn unix_seconds(name: &str, value: f64) -> Result<i64, JsError> {
+    // `as` saturates at the i64 bounds and maps NaN to 0; both fail the range check.
+    let secs = value.trunc() as i64;
+    if value.is_nan() || !(MIN_UNIX_SECONDS..MAX_UNIX_SECONDS).contains(&secs) {
+        return Err(JsError::new(&format!(
+            "{name} must be a Unix timestamp in seconds between 2010 and 2100, got {value}"
+        )));
+    }
+    Ok(secs)
+}
+
 /// Convert a serde_json::Value to a native JS object via serde-wasm-bindgen.
 #[cfg(feature = "browser")]
 fn to_js(val: &serde_json::Value) -> Result<JsValue, JsError> {
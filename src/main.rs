@@ -34,6 +34,12 @@ struct Cli {
     /// Enable agent mode
     #[arg(long, global = true)]
     agent: bool,
+    /// Disable truncation of wide table cells
+    #[arg(long = "no-truncate", global = true)]
+    no_truncate: bool,
+    /// Suppress the warning printed when an API rate limit is nearly exhausted
+    #[arg(long = "no-rate-limit-warning", global = true)]
+    no_rate_limit_warning: bool,
     #[command(subcommand)]
     command: Commands,
 }
@@ -194,12 +200,18 @@ enum Commands {
     ///   # List services with stats
     ///   pup apm services stats --start $(date -d '1 hour ago' +%s) --end $(date +%s)
     ///
+    ///   # List every endpoint a service exposes, across operations
+    ///   pup apm services all-resources --service web-store --env prod
+    ///
     ///   # Query entities with filtering
     ///   pup apm entities list --start $(date -d '1 hour ago' +%s) --end $(date +%s) --env prod
     ///
     ///   # View service dependencies
     ///   pup apm dependencies list --env prod --start $(date -d '1 hour ago' +%s) --end $(date +%s)
     ///
+    ///   # Show everything within two hops of one service
+    ///   pup apm dependencies get-for-service checkout --env prod --depth 2
+    ///
     /// AUTHENTICATION:
     ///   Requires either OAuth2 authentication (pup auth login) or API keys
     ///   (DD_API_KEY and DD_APP_KEY environment variables).
@@ -298,6 +310,9 @@ enum Commands {
     ///   # Login with OAuth2
     ///   pup auth login
     ///
+    ///   # Login from an SSH session (paste the redirect URL back)
+    ///   pup auth login --no-browser
+    ///
     ///   # Check authentication status
     ///   pup auth status
     ///
@@ -307,6 +322,9 @@ enum Commands {
     ///   # Logout and clear credentials
     ///   pup auth logout
     ///
+    ///   # Revoke a compromised token immediately
+    ///   pup auth expire-token
+    ///
     ///   # Login to different Datadog site
     ///   DD_SITE=datadoghq.eu pup auth login
     ///
@@ -401,6 +419,9 @@ enum Commands {
     ///   # List recent pipelines
     ///   pup cicd pipelines list
     ///
+    ///   # Show recent pipelines as a table (status is colored on a terminal)
+    ///   pup cicd pipelines list --output=table
+    ///
     ///   # Get pipeline details
     ///   pup cicd pipelines get --pipeline-id="abc-123"
     ///
@@ -413,9 +434,15 @@ enum Commands {
     ///   # List recent test events
     ///   pup cicd tests list --from="1h"
     ///
+    ///   # Show code coverage for the main branch
+    ///   pup cicd tests coverage --repository="github.com/org/repo" --branch=main
+    ///
     ///   # Search flaky tests
     ///   pup cicd flaky-tests search --query="flaky_test_state:active"
     ///
+    ///   # Report a deployment from a CI job
+    ///   pup cicd dora create-deployment --service=web-store --env=prod --started-at=$START_NS
+    ///
     /// AUTHENTICATION:
     ///   Requires either OAuth2 authentication (pup auth login) or API keys.
     #[command(verbatim_doc_comment)]
@@ -440,12 +467,21 @@ enum Commands {
     ///   # List AWS integrations
     ///   pup cloud aws list
     ///
+    ///   # Create an AWS account integration
+    ///   pup cloud aws create --file account.json
+    ///
     ///   # List GCP integrations
     ///   pup cloud gcp list
     ///
+    ///   # Create a GCP integration from a service account key
+    ///   pup cloud gcp create --file service-account.json
+    ///
     ///   # List Azure integrations
     ///   pup cloud azure list
     ///
+    ///   # Remove an Azure integration
+    ///   pup cloud azure delete my-tenant --client-id=00000000-0000-0000-0000-000000000000
+    ///
     /// AUTHENTICATION:
     ///   Requires either OAuth2 authentication or API keys.
     #[command(verbatim_doc_comment)]
@@ -570,12 +606,18 @@ enum Commands {
     ///   # List all dashboards
     ///   pup dashboards list
     ///
+    ///   # Find dashboards whose title contains "checkout"
+    ///   pup dashboards search checkout
+    ///
     ///   # Get detailed dashboard configuration
     ///   pup dashboards get abc-def-123
     ///
     ///   # Get dashboard and save to file
     ///   pup dashboards get abc-def-123 > dashboard.json
     ///
+    ///   # Create an empty dashboard without a JSON file
+    ///   pup dashboards create --title="Checkout" --template-variables="env:prod,service"
+    ///
     ///   # Delete a dashboard with confirmation
     ///   pup dashboards delete abc-def-123
     ///
@@ -611,8 +653,14 @@ enum Commands {
     ///   # List scanning rules
     ///   pup data-governance scanner rules list
     ///
-    ///   # Get rule details
-    ///   pup data-governance scanner rules get rule-id
+    ///   # Create a scanning rule
+    ///   pup data-governance scanner rules create --file=rule.json
+    ///
+    ///   # Delete a scanning rule
+    ///   pup data-governance scanner rules delete rule-id
+    ///
+    ///   # Reorder scanning groups
+    ///   pup data-governance scanner groups reorder group-1 group-2
     ///
     /// AUTHENTICATION:
     ///   Requires either OAuth2 authentication or API keys.
@@ -670,6 +718,12 @@ enum Commands {
     ///   # Get issue details
     ///   pup error-tracking issues get issue-id
     ///
+    ///   # Resolve all issues matching a query in the last week
+    ///   pup error-tracking issues set-status --query="service:web" --status=resolved --from=7d
+    ///
+    ///   # Ignore every matching issue, skipping the confirmation prompt
+    ///   pup error-tracking issues bulk-update --query="env:staging" --state=ignored --from=30d --yes
+    ///
     /// AUTHENTICATION:
     ///   Requires either OAuth2 authentication or API keys.
     #[command(name = "error-tracking", verbatim_doc_comment)]
@@ -693,6 +747,9 @@ enum Commands {
     ///   # List recent events
     ///   pup events list
     ///
+    ///   # Show recent events as a table
+    ///   pup events list --output=table
+    ///
     ///   # Search for deployment events
     ///   pup events search --query="tags:deployment"
     ///
@@ -727,6 +784,12 @@ enum Commands {
     ///   # Get agent details
     ///   pup fleet agents get <agent-key>
     ///
+    ///   # Enable APM on all production agents
+    ///   pup fleet agents update-config --filter-tags="env:prod" --config-key=apm_config.enabled --config-value=true
+    ///
+    ///   # Add tags to every prod agent (use --replace to overwrite its tags instead)
+    ///   pup fleet agents bulk-update-tags --filter-tags="env:prod" --new-tags=team:core,tier:1
+    ///
     ///   # List deployments
     ///   pup fleet deployments list
     ///
@@ -758,6 +821,9 @@ enum Commands {
     ///   # Create a HAMR connection
     ///   pup hamr connections create --file=connection.json
     ///
+    ///   # Update the failover configuration
+    ///   pup hamr connections update --file=connection.json
+    ///
     /// AUTHENTICATION:
     ///   Requires either OAuth2 authentication or API keys.
     #[command(verbatim_doc_comment)]
@@ -805,6 +871,9 @@ enum Commands {
     ///   # Check incident status
     ///   pup incidents get abc-123-def | jq '{status: .data.status, severity: .data.severity}'
     ///
+    ///   # List integrations (Jira, Slack) linked to an incident
+    ///   pup incidents integrations list abc-123-def
+    ///
     /// INCIDENT FIELDS:
     ///   • id: Incident ID
     ///   • title: Incident title
@@ -872,6 +941,12 @@ enum Commands {
     ///   # List Slack integrations
     ///   pup integrations slack list
     ///
+    ///   # Get a Slack channel configuration
+    ///   pup integrations slack channels get my-workspace "#alerts"
+    ///
+    ///   # List Microsoft Teams channel configurations for a tenant
+    ///   pup integrations microsoft-teams channels list --tenant-id=<tenant-id>
+    ///
     ///   # List PagerDuty integrations
     ///   pup integrations pagerduty list
     ///
@@ -938,6 +1013,11 @@ enum Commands {
     ///   • online-archives - Rehydrated logs from archives (slower queries, lower cost)
     ///   • flex - Flex logs (cost-optimized storage tier, balanced performance)
     ///
+    /// INDEXES VS STORAGE TIERS:
+    ///   --storage picks which tier to search; --index narrows the search to named log
+    ///   indexes (e.g. main, security) within it. Index names are what you see under
+    ///   Logs > Configuration > Indexes, not URLs. Without --index, all indexes are searched.
+    ///
     /// LOG QUERY SYNTAX:
     ///   Logs use a query language similar to web search:
     ///   • status:error - Match by status
@@ -964,6 +1044,15 @@ enum Commands {
     ///   # Search Flex logs specifically
     ///   pup logs search --query="status:error" --from="1h" --storage="flex"
     ///
+    ///   # Search only specific indexes
+    ///   pup logs search --query="status:error" --index=main,security
+    ///
+    ///   # Stream logs as NDJSON into another tool
+    ///   pup logs search --query="service:web-app" --limit=5000 --stream | jq .attributes.message
+    ///
+    ///   # List the oldest matching logs from one index first
+    ///   pup logs list --query="service:web-app" --index=main --sort=timestamp
+    ///
     ///   # Query logs from a specific service
     ///   pup logs query --query="service:web-app" --from="4h" --to="now"
     ///
@@ -979,6 +1068,12 @@ enum Commands {
     ///   # Get specific archive details
     ///   pup logs archives get "my-archive-id"
     ///
+    ///   # Change the order archives are evaluated in
+    ///   pup logs archives reorder archive-a archive-b archive-c
+    ///
+    ///   # Rehydrate a day of error logs from an archive (the job runs asynchronously)
+    ///   pup logs archives rehydrate "my-archive-id" --from=2d --to=1d --query="status:error"
+    ///
     ///   # List log-based metrics
     ///   pup logs metrics list
     ///
@@ -991,6 +1086,9 @@ enum Commands {
     ///   # List restriction queries
     ///   pup logs restriction-queries list
     ///
+    ///   # Grant a role access to a restriction query
+    ///   pup logs restriction-queries add-role <query-id> <role-id>
+    ///
     /// AUTHENTICATION:
     ///   Requires either OAuth2 authentication (pup auth login) or API keys
     ///   (DD_API_KEY and DD_APP_KEY environment variables).
@@ -1034,10 +1132,12 @@ enum Commands {
     ///   # List metrics
     ///   pup metrics list
     ///   pup metrics list --filter="system.*"
+    ///   pup metrics list --since=24h
     ///
     ///   # Get metric metadata
     ///   pup metrics metadata get system.cpu.user
     ///   pup metrics metadata get system.cpu.user --output=table
+    ///   pup metrics metadata list --filter="system.cpu"
     ///
     ///   # Update metric metadata
     ///   pup metrics metadata update system.cpu.user \
@@ -1048,6 +1148,7 @@ enum Commands {
     ///   # Submit custom metrics
     ///   pup metrics submit --name="custom.metric" --value=123 --tags="env:prod,team:backend"
     ///   pup metrics submit --name="custom.gauge" --value=99.5 --type="gauge" --timestamp=now
+    ///   pup metrics submit --many --file=series.json
     ///
     ///   # List metric tags
     ///   pup metrics tags list system.cpu.user
@@ -1068,6 +1169,7 @@ enum Commands {
     /// CAPABILITIES:
     ///   • Query IP ranges
     ///   • Check API status
+    ///   • List permissions and their IDs
     ///   • View service level agreements
     ///   • Access miscellaneous endpoints
     ///
@@ -1078,6 +1180,12 @@ enum Commands {
     ///   # Check API status
     ///   pup misc status
     ///
+    ///   # Gate a CI job on a valid API key
+    ///   pup misc validate-api-key
+    ///
+    ///   # Look up permission IDs to assign to roles
+    ///   pup misc permissions list
+    ///
     /// AUTHENTICATION:
     ///   Some endpoints may not require authentication.
     #[command(verbatim_doc_comment)]
@@ -1108,6 +1216,13 @@ enum Commands {
     ///   • event alert: Alert on event patterns
     ///   • process alert: Alert on process status
     ///
+    /// MONITOR STATES (overall_state):
+    ///   • OK / Warn / Alert: Result of the last evaluation against the thresholds
+    ///   • No Data: The monitor is active but its query returned no data recently
+    ///   • Ignored: The monitor is not being evaluated (e.g. its group was resolved)
+    ///   • Deleted monitors are a separate case: they have a non-null `deleted`
+    ///     timestamp and may still appear in listings; use --no-deleted to hide them
+    ///
     /// EXAMPLES:
     ///   # List all monitors
     ///   pup monitors list
@@ -1118,8 +1233,27 @@ enum Commands {
     ///   # Filter monitors by tags
     ///   pup monitors list --tags="env:production,team:backend"
     ///
+    ///   # Sort monitors by status, descending
+    ///   pup monitors list --sort=-status
+    ///
+    ///   # Print only monitor names
+    ///   pup monitors list --json-path '$[*].name'
+    ///
+    ///   # Count matching monitors
+    ///   pup monitors list --tags="team:backend" --count
+    ///
+    ///   # Leave recently deleted monitors out of the results
+    ///   pup monitors list --no-deleted
+    ///
     ///   # Get detailed information about a specific monitor
     ///   pup monitors get 12345678
+    ///   pup monitors get 12345678 --output=table
+    ///
+    ///   # Mute all backend monitors for a 2-hour maintenance window
+    ///   pup monitors bulk-mute --tags="team:backend" --end=2h --message="DB upgrade"
+    ///
+    ///   # Check whether monitors are referenced before deleting them
+    ///   pup monitors check-can-delete 12345678 87654321
     ///
     ///   # Delete a monitor with confirmation prompt
     ///   pup monitors delete 12345678
@@ -1157,6 +1291,7 @@ enum Commands {
     ///
     ///   # List network devices
     ///   pup network devices list
+    ///   pup network devices list --output=table
     ///
     /// AUTHENTICATION:
     ///   Requires either OAuth2 authentication or API keys.
@@ -1175,7 +1310,7 @@ enum Commands {
     /// CAPABILITIES:
     ///   • List notebooks
     ///   • Get notebook details
-    ///   • Create new notebooks
+    ///   • Create new notebooks, from scratch or from a template notebook
     ///   • Update notebooks
     ///   • Delete notebooks
     ///
@@ -1192,6 +1327,9 @@ enum Commands {
     ///   # Create from stdin
     ///   cat notebook.json | pup notebooks create --body -
     ///
+    ///   # Start an investigation notebook from a standard template
+    ///   pup notebooks create-from-template 12345 --name="Incident 42 investigation"
+    ///
     ///   # Update a notebook
     ///   pup notebooks update 12345 --body @updated.json
     ///
@@ -1216,6 +1354,8 @@ enum Commands {
     /// CAPABILITIES:
     ///   • List pipeline configurations
     ///   • Get pipeline details
+    ///   • Create, update, and delete pipelines
+    ///   • Validate pipeline configurations
     ///   • View pipeline metrics
     ///   • Monitor pipeline health
     ///
@@ -1226,6 +1366,12 @@ enum Commands {
     ///   # Get pipeline details
     ///   pup obs-pipelines get pipeline-id
     ///
+    ///   # Validate a pipeline config without creating it
+    ///   pup obs-pipelines validate --file pipeline.json
+    ///
+    ///   # Create a pipeline
+    ///   pup obs-pipelines create --file pipeline.json
+    ///
     /// AUTHENTICATION:
     ///   Requires either OAuth2 authentication or API keys.
     #[command(name = "obs-pipelines", verbatim_doc_comment)]
@@ -1251,6 +1397,9 @@ enum Commands {
     ///   # List all teams
     ///   pup on-call teams list
     ///
+    ///   # Who is on call for a team right now
+    ///   pup on-call teams get-on-call <team-id>
+    ///
     ///   # Create a new team
     ///   pup on-call teams create --name="SRE Team" --handle="sre-team"
     ///
@@ -1260,6 +1409,12 @@ enum Commands {
     ///   # List team members
     ///   pup on-call teams memberships list <team-id>
     ///
+    ///   # Add a runbook link to a team
+    ///   pup on-call teams links create <team-id> --label="Runbook" --url="https://wiki/runbook"
+    ///
+    ///   # Show who is on call right now
+    ///   pup on-call schedules oncall-users <schedule-id>
+    ///
     /// AUTHENTICATION:
     ///   Requires either OAuth2 authentication (pup auth login) or API keys.
     #[command(name = "on-call", verbatim_doc_comment)]
@@ -1284,6 +1439,12 @@ enum Commands {
     ///   # List child organizations
     ///   pup organizations list
     ///
+    ///   # Show organization settings
+    ///   pup organizations settings list
+    ///
+    ///   # Change a setting
+    ///   pup organizations settings update monitor_timezone UTC
+    ///
     /// AUTHENTICATION:
     ///   Requires either OAuth2 authentication or API keys with org management permissions.
     #[command(verbatim_doc_comment)]
@@ -1291,9 +1452,9 @@ enum Commands {
         #[command(subcommand)]
         action: OrgActions,
     },
-    /// Send product analytics events
+    /// Send and query product analytics events
     ///
-    /// Send server-side product analytics events to Datadog.
+    /// Send server-side product analytics events to Datadog and read them back.
     ///
     /// Product Analytics provides insights into user behavior and product usage
     /// through server-side event tracking.
@@ -1314,6 +1475,10 @@ enum Commands {
     ///     --properties='{"amount":99.99,"currency":"USD"}' \
     ///     --user-id=user-123
     ///
+    ///   # Count distinct users per action over the last day
+    ///   pup product-analytics events aggregate --app-id=my-app --from=1d \
+    ///     --compute=cardinality:@usr.id --group-by=@action.name
+    ///
     /// AUTHENTICATION:
     ///   Requires OAuth2 (via 'pup auth login') or valid API + Application keys.
     #[command(name = "product-analytics", verbatim_doc_comment)]
@@ -1359,6 +1524,12 @@ enum Commands {
     ///   # Create a new browser RUM application
     ///   pup rum apps create --name="my-web-app" --type="browser"
     ///
+    ///   # Clone a RUM application under a new name
+    ///   pup rum apps clone abc-123-def --name="my-web-app-staging"
+    ///
+    ///   # Rename an application
+    ///   pup rum apps update abc-123-def --name="my-web-app-v2"
+    ///
     ///   # List RUM custom metrics
     ///   pup rum metrics list
     ///
@@ -1368,6 +1539,9 @@ enum Commands {
     ///   # Query session replay data
     ///   pup rum sessions list --from="1h"
     ///
+    ///   # Export last week's sessions to CSV
+    ///   pup rum sessions export --from="7d" --output-file=sessions.csv
+    ///
     /// AUTHENTICATION:
     ///   Requires either OAuth2 authentication (pup auth login) or API keys
     ///   (DD_API_KEY and DD_APP_KEY environment variables).
@@ -1393,8 +1567,12 @@ enum Commands {
     ///   # List scorecards
     ///   pup scorecards list
     ///
-    ///   # Get scorecard details
-    ///   pup scorecards get scorecard-id
+    ///   # Get scorecard details with its rules embedded
+    ///   pup scorecards get scorecard-id --include-rules
+    ///
+    ///   # List scorecard rules and outcomes
+    ///   pup scorecards rules list --filter-query="Has owner"
+    ///   pup scorecards outcomes list --service-name=web-store
     ///
     /// AUTHENTICATION:
     ///   Requires either OAuth2 authentication or API keys.
@@ -1423,6 +1601,9 @@ enum Commands {
     ///   # List security signals
     ///   pup security signals list
     ///
+    ///   # Open a case for related signals (case.json sets project and type_id)
+    ///   pup security signals correlate sig-1,sig-2 --title="Credential stuffing" --file=case.json
+    ///
     /// AUTHENTICATION:
     ///   Requires either OAuth2 authentication or API keys.
     #[command(verbatim_doc_comment)]
@@ -1450,6 +1631,12 @@ enum Commands {
     ///   # Get service details
     ///   pup service-catalog get service-name
     ///
+    ///   # Show what a service depends on
+    ///   pup service-catalog dependencies service-name --direction=downstream --output=table
+    ///
+    ///   # Create or update a service from its definition file
+    ///   pup service-catalog create-or-update --file=service.json
+    ///
     /// AUTHENTICATION:
     ///   Requires either OAuth2 authentication or API keys.
     #[command(name = "service-catalog", verbatim_doc_comment)]
@@ -1495,6 +1682,13 @@ enum Commands {
     ///   # Get SLO history and status
     ///   pup slos get abc-123-def | jq '.data'
     ///
+    ///   # Show 30 days of SLO compliance as a table
+    ///   pup slos history abc-123-def --from=30d --output=table
+    ///
+    ///   # Validate, then create, a batch of SLOs from a JSON array
+    ///   pup slos bulk-create --file=slos.json --dry-run
+    ///   pup slos bulk-create --file=slos.json
+    ///
     ///   # Delete an SLO with confirmation
     ///   pup slos delete abc-123-def
     ///
@@ -1591,6 +1785,10 @@ enum Commands {
     ///   # Get test details
     ///   pup synthetics tests get test-id
     ///
+    ///   # Pause a test during maintenance, then resume it
+    ///   pup synthetics tests pause abc-123-def
+    ///   pup synthetics tests resume abc-123-def
+    ///
     ///   # List available locations
     ///   pup synthetics locations list
     ///
@@ -1701,6 +1899,7 @@ enum Commands {
     /// EXAMPLES:
     ///   # List all users
     ///   pup users list
+    ///   pup users list --output=table
     ///
     ///   # Get user details
     ///   pup users get user-id
@@ -1708,6 +1907,14 @@ enum Commands {
     ///   # List roles
     ///   pup users roles list
     ///
+    ///   # Assign or revoke a role
+    ///   pup users roles assign user-id role-id
+    ///   pup users roles revoke user-id role-id
+    ///
+    ///   # List permissions and grant one to a role
+    ///   pup users permissions list
+    ///   pup users permissions assign role-id permission-id
+    ///
     /// AUTHENTICATION:
     ///   Requires either OAuth2 authentication or API keys.
     #[command(verbatim_doc_comment)]
@@ -1737,9 +1944,54 @@ enum MonitorActions {
             help = "Maximum number of monitors to return (default: 200, max: 1000)"
         )]
         limit: i32,
+        #[arg(
+            long,
+            help = "Sort by field, prefix with - for descending (e.g. name, -name, status, -status); fetches all pages before applying --limit"
+        )]
+        sort: Option<String>,
+        #[arg(
+            long = "json-path",
+            help = "Print only the values matched by a JSONPath expression (e.g. '$[*].name')"
+        )]
+        json_path: Option<String>,
+        #[arg(
+            long,
+            conflicts_with_all = ["limit", "sort", "json_path"],
+            help = "Print only the number of matching monitors (fetches all pages)"
+        )]
+        count: bool,
+        #[arg(
+            long,
+            default_value_t = false,
+            help = "Exit with code 1 if any monitor is in Alert state"
+        )]
+        exit_code: bool,
+        #[arg(
+            long,
+            help = "Exit with code 1 if any monitor is in this state (e.g. Alert, Warn, \"No Data\")"
+        )]
+        exit_on_state: Option<String>,
+        #[arg(long, help = "Exclude deleted monitors from the results")]
+        no_deleted: bool,
     },
     /// Get monitor details
     Get { monitor_id: i64 },
+    /// Mute all monitors matching a tag filter (one downtime per monitor)
+    #[command(name = "bulk-mute")]
+    BulkMute {
+        #[arg(
+            long,
+            help = "Monitor tags to match (comma-separated, e.g., team:backend,env:prod)"
+        )]
+        tags: String,
+        #[arg(
+            long,
+            help = "Mute until: RFC3339, Unix timestamp, or duration from now (e.g. 2h); default: indefinitely"
+        )]
+        end: Option<String>,
+        #[arg(long, help = "Message attached to each downtime")]
+        message: Option<String>,
+    },
     /// Create a monitor from JSON file
     Create {
         #[arg(long)]
@@ -1764,6 +2016,16 @@ enum MonitorActions {
     },
     /// Delete a monitor
     Delete { monitor_id: i64 },
+    /// Check whether monitors can be deleted (not referenced by composites or SLOs)
+    #[command(name = "check-can-delete")]
+    CheckCanDelete {
+        #[arg(
+            required = true,
+            value_delimiter = ',',
+            help = "Monitor IDs (space or comma-separated)"
+        )]
+        monitor_ids: Vec<i64>,
+    },
 }
 
 // ---- Logs ----
@@ -1785,14 +2047,23 @@ enum LogActions {
             help = "End time: 1h, 5min, 2hours, '5 minutes', RFC3339, Unix timestamp, or 'now'"
         )]
         to: String,
-        #[arg(long, default_value_t = 50, help = "Maximum number of logs (1-1000)")]
+        #[arg(
+            long,
+            default_value_t = 50,
+            help = "Maximum number of logs (1-1000, or more with --stream)"
+        )]
         limit: i32,
         #[arg(long, help = "Sort order: asc or desc", default_value = "desc")]
         sort: String,
-        #[arg(long, help = "Comma-separated log indexes")]
+        #[arg(long, help = "Comma-separated log index names (e.g. main,security)")]
         index: Option<String>,
         #[arg(long, help = "Storage tier: indexes, online-archives, or flex")]
         storage: Option<String>,
+        #[arg(
+            long,
+            help = "Print each log as a JSON line (NDJSON) as soon as it is received"
+        )]
+        stream: bool,
     },
     /// List logs (v2 API)
     List {
@@ -1808,8 +2079,14 @@ enum LogActions {
         to: String,
         #[arg(long, default_value_t = 10, help = "Number of logs")]
         limit: i32,
-        #[arg(long, default_value = "-timestamp", help = "Sort order")]
+        #[arg(
+            long,
+            default_value = "-timestamp",
+            help = "Sort order: timestamp, -timestamp, @timestamp, or -@timestamp"
+        )]
         sort: String,
+        #[arg(long, help = "Comma-separated log index names (e.g. main,security)")]
+        index: Option<String>,
         #[arg(long, help = "Storage tier: indexes, online-archives, or flex")]
         storage: Option<String>,
     },
@@ -1827,7 +2104,11 @@ enum LogActions {
         to: String,
         #[arg(long, default_value_t = 50, help = "Maximum results")]
         limit: i32,
-        #[arg(long, default_value = "-timestamp", help = "Sort order")]
+        #[arg(
+            long,
+            default_value = "-timestamp",
+            help = "Sort order: timestamp, -timestamp, @timestamp, or -@timestamp"
+        )]
         sort: String,
         #[arg(long, help = "Storage tier: indexes, online-archives, or flex")]
         storage: Option<String>,
@@ -1877,6 +2158,37 @@ enum LogActions {
         #[command(subcommand)]
         action: LogRestrictionQueryActions,
     },
+    /// Manage log processing pipelines
+    Pipelines {
+        #[command(subcommand)]
+        action: LogPipelineActions,
+    },
+}
+
+#[derive(Subcommand)]
+enum LogPipelineActions {
+    /// List log pipelines
+    List,
+    /// Get log pipeline details
+    Get { pipeline_id: String },
+    /// Create a log pipeline from JSON file
+    Create {
+        #[arg(long, help = "JSON file with pipeline definition (required)")]
+        file: String,
+    },
+    /// Update a log pipeline from JSON file
+    Update {
+        pipeline_id: String,
+        #[arg(long, help = "JSON file with pipeline definition (required)")]
+        file: String,
+    },
+    /// Delete a log pipeline
+    Delete { pipeline_id: String },
+    /// Reorder log pipelines from JSON file ({"pipeline_ids": [...]})
+    Order {
+        #[arg(long, help = "JSON file with pipeline order (required)")]
+        file: String,
+    },
 }
 
 #[derive(Subcommand)]
@@ -1885,6 +2197,25 @@ enum LogRestrictionQueryActions {
     List,
     /// Get restriction query details
     Get { query_id: String },
+    /// Create a restriction query from JSON file
+    Create {
+        #[arg(long)]
+        file: String,
+    },
+    /// Update a restriction query from JSON file
+    Update {
+        query_id: String,
+        #[arg(long)]
+        file: String,
+    },
+    /// Delete a restriction query
+    Delete { query_id: String },
+    /// Grant a role access to a restriction query
+    #[command(name = "add-role")]
+    AddRole { query_id: String, role_id: String },
+    /// Revoke a role's access to a restriction query
+    #[command(name = "remove-role")]
+    RemoveRole { query_id: String, role_id: String },
 }
 
 #[derive(Subcommand)]
@@ -1895,6 +2226,23 @@ enum LogArchiveActions {
     Get { archive_id: String },
     /// Delete a log archive
     Delete { archive_id: String },
+    /// Show the current archive processing order
+    Order,
+    /// Set the archive processing order (IDs in the desired order)
+    Reorder {
+        #[arg(required = true, num_args = 1.., value_delimiter = ',')]
+        ids: Vec<String>,
+    },
+    /// Rehydrate an archive's logs for a time range (runs asynchronously)
+    Rehydrate {
+        archive_id: String,
+        #[arg(long, help = "Start time (required)")]
+        from: String,
+        #[arg(long, default_value = "now", help = "End time")]
+        to: String,
+        #[arg(long, help = "Only rehydrate logs matching this query")]
+        query: Option<String>,
+    },
 }
 
 #[derive(Subcommand)]
@@ -1946,6 +2294,23 @@ enum IncidentActions {
         #[command(subcommand)]
         action: IncidentPostmortemActions,
     },
+    /// Manage incident integrations (e.g. Jira, Slack)
+    Integrations {
+        #[command(subcommand)]
+        action: IncidentIntegrationActions,
+    },
+}
+
+#[derive(Subcommand)]
+enum IncidentIntegrationActions {
+    /// List integrations linked to an incident
+    List { incident_id: String },
+    /// Add an integration to an incident
+    Create {
+        incident_id: String,
+        #[arg(long, help = "JSON file with request body (required)")]
+        file: String,
+    },
 }
 
 #[derive(Subcommand)]
@@ -2013,13 +2378,33 @@ enum IncidentPostmortemActions {
 #[derive(Subcommand)]
 enum DashboardActions {
     /// List all dashboards
-    List,
+    List {
+        #[arg(
+            long,
+            default_value_t = false,
+            help = "Fetch each dashboard and include its widget definitions"
+        )]
+        with_widgets: bool,
+    },
+    /// Search dashboards by title (case-insensitive substring match)
+    Search { query: String },
     /// Get dashboard details
     Get { id: String },
-    /// Create a dashboard from JSON file
+    /// Create a dashboard from JSON file or from flags
     Create {
-        #[arg(long)]
-        file: String,
+        #[arg(long, conflicts_with_all = ["title", "description", "template_variables"])]
+        file: Option<String>,
+        #[arg(long, required_unless_present = "file", help = "Dashboard title")]
+        title: Option<String>,
+        #[arg(long, help = "Dashboard description")]
+        description: Option<String>,
+        #[arg(long, default_value = "ordered", help = "Layout type: ordered or free")]
+        layout_type: String,
+        #[arg(
+            long,
+            help = "Comma-separated template variables, optionally with defaults (e.g. env:prod,service)"
+        )]
+        template_variables: Option<String>,
     },
     /// Update a dashboard from JSON file
     Update {
@@ -2043,7 +2428,12 @@ enum MetricActions {
         filter: Option<String>,
         #[arg(long, help = "Filter metrics by tags (e.g., env:prod,service:api)")]
         tag_filter: Option<String>,
-        #[arg(long, default_value = "1h")]
+        #[arg(
+            long,
+            visible_alias = "since",
+            default_value = "1h",
+            help = "Show metrics active since this time (e.g., 1h, 7d, RFC3339, or Unix timestamp in milliseconds); sent to the API as Unix seconds"
+        )]
         from: String,
     },
     /// Search metrics (v1 API)
@@ -2079,6 +2469,11 @@ enum MetricActions {
             help = "End time (e.g., now, unix timestamp)"
         )]
         to: String,
+        #[arg(
+            long,
+            help = "Emit raw data points as CSV (<epoch_ms>,<value>, prefixed with the group key for grouped queries)"
+        )]
+        raw: bool,
     },
     /// Submit custom metrics to Datadog
     Submit {
@@ -2088,8 +2483,12 @@ enum MetricActions {
             required_unless_present = "file"
         )]
         name: Option<String>,
-        #[arg(long, default_value_t = 0.0, help = "Metric value (required)")]
-        value: f64,
+        #[arg(
+            long,
+            help = "Metric value (required)",
+            required_unless_present = "file"
+        )]
+        value: Option<f64>,
         #[arg(long, help = "Tags (comma-separated)")]
         tags: Option<String>,
         #[arg(
@@ -2106,8 +2505,16 @@ enum MetricActions {
             help = "Interval in seconds for rate/count metrics"
         )]
         interval: i64,
+        #[arg(long, help = "Point timestamp (default: now)")]
+        timestamp: Option<String>,
         #[arg(long, help = "JSON file with metrics data", conflicts_with = "name")]
         file: Option<String>,
+        #[arg(
+            long,
+            requires = "file",
+            help = "Treat --file as an array of series and submit in batches of 500"
+        )]
+        many: bool,
     },
     /// Manage metric metadata
     Metadata {
@@ -2135,6 +2542,18 @@ enum MetricTagActions {
 
 #[derive(Subcommand)]
 enum MetricMetadataActions {
+    /// List metadata for all active metrics
+    List {
+        #[arg(long, help = "Only include metrics whose name contains this text")]
+        filter: Option<String>,
+        #[arg(
+            long,
+            visible_alias = "since",
+            default_value = "1h",
+            help = "Include metrics active since this time (e.g., 1h, 7d, RFC3339, or Unix timestamp in milliseconds)"
+        )]
+        from: String,
+    },
     /// Get metric metadata
     Get { metric_name: String },
     /// Update metric metadata
@@ -2167,6 +2586,14 @@ enum SloActions {
         #[arg(long)]
         file: String,
     },
+    /// Create every SLO in a JSON array file
+    #[command(name = "bulk-create")]
+    BulkCreate {
+        #[arg(long, help = "JSON file with an array of SLO definitions (required)")]
+        file: String,
+        #[arg(long, help = "Validate the definitions without creating anything")]
+        dry_run: bool,
+    },
     /// Update an SLO from JSON file
     Update {
         id: String,
@@ -2183,6 +2610,20 @@ enum SloActions {
         #[arg(long, help = "End time (now, Unix timestamp, or RFC3339)")]
         to: String,
     },
+    /// Get historical SLO compliance (overall, per group, and per monitor)
+    History {
+        id: String,
+        #[arg(long, help = "Start time (1h, 30d, Unix timestamp, or RFC3339)")]
+        from: String,
+        #[arg(
+            long,
+            default_value = "now",
+            help = "End time (now, Unix timestamp, or RFC3339)"
+        )]
+        to: String,
+        #[arg(long, help = "SLO target to evaluate against (e.g. 99.9)")]
+        target: Option<f64>,
+    },
 }
 
 // ---- Synthetics ----
@@ -2227,6 +2668,10 @@ enum SyntheticsTestActions {
         #[arg(long, default_value_t = 0)]
         start: i64,
     },
+    /// Pause a synthetic test
+    Pause { public_id: String },
+    /// Resume a paused synthetic test
+    Resume { public_id: String },
 }
 
 #[derive(Subcommand)]
@@ -2344,12 +2789,44 @@ enum UserActions {
         #[command(subcommand)]
         action: UserRoleActions,
     },
+    /// Manage permissions
+    Permissions {
+        #[command(subcommand)]
+        action: UserPermissionActions,
+    },
+}
+
+#[derive(Subcommand)]
+enum UserPermissionActions {
+    /// List available permissions
+    List,
+    /// Grant a permission to a role
+    Assign {
+        /// Role ID
+        role_id: String,
+        /// Permission ID
+        permission_id: String,
+    },
 }
 
 #[derive(Subcommand)]
 enum UserRoleActions {
     /// List roles
     List,
+    /// Assign a role to a user
+    Assign {
+        /// User ID
+        user_id: String,
+        /// Role ID
+        role_id: String,
+    },
+    /// Revoke a role from a user
+    Revoke {
+        /// User ID
+        user_id: String,
+        /// Role ID
+        role_id: String,
+    },
 }
 
 // ---- Infrastructure ----
@@ -2360,6 +2837,11 @@ enum InfraActions {
         #[command(subcommand)]
         action: InfraHostActions,
     },
+    /// Manage containers
+    Containers {
+        #[command(subcommand)]
+        action: InfraContainerActions,
+    },
 }
 
 #[derive(Subcommand)]
@@ -2377,6 +2859,21 @@ enum InfraHostActions {
     Get { hostname: String },
 }
 
+#[derive(Subcommand)]
+enum InfraContainerActions {
+    /// List containers
+    List {
+        #[arg(long, help = "Tag filter (e.g. env:prod,kube_namespace:web)")]
+        filter: Option<String>,
+        #[arg(long, help = "Sort field")]
+        sort: Option<String>,
+        #[arg(long, default_value_t = 100, help = "Results per page")]
+        page_size: i32,
+    },
+    /// Get container details
+    Get { container_id: String },
+}
+
 // ---- Audit Logs ----
 #[derive(Subcommand)]
 enum AuditLogActions {
@@ -2466,6 +2963,18 @@ enum SecuritySignalActions {
         #[arg(long, help = "Sort field: severity, status, timestamp")]
         sort: Option<String>,
     },
+    /// Group related signals into a security case
+    Correlate {
+        #[arg(required = true, num_args = 1.., value_delimiter = ',')]
+        signal_ids: Vec<String>,
+        #[arg(long, help = "Case title")]
+        title: Option<String>,
+        #[arg(
+            long,
+            help = "JSON case create request with project and type_id (prints a draft without it)"
+        )]
+        file: Option<String>,
+    },
 }
 
 #[derive(Subcommand)]
@@ -2483,6 +2992,8 @@ enum SecurityFindingActions {
 enum SecurityContentPackActions {
     /// List content pack states
     List,
+    /// Get the state of a single content pack
+    Get { pack_id: String },
     /// Activate a content pack
     Activate { pack_id: String },
     /// Deactivate a content pack
@@ -2496,6 +3007,8 @@ enum SecurityRiskScoreActions {
         #[arg(long)]
         query: Option<String>,
     },
+    /// Get an entity's risk score, including contributing factors
+    Get { entity_id: String },
 }
 
 // ---- Organizations ----
@@ -2505,6 +3018,25 @@ enum OrgActions {
     List,
     /// Get organization details
     Get,
+    /// Manage organization settings
+    Settings {
+        #[command(subcommand)]
+        action: OrgSettingsActions,
+    },
+}
+
+#[derive(Subcommand)]
+enum OrgSettingsActions {
+    /// List organization settings and their values
+    List,
+    /// Get an organization setting
+    Get { setting_name: String },
+    /// Update an organization setting
+    Update {
+        setting_name: String,
+        #[arg(help = "New value; JSON literals (true, 30, {...}) keep their type")]
+        value: String,
+    },
 }
 
 // ---- Cloud ----
@@ -2536,22 +3068,77 @@ enum CloudActions {
 enum CloudAwsActions {
     /// List AWS integrations
     List,
+    /// Get an AWS account integration
+    Get { account_id: String },
+    /// Create an AWS account integration
+    Create {
+        #[arg(long, help = "JSON file with request body (required)")]
+        file: String,
+    },
+    /// Update an AWS account integration
+    Update {
+        account_id: String,
+        #[arg(long, help = "JSON file with request body (required)")]
+        file: String,
+    },
+    /// Delete an AWS account integration
+    Delete { account_id: String },
 }
 
 #[derive(Subcommand)]
 enum CloudGcpActions {
     /// List GCP integrations
     List,
+    /// Create a GCP integration from a service account key
+    Create {
+        #[arg(
+            long,
+            help = "GCP service account key JSON file (required). Must include type, project_id, private_key_id, private_key, client_email, client_id, auth_uri, token_uri, auth_provider_x509_cert_url and client_x509_cert_url"
+        )]
+        file: String,
+    },
+    /// Delete a GCP integration
+    Delete {
+        /// GCP project ID of the integrated account
+        account_id: String,
+        #[arg(long, help = "Service account email of the integration (required)")]
+        client_email: String,
+    },
 }
 
 #[derive(Subcommand)]
 enum CloudAzureActions {
     /// List Azure integrations
     List,
-}
-
-#[derive(Subcommand)]
-enum CloudOciActions {
+    /// Create an Azure integration
+    Create {
+        #[arg(
+            long,
+            help = "Azure account JSON file (required). Must include tenant_name, client_id and client_secret"
+        )]
+        file: String,
+    },
+    /// Update an Azure integration
+    Update {
+        /// Azure tenant name of the integrated account
+        tenant_name: String,
+        #[arg(
+            long,
+            help = "Azure account JSON file (required). Must include client_id; set new_tenant_name or new_client_id to change them"
+        )]
+        file: String,
+    },
+    /// Delete an Azure integration
+    Delete {
+        /// Azure tenant name of the integrated account
+        tenant_name: String,
+        #[arg(long, help = "Client ID of the Azure app registration (required)")]
+        client_id: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum CloudOciActions {
     /// Manage OCI tenancy configurations
     Tenancies {
         #[command(subcommand)]
@@ -2650,6 +3237,16 @@ enum CaseActions {
         #[arg(long, help = "New status (required)")]
         status: String,
     },
+    /// Update the status of multiple cases at once
+    #[command(name = "bulk-update-status")]
+    BulkUpdateStatus {
+        #[arg(help = "Case IDs to update", required_unless_present = "file")]
+        case_ids: Vec<String>,
+        #[arg(long, help = "New status: OPEN, IN_PROGRESS, or CLOSED (required)")]
+        status: String,
+        #[arg(long, help = "File with newline-separated case IDs")]
+        file: Option<String>,
+    },
     /// Manage case projects
     Projects {
         #[command(subcommand)]
@@ -2767,6 +3364,30 @@ enum ServiceCatalogActions {
     List,
     /// Get service details
     Get { service_name: String },
+    /// Search catalog entities with a full-text query
+    Search {
+        #[arg(long, help = "Full-text search query (required)")]
+        query: String,
+        #[arg(
+            long,
+            help = "Entity kind: service, queue, datastore, system, or custom"
+        )]
+        kind: Option<String>,
+        #[arg(long, default_value_t = 100, help = "Maximum number of entities")]
+        limit: i32,
+    },
+    /// List a service's upstream and downstream dependencies
+    Dependencies {
+        service_name: String,
+        #[arg(long, help = "upstream, downstream, or both (default: both)")]
+        direction: Option<String>,
+    },
+    /// Create a service definition, or update it if it already exists
+    #[command(name = "create-or-update", alias = "upsert")]
+    Upsert {
+        #[arg(long, help = "JSON file with the service definition (required)")]
+        file: String,
+    },
 }
 
 // ---- API Keys ----
@@ -2846,6 +3467,17 @@ enum UsageActions {
         #[arg(long, help = "End time (now, YYYY-MM-DD, or RFC3339)")]
         to: Option<String>,
     },
+    /// Get hourly custom metrics (timeseries) usage
+    Timeseries {
+        #[arg(
+            long,
+            default_value = "1d",
+            help = "Start time (1d, 7d, YYYY-MM-DD, or RFC3339)"
+        )]
+        from: String,
+        #[arg(long, help = "End time (now, YYYY-MM-DD, or RFC3339)")]
+        to: Option<String>,
+    },
 }
 
 // ---- Notebooks ----
@@ -2864,6 +3496,14 @@ enum NotebookActions {
         )]
         file: String,
     },
+    /// Create a new notebook by copying an existing one
+    #[command(name = "create-from-template")]
+    CreateFromTemplate {
+        #[arg(help = "ID of the notebook to copy")]
+        source_id: i64,
+        #[arg(long, help = "Name of the new notebook (required)")]
+        name: String,
+    },
     /// Update a notebook
     Update {
         notebook_id: i64,
@@ -2895,6 +3535,32 @@ enum RumActions {
         #[arg(long, default_value_t = 100)]
         limit: i32,
     },
+    /// Aggregate RUM events (e.g. page views by URL, P95 load time by browser)
+    Aggregate {
+        #[arg(long, help = "RUM search query (default: *)")]
+        query: Option<String>,
+        #[arg(
+            long,
+            default_value = "1h",
+            help = "Start time: 1h, 5min, 2hours, '5 minutes', RFC3339, Unix timestamp, or 'now'"
+        )]
+        from: String,
+        #[arg(long, default_value = "now", help = "End time")]
+        to: String,
+        #[arg(
+            long,
+            default_value = "count",
+            help = "Aggregation, optionally with a metric (e.g. count, pc95:@view.loading_time)"
+        )]
+        compute: String,
+        #[arg(
+            long,
+            help = "Facet(s) to group by, comma-separated (e.g. @view.url_path)"
+        )]
+        group_by: Option<String>,
+        #[arg(long, default_value_t = 10, help = "Maximum groups per facet")]
+        limit: i32,
+    },
     /// Query RUM session replay data
     Sessions {
         #[command(subcommand)]
@@ -2947,7 +3613,12 @@ enum RumAppActions {
         name: Option<String>,
         #[arg(long, name = "type", help = "Application type")]
         app_type: Option<String>,
-        #[arg(long)]
+        #[arg(
+            long,
+            conflicts_with_all = ["name", "type"],
+            required_unless_present_any = ["name", "type"],
+            help = "JSON file with the full update request"
+        )]
         file: Option<String>,
     },
     /// Delete a RUM application
@@ -2955,6 +3626,13 @@ enum RumAppActions {
         #[arg(help = "Application ID (required)")]
         app_id: String,
     },
+    /// Clone a RUM application under a new name
+    Clone {
+        #[arg(help = "Source application ID (required)")]
+        app_id: String,
+        #[arg(long, help = "Name for the new application (required)")]
+        name: String,
+    },
 }
 
 #[derive(Subcommand)]
@@ -2979,6 +3657,17 @@ enum RumSessionActions {
         #[arg(long, default_value_t = 100)]
         limit: i32,
     },
+    /// Export all matching sessions to a CSV file
+    Export {
+        #[arg(long)]
+        query: Option<String>,
+        #[arg(long, default_value = "1h")]
+        from: String,
+        #[arg(long, default_value = "now")]
+        to: String,
+        #[arg(long, help = "CSV file to write (required)")]
+        output_file: String,
+    },
 }
 
 #[derive(Subcommand)]
@@ -3140,6 +3829,20 @@ enum CicdTestActions {
         #[arg(long, default_value_t = 10, help = "Maximum groups")]
         limit: i32,
     },
+    /// Show the code coverage summary for a branch or commit
+    Coverage {
+        #[arg(long, help = "Repository ID, e.g. github.com/org/repo (required)")]
+        repository: String,
+        #[arg(
+            long,
+            required_unless_present = "commit",
+            conflicts_with = "commit",
+            help = "Branch to summarize"
+        )]
+        branch: Option<String>,
+        #[arg(long, help = "Commit SHA to summarize")]
+        commit: Option<String>,
+    },
 }
 
 #[derive(Subcommand)]
@@ -3183,6 +3886,30 @@ enum CicdDoraActions {
         #[arg(long, help = "JSON file with patch data (required)")]
         file: String,
     },
+    /// Report a deployment for DORA metrics
+    #[command(name = "create-deployment", alias = "deployment")]
+    Deployment {
+        #[arg(long, help = "Deployed service (required unless --file)")]
+        service: Option<String>,
+        #[arg(long, help = "Deployed version")]
+        version: Option<String>,
+        #[arg(long, requires = "commit_sha", help = "Git repository URL")]
+        repository_url: Option<String>,
+        #[arg(long, requires = "repository_url", help = "Deployed commit SHA")]
+        commit_sha: Option<String>,
+        #[arg(long, help = "Environment")]
+        env: Option<String>,
+        #[arg(long, help = "Start time in unix nanoseconds (required unless --file)")]
+        started_at: Option<i64>,
+        #[arg(long, help = "End time in unix nanoseconds (default: now)")]
+        finished_at: Option<i64>,
+        #[arg(
+            long,
+            conflicts_with_all = ["service", "version", "repository_url", "commit_sha", "env", "started_at", "finished_at"],
+            help = "JSON file with the full deployment request"
+        )]
+        file: Option<String>,
+    },
 }
 
 #[derive(Subcommand)]
@@ -3199,6 +3926,8 @@ enum CicdFlakyTestActions {
         include_history: bool,
         #[arg(long, help = "Sort order (fqn, -fqn)")]
         sort: Option<String>,
+        #[arg(long, default_value_t = false, help = "Fetch all pages")]
+        all: bool,
     },
     /// Update flaky tests
     Update {
@@ -3215,6 +3944,18 @@ enum OnCallActions {
         #[command(subcommand)]
         action: OnCallTeamActions,
     },
+    /// Manage on-call schedules
+    Schedules {
+        #[command(subcommand)]
+        action: OnCallScheduleActions,
+    },
+}
+
+#[derive(Subcommand)]
+enum OnCallScheduleActions {
+    /// Show who is currently on call for a schedule
+    #[command(name = "oncall-users", alias = "currently-on-call")]
+    CurrentlyOnCall { schedule_id: String },
 }
 
 #[derive(Subcommand)]
@@ -3222,7 +3963,13 @@ enum OnCallTeamActions {
     /// List all teams
     List,
     /// Get team details
-    Get { team_id: String },
+    Get {
+        team_id: String,
+        #[arg(long, help = "Include the team's links in the response")]
+        include_links: bool,
+        #[arg(long, help = "Include the team's memberships in the response")]
+        include_memberships: bool,
+    },
     /// Create a new team
     Create {
         #[arg(long, help = "Team display name (required)")]
@@ -3246,11 +3993,73 @@ enum OnCallTeamActions {
     },
     /// Delete a team
     Delete { team_id: String },
+    /// Show who is on call for a team right now
+    #[command(name = "get-on-call")]
+    GetOnCall { team_id: String },
     /// Manage team memberships
     Memberships {
         #[command(subcommand)]
         action: OnCallMembershipActions,
     },
+    /// Manage team notification rules
+    #[command(name = "notification-rules")]
+    NotificationRules {
+        #[command(subcommand)]
+        action: TeamNotificationRuleActions,
+    },
+    /// Manage team links (runbooks, dashboards, documentation)
+    Links {
+        #[command(subcommand)]
+        action: TeamLinkActions,
+    },
+}
+
+#[derive(Subcommand)]
+enum TeamLinkActions {
+    /// List links for a team
+    List { team_id: String },
+    /// Add a link to a team
+    Create {
+        team_id: String,
+        #[arg(long, help = "Link label (required)")]
+        label: String,
+        #[arg(long, help = "Link URL (required)")]
+        url: String,
+    },
+    /// Update a team link's label or URL
+    Update {
+        team_id: String,
+        link_id: String,
+        #[arg(long, help = "New link label")]
+        label: Option<String>,
+        #[arg(long, help = "New link URL")]
+        url: Option<String>,
+    },
+    /// Delete a team link
+    Delete { team_id: String, link_id: String },
+}
+
+#[derive(Subcommand)]
+enum TeamNotificationRuleActions {
+    /// List notification rules for a team
+    List { team_id: String },
+    /// Get notification rule details
+    Get { team_id: String, rule_id: String },
+    /// Create a notification rule from JSON file
+    Create {
+        team_id: String,
+        #[arg(long)]
+        file: String,
+    },
+    /// Update a notification rule from JSON file
+    Update {
+        team_id: String,
+        rule_id: String,
+        #[arg(long)]
+        file: String,
+    },
+    /// Delete a notification rule
+    Delete { team_id: String, rule_id: String },
 }
 
 #[derive(Subcommand)]
@@ -3315,6 +4124,42 @@ enum FleetAgentActions {
     Get { agent_key: String },
     /// List available agent versions
     Versions,
+    /// Set a datadog.yaml key on all agents matching a filter
+    #[command(name = "update-config")]
+    UpdateConfig {
+        #[arg(
+            long,
+            help = "Agent filter query (e.g. env:prod); omit to target every agent"
+        )]
+        filter_tags: Option<String>,
+        #[arg(
+            long,
+            help = "Config key, dotted for nesting (e.g. apm_config.enabled)"
+        )]
+        config_key: String,
+        #[arg(long, help = "Config value (parsed as JSON when possible)")]
+        config_value: String,
+    },
+    /// Set host tags on all agents matching a filter
+    ///
+    /// By default each agent's current tags are fetched and the new tags are
+    /// added to them, with one deployment per host. With --replace, a single
+    /// deployment sets exactly the given tags on every matching agent.
+    /// Either way `tags` in datadog.yaml is written and `extra_tags` removed.
+    #[command(name = "bulk-update-tags", verbatim_doc_comment)]
+    BulkUpdateTags {
+        #[arg(long, help = "Agent filter query (e.g. env:prod) (required)")]
+        filter_tags: String,
+        #[arg(
+            long,
+            required = true,
+            value_delimiter = ',',
+            help = "Tags to set (comma-separated, e.g. team:core,tier:1)"
+        )]
+        new_tags: Vec<String>,
+        #[arg(long, help = "Replace all existing tags instead of adding to them")]
+        replace: bool,
+    },
 }
 
 #[derive(Subcommand)]
@@ -3380,12 +4225,41 @@ enum DataGovScannerActions {
         #[command(subcommand)]
         action: DataGovScannerRuleActions,
     },
+    /// Manage scanning groups
+    Groups {
+        #[command(subcommand)]
+        action: DataGovScannerGroupActions,
+    },
 }
 
 #[derive(Subcommand)]
 enum DataGovScannerRuleActions {
     /// List scanning rules
     List,
+    /// Create a scanning rule
+    Create {
+        #[arg(long, help = "JSON file with request body (required)")]
+        file: String,
+    },
+    /// Update a scanning rule
+    Update {
+        rule_id: String,
+        #[arg(long, help = "JSON file with request body (required)")]
+        file: String,
+    },
+    /// Delete a scanning rule
+    Delete { rule_id: String },
+}
+
+#[derive(Subcommand)]
+enum DataGovScannerGroupActions {
+    /// List scanning groups
+    List,
+    /// Reorder scanning groups (all group IDs in the desired order)
+    Reorder {
+        #[arg(required = true, num_args = 1.., value_delimiter = ',')]
+        ids: Vec<String>,
+    },
 }
 
 // ---- Error Tracking ----
@@ -3423,6 +4297,41 @@ enum ErrorTrackingIssueActions {
     },
     /// Get issue details
     Get { issue_id: String },
+    /// Set the status of all issues matching a query (same as bulk-update)
+    #[command(name = "set-status")]
+    SetStatus {
+        #[arg(long, help = "Search query selecting the issues to update")]
+        query: String,
+        #[arg(
+            long,
+            help = "New status: open, acknowledged, resolved, ignored, excluded"
+        )]
+        status: String,
+        #[arg(long, default_value = "1d", help = "Start time (relative or absolute)")]
+        from: String,
+        #[arg(long, default_value = "now", help = "End time (relative or absolute)")]
+        to: String,
+    },
+    /// Update issue statuses from a JSON file of {"issue_id", "status"} objects
+    Bulk {
+        #[arg(long)]
+        file: String,
+    },
+    /// Set the state of all matching issues after confirming the count
+    #[command(name = "bulk-update")]
+    BulkUpdate {
+        #[arg(long, help = "Search query selecting the issues to update")]
+        query: String,
+        #[arg(
+            long,
+            help = "New state: open, acknowledged, resolved, ignored, excluded"
+        )]
+        state: String,
+        #[arg(long, default_value = "1d", help = "Start time (relative or absolute)")]
+        from: String,
+        #[arg(long, default_value = "now", help = "End time (relative or absolute)")]
+        to: String,
+    },
 }
 
 // ---- Code Coverage ----
@@ -3465,6 +4374,11 @@ enum HamrConnectionActions {
         #[arg(long, help = "JSON file with request body (required)")]
         file: String,
     },
+    /// Update HAMR organization connection (e.g. failover configuration)
+    Update {
+        #[arg(long, help = "JSON file with request body (required)")]
+        file: String,
+    },
 }
 
 // ---- Status Pages ----
@@ -3604,6 +4518,12 @@ enum IntegrationActions {
         #[command(subcommand)]
         action: SlackActions,
     },
+    /// Manage Microsoft Teams integration
+    #[command(name = "microsoft-teams", alias = "ms-teams")]
+    MicrosoftTeams {
+        #[command(subcommand)]
+        action: MsTeamsActions,
+    },
     /// Manage PagerDuty integration
     Pagerduty {
         #[command(subcommand)]
@@ -3739,18 +4659,149 @@ enum ServiceNowTemplateActions {
 enum SlackActions {
     /// List Slack channels
     List,
+    /// Manage Slack workspaces
+    Workspaces {
+        #[command(subcommand)]
+        action: SlackWorkspaceActions,
+    },
+    /// Manage Slack channel configurations
+    Channels {
+        #[command(subcommand)]
+        action: SlackChannelActions,
+    },
+}
+
+#[derive(Subcommand)]
+enum SlackWorkspaceActions {
+    /// List connected Slack workspaces
+    List,
+}
+
+#[derive(Subcommand)]
+enum SlackChannelActions {
+    /// List channel configurations in a workspace
+    List { workspace_name: String },
+    /// Get a channel configuration
+    Get {
+        workspace_name: String,
+        channel_name: String,
+    },
+    /// Create a channel configuration from JSON file
+    Create {
+        workspace_name: String,
+        #[arg(long, help = "JSON file with request body (required)")]
+        file: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum MsTeamsActions {
+    /// Show tenants with configured channels
+    Tenants {
+        #[command(subcommand)]
+        action: MsTeamsTenantActions,
+    },
+    /// Manage channel configurations (tenant-based handles)
+    Channels {
+        #[command(subcommand)]
+        action: MsTeamsChannelActions,
+    },
+}
+
+#[derive(Subcommand)]
+enum MsTeamsTenantActions {
+    /// List tenants with configured channels
+    List,
+    /// Get a tenant and its channel configurations
+    Get { tenant_id: String },
+}
+
+#[derive(Subcommand)]
+enum MsTeamsChannelActions {
+    /// List channel configurations
+    List {
+        #[arg(long, help = "Filter by tenant ID")]
+        tenant_id: Option<String>,
+        #[arg(long, help = "Filter by handle name")]
+        name: Option<String>,
+    },
+    /// Get a channel configuration
+    Get { handle_id: String },
+    /// Create a channel configuration from JSON file
+    Create {
+        #[arg(long, help = "JSON file with request body (required)")]
+        file: String,
+    },
+    /// Update a channel configuration from JSON file
+    Update {
+        handle_id: String,
+        #[arg(long, help = "JSON file with request body (required)")]
+        file: String,
+    },
+    /// Delete a channel configuration
+    Delete { handle_id: String },
 }
 
 #[derive(Subcommand)]
 enum PagerdutyActions {
     /// List PagerDuty services
     List,
+    /// Get a PagerDuty service
+    Get { service_name: String },
+    /// Create a PagerDuty service from JSON file
+    Create {
+        #[arg(long, help = "JSON file with service_name and service_key (required)")]
+        file: String,
+    },
+    /// Update a PagerDuty service key from JSON file
+    Update {
+        service_name: String,
+        #[arg(long, help = "JSON file with service_key (required)")]
+        file: String,
+    },
+    /// Delete a PagerDuty service
+    Delete { service_name: String },
+    /// Manage account-level PagerDuty configuration
+    Account {
+        #[command(subcommand)]
+        action: PagerdutyAccountActions,
+    },
+}
+
+#[derive(Subcommand)]
+enum PagerdutyAccountActions {
+    /// Get PagerDuty account configuration
+    Get,
+    /// Update PagerDuty account configuration from JSON file
+    Update {
+        #[arg(long, help = "JSON file with account configuration (required)")]
+        file: String,
+    },
 }
 
 #[derive(Subcommand)]
 enum WebhooksActions {
     /// List webhooks
     List,
+    /// Get webhook details
+    Get { webhook_name: String },
+    /// Create a webhook from flags or JSON file
+    Create {
+        #[arg(long, help = "JSON file with webhook definition")]
+        file: Option<String>,
+        #[arg(long, help = "Webhook name (required without --file)")]
+        name: Option<String>,
+        #[arg(long, help = "Webhook URL (required without --file)")]
+        url: Option<String>,
+    },
+    /// Update a webhook from JSON file
+    Update {
+        webhook_name: String,
+        #[arg(long, help = "JSON file with webhook fields to update (required)")]
+        file: String,
+    },
+    /// Delete a webhook
+    Delete { webhook_name: String },
 }
 
 // ---- Cost ----
@@ -3781,6 +4832,13 @@ enum CostActions {
         #[arg(long, help = "Tag keys for breakdown (required)")]
         fields: Option<String>,
     },
+    /// Forecast end-of-month cost per product
+    Forecast {
+        #[arg(long, help = "Only include products whose name contains this text")]
+        product: Option<String>,
+        #[arg(long, help = "Row granularity: product (default) or org")]
+        granularity: Option<String>,
+    },
 }
 
 // ---- Misc ----
@@ -3791,6 +4849,20 @@ enum MiscActions {
     IpRanges,
     /// Check API status
     Status,
+    /// Check that the configured API key is valid
+    #[command(name = "validate-api-key")]
+    ValidateApiKey,
+    /// Look up Datadog permissions
+    Permissions {
+        #[command(subcommand)]
+        action: MiscPermissionActions,
+    },
+}
+
+#[derive(Subcommand)]
+enum MiscPermissionActions {
+    /// List every permission with its ID (same as `pup users permissions list`)
+    List,
 }
 
 // ---- APM ----
@@ -3883,8 +4955,22 @@ enum ApmServiceActions {
         #[arg(long, help = "Peer service filter")]
         peer_service: Option<String>,
     },
-}
-
+    /// List all resources (endpoints) for a service across operations
+    #[command(name = "all-resources")]
+    AllResources {
+        #[arg(long, help = "Service name (required)")]
+        service: String,
+        #[arg(long, help = "Environment filter (required)")]
+        env: String,
+        #[arg(long, default_value = "1h", help = "Start time")]
+        from: String,
+        #[arg(long, default_value = "now", help = "End time")]
+        to: String,
+        #[arg(long, default_value_t = 100, help = "Maximum number of resources")]
+        limit: i32,
+    },
+}
+
 #[derive(Subcommand)]
 enum ApmEntityActions {
     /// Query APM entities
@@ -3906,6 +4992,17 @@ enum ApmEntityActions {
         #[arg(long, help = "Entity types (comma-separated)")]
         types: Option<String>,
     },
+    /// Search APM entities by name or tag
+    Search {
+        #[arg(long, help = "Search query, e.g. service:web-store (required)")]
+        query: String,
+        #[arg(long, default_value = "1h", help = "Start time")]
+        from: String,
+        #[arg(long, default_value = "now", help = "End time")]
+        to: String,
+        #[arg(long, default_value_t = 50, help = "Max results")]
+        limit: i32,
+    },
 }
 
 #[derive(Subcommand)]
@@ -3921,6 +5018,20 @@ enum ApmDependencyActions {
         #[arg(long, help = "Primary tag (group:value)")]
         primary_tag: Option<String>,
     },
+    /// Show the dependency graph around one service
+    #[command(name = "get-for-service")]
+    GetForService {
+        /// Service name
+        service: String,
+        #[arg(long, help = "Environment filter (required)")]
+        env: String,
+        #[arg(long, default_value = "1h", help = "Start time")]
+        from: String,
+        #[arg(long, default_value = "now", help = "End time")]
+        to: String,
+        #[arg(long, help = "Hops to follow from the service (default: 1)")]
+        depth: Option<u32>,
+    },
 }
 
 // ---- Investigations ----
@@ -3993,22 +5104,82 @@ enum NetworkDeviceActions {
     List,
 }
 
-// ---- Obs Pipelines (placeholder) ----
+// ---- Obs Pipelines ----
 #[derive(Subcommand)]
 enum ObsPipelinesActions {
     /// List observability pipelines
-    List,
+    List {
+        #[arg(long, default_value_t = 0, help = "Results per page")]
+        page_size: i64,
+        #[arg(long, default_value_t = 0, help = "Page number")]
+        page_number: i64,
+    },
     /// Get pipeline details
     Get { pipeline_id: String },
+    /// Create a pipeline from a JSON file
+    Create {
+        #[arg(long)]
+        file: String,
+    },
+    /// Update a pipeline from a JSON file
+    Update {
+        pipeline_id: String,
+        #[arg(long)]
+        file: String,
+    },
+    /// Delete a pipeline
+    Delete { pipeline_id: String },
+    /// Validate a pipeline configuration without creating it
+    Validate {
+        #[arg(long)]
+        file: String,
+    },
 }
 
 // ---- Scorecards (placeholder) ----
 #[derive(Subcommand)]
 enum ScorecardsActions {
     /// List scorecards
-    List,
+    List {
+        #[arg(long, default_value_t = 100, help = "Maximum number of scorecards")]
+        limit: usize,
+        #[arg(long, help = "Embed rule objects in the response")]
+        include_rules: bool,
+    },
     /// Get scorecard details
-    Get { scorecard_id: String },
+    Get {
+        scorecard_id: String,
+        #[arg(long, help = "Embed rule objects in the response")]
+        include_rules: bool,
+    },
+    /// Manage scorecard rules
+    Rules {
+        #[command(subcommand)]
+        action: ScorecardRuleActions,
+    },
+    /// View scorecard rule outcomes
+    Outcomes {
+        #[command(subcommand)]
+        action: ScorecardOutcomeActions,
+    },
+}
+
+#[derive(Subcommand)]
+enum ScorecardRuleActions {
+    /// List scorecard rules
+    List {
+        #[arg(long, help = "Filter rules by name")]
+        filter_query: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum ScorecardOutcomeActions {
+    /// List rule outcomes
+    List {
+        #[arg(long, help = "Filter outcomes by service name")]
+        service_name: Option<String>,
+    },
 }
 
 // ---- Traces ----
@@ -4137,7 +5308,7 @@ enum AliasActions {
 // ---- Product Analytics ----
 #[derive(Subcommand)]
 enum ProductAnalyticsActions {
-    /// Send product analytics events
+    /// Send and query product analytics events
     Events {
         #[command(subcommand)]
         action: ProductAnalyticsEventActions,
@@ -4159,6 +5330,39 @@ enum ProductAnalyticsEventActions {
         #[arg(long, name = "user-id", help = "User ID")]
         user_id: Option<String>,
     },
+    /// List recent product analytics events for an application
+    List {
+        #[arg(long, help = "Application ID (required)")]
+        app_id: String,
+        #[arg(long, default_value = "1h", help = "Start time")]
+        from: String,
+        #[arg(long, default_value = "now", help = "End time")]
+        to: String,
+        #[arg(long, default_value_t = 100, help = "Maximum events to return")]
+        limit: i32,
+    },
+    /// Aggregate product analytics events for an application
+    Aggregate {
+        #[arg(long, help = "Application ID (required)")]
+        app_id: String,
+        #[arg(long, help = "Additional search query (e.g. @type:action)")]
+        query: Option<String>,
+        #[arg(long, default_value = "1h", help = "Start time")]
+        from: String,
+        #[arg(long, default_value = "now", help = "End time")]
+        to: String,
+        #[arg(
+            long,
+            default_value = "count",
+            help = "Aggregation, optionally with a metric (e.g. count, cardinality:@usr.id)"
+        )]
+        compute: String,
+        #[arg(
+            long,
+            help = "Facet(s) to group by, comma-separated (e.g. @action.name)"
+        )]
+        group_by: Option<String>,
+    },
 }
 
 // ---- Static Analysis ----
@@ -4189,23 +5393,8 @@ enum StaticAnalysisActions {
 
 #[derive(Subcommand)]
 enum StaticAnalysisAstActions {
-    /// List AST analyses
-    List {
-        #[arg(long, help = "Filter by branch")]
-        branch: Option<String>,
-        #[arg(long, help = "Start time")]
-        from: Option<String>,
-        #[arg(long, help = "End time")]
-        to: Option<String>,
-        #[arg(long, help = "Filter by repository")]
-        repository: Option<String>,
-        #[arg(long, help = "Filter by language")]
-        language: Option<String>,
-        #[arg(long, help = "Filter by severity")]
-        severity: Option<String>,
-        #[arg(long, help = "Filter by status")]
-        status: Option<String>,
-    },
+    /// List AST analyses (placeholder)
+    List,
     /// Get AST analysis details
     Get { id: String },
 }
@@ -4283,7 +5472,13 @@ enum StaticAnalysisCoverageActions {
 #[derive(Subcommand)]
 enum AuthActions {
     /// Login via OAuth2
-    Login,
+    Login {
+        #[arg(
+            long,
+            help = "Print the authorization URL instead of opening a browser (for SSH/headless sessions)"
+        )]
+        no_browser: bool,
+    },
     /// Logout and clear tokens
     Logout,
     /// Check authentication status
@@ -4292,6 +5487,15 @@ enum AuthActions {
     Token,
     /// Refresh access token
     Refresh,
+    /// Revoke the current OAuth token and clear stored credentials
+    #[command(name = "expire-token")]
+    ExpireToken {
+        #[arg(
+            long,
+            help = "Remove local credentials even if server-side revocation fails"
+        )]
+        force: bool,
+    },
 }
 
 // ---- Agent-mode JSON schema for --help ----
@@ -4745,6 +5949,12 @@ async fn main_inner() -> anyhow::Result<()> {
     if cli.yes {
         cfg.auto_approve = true;
     }
+    if cli.no_truncate {
+        cfg.table_max_width = None;
+    }
+    if cli.no_rate_limit_warning {
+        cfg.rate_limit_warning = false;
+    }
     cfg.agent_mode = cli.agent || useragent::is_agent_mode();
     if cfg.agent_mode {
         cfg.auto_approve = true;
@@ -4755,12 +5965,43 @@ async fn main_inner() -> anyhow::Result<()> {
         Commands::Monitors { action } => {
             cfg.validate_auth()?;
             match action {
-                MonitorActions::List { name, tags, limit } => {
-                    commands::monitors::list(&cfg, name, tags, limit).await?;
+                MonitorActions::List {
+                    name,
+                    tags,
+                    limit,
+                    sort,
+                    json_path,
+                    count,
+                    exit_code,
+                    exit_on_state,
+                    no_deleted,
+                } => {
+                    let exit_on_state = exit_on_state.or(exit_code.then(|| "Alert".to_string()));
+                    let filter = commands::monitors::MonitorFilter {
+                        name,
+                        tags,
+                        no_deleted,
+                    };
+                    if count {
+                        commands::monitors::count(&cfg, &filter, exit_on_state.as_deref()).await?;
+                    } else {
+                        commands::monitors::list(
+                            &cfg,
+                            &filter,
+                            limit,
+                            sort.as_deref(),
+                            json_path.as_deref(),
+                            exit_on_state.as_deref(),
+                        )
+                        .await?;
+                    }
                 }
                 MonitorActions::Get { monitor_id } => {
                     commands::monitors::get(&cfg, monitor_id).await?;
                 }
+                MonitorActions::BulkMute { tags, end, message } => {
+                    commands::monitors::bulk_mute(&cfg, tags, end, message).await?;
+                }
                 MonitorActions::Create { file } => {
                     commands::monitors::create(&cfg, &file).await?;
                 }
@@ -4773,6 +6014,9 @@ async fn main_inner() -> anyhow::Result<()> {
                 MonitorActions::Delete { monitor_id } => {
                     commands::monitors::delete(&cfg, monitor_id).await?;
                 }
+                MonitorActions::CheckCanDelete { monitor_ids } => {
+                    commands::monitors::check_can_delete(&cfg, &monitor_ids).await?;
+                }
             }
         }
         // --- Logs ---
@@ -4784,32 +6028,53 @@ async fn main_inner() -> anyhow::Result<()> {
                     from,
                     to,
                     limit,
-                    sort: _,
-                    index: _,
-                    storage: _,
+                    sort,
+                    index,
+                    storage,
+                    stream,
                 } => {
-                    commands::logs::search(&cfg, query, from, to, limit).await?;
+                    let opts = commands::logs::LogSearchOptions {
+                        storage,
+                        index,
+                        sort: Some(sort),
+                    };
+                    if stream {
+                        commands::logs::search_stream(&cfg, query, from, to, limit, &opts).await?;
+                    } else {
+                        commands::logs::search(&cfg, query, from, to, limit, &opts).await?;
+                    }
                 }
                 LogActions::List {
                     query,
                     from,
                     to,
                     limit,
-                    sort: _,
-                    storage: _,
+                    sort,
+                    index,
+                    storage,
                 } => {
-                    commands::logs::list(&cfg, query, from, to, limit).await?;
+                    let opts = commands::logs::LogSearchOptions {
+                        storage,
+                        index,
+                        sort: Some(sort),
+                    };
+                    commands::logs::list(&cfg, query, from, to, limit, &opts).await?;
                 }
                 LogActions::Query {
                     query,
                     from,
                     to,
                     limit,
-                    sort: _,
-                    storage: _,
+                    sort,
+                    storage,
                     timezone: _,
                 } => {
-                    commands::logs::query(&cfg, query, from, to, limit).await?;
+                    let opts = commands::logs::LogSearchOptions {
+                        storage,
+                        sort: Some(sort),
+                        ..Default::default()
+                    };
+                    commands::logs::query(&cfg, query, from, to, limit, &opts).await?;
                 }
                 LogActions::Aggregate {
                     query,
@@ -4830,6 +6095,19 @@ async fn main_inner() -> anyhow::Result<()> {
                     LogArchiveActions::Delete { archive_id } => {
                         commands::logs::archives_delete(&cfg, &archive_id).await?;
                     }
+                    LogArchiveActions::Order => commands::logs::archives_order_get(&cfg).await?,
+                    LogArchiveActions::Reorder { ids } => {
+                        commands::logs::archives_reorder(&cfg, ids).await?;
+                    }
+                    LogArchiveActions::Rehydrate {
+                        archive_id,
+                        from,
+                        to,
+                        query,
+                    } => {
+                        commands::logs::archives_rehydrate(&cfg, &archive_id, from, to, query)
+                            .await?;
+                    }
                 },
                 LogActions::CustomDestinations { action } => match action {
                     LogCustomDestinationActions::List => {
@@ -4855,6 +6133,41 @@ async fn main_inner() -> anyhow::Result<()> {
                     LogRestrictionQueryActions::Get { query_id } => {
                         commands::logs::restriction_queries_get(&cfg, &query_id).await?;
                     }
+                    LogRestrictionQueryActions::Create { file } => {
+                        commands::logs::restriction_queries_create(&cfg, &file).await?;
+                    }
+                    LogRestrictionQueryActions::Update { query_id, file } => {
+                        commands::logs::restriction_queries_update(&cfg, &query_id, &file).await?;
+                    }
+                    LogRestrictionQueryActions::Delete { query_id } => {
+                        commands::logs::restriction_queries_delete(&cfg, &query_id).await?;
+                    }
+                    LogRestrictionQueryActions::AddRole { query_id, role_id } => {
+                        commands::logs::restriction_queries_add_role(&cfg, &query_id, &role_id)
+                            .await?;
+                    }
+                    LogRestrictionQueryActions::RemoveRole { query_id, role_id } => {
+                        commands::logs::restriction_queries_remove_role(&cfg, &query_id, &role_id)
+                            .await?;
+                    }
+                },
+                LogActions::Pipelines { action } => match action {
+                    LogPipelineActions::List => commands::logs::pipelines_list(&cfg).await?,
+                    LogPipelineActions::Get { pipeline_id } => {
+                        commands::logs::pipelines_get(&cfg, &pipeline_id).await?;
+                    }
+                    LogPipelineActions::Create { file } => {
+                        commands::logs::pipelines_create(&cfg, &file).await?;
+                    }
+                    LogPipelineActions::Update { pipeline_id, file } => {
+                        commands::logs::pipelines_update(&cfg, &pipeline_id, &file).await?;
+                    }
+                    LogPipelineActions::Delete { pipeline_id } => {
+                        commands::logs::pipelines_delete(&cfg, &pipeline_id).await?;
+                    }
+                    LogPipelineActions::Order { file } => {
+                        commands::logs::pipelines_order(&cfg, &file).await?;
+                    }
                 },
             }
         }
@@ -4921,16 +6234,48 @@ async fn main_inner() -> anyhow::Result<()> {
                             .await?;
                     }
                 },
+                IncidentActions::Integrations { action } => match action {
+                    IncidentIntegrationActions::List { incident_id } => {
+                        commands::incidents::integrations_list(&cfg, &incident_id).await?;
+                    }
+                    IncidentIntegrationActions::Create { incident_id, file } => {
+                        commands::incidents::integrations_create(&cfg, &incident_id, &file).await?;
+                    }
+                },
             }
         }
         // --- Dashboards ---
         Commands::Dashboards { action } => {
             cfg.validate_auth()?;
             match action {
-                DashboardActions::List => commands::dashboards::list(&cfg).await?,
+                DashboardActions::List { with_widgets } => {
+                    commands::dashboards::list(&cfg, with_widgets).await?;
+                }
+                DashboardActions::Search { query } => {
+                    commands::dashboards::search(&cfg, &query).await?;
+                }
                 DashboardActions::Get { id } => commands::dashboards::get(&cfg, &id).await?,
-                DashboardActions::Create { file } => {
-                    commands::dashboards::create(&cfg, &file).await?;
+                DashboardActions::Create {
+                    file,
+                    title,
+                    description,
+                    layout_type,
+                    template_variables,
+                } => {
+                    if let Some(f) = file {
+                        commands::dashboards::create(&cfg, &f).await?;
+                    } else {
+                        let Some(title) = title else {
+                            anyhow::bail!("--title is required without --file");
+                        };
+                        let body = commands::dashboards::dashboard_body(
+                            &title,
+                            description.as_deref(),
+                            &layout_type,
+                            template_variables.as_deref(),
+                        )?;
+                        commands::dashboards::create_payload(&cfg, body).await?;
+                    }
                 }
                 DashboardActions::Update { id, file } => {
                     commands::dashboards::update(&cfg, &id, &file).await?;
@@ -4948,17 +6293,49 @@ async fn main_inner() -> anyhow::Result<()> {
                 MetricActions::Search { query, from, to } => {
                     commands::metrics::search(&cfg, query, from, to).await?;
                 }
-                MetricActions::Query { query, from, to } => {
-                    commands::metrics::query(&cfg, query, from, to).await?;
+                MetricActions::Query {
+                    query,
+                    from,
+                    to,
+                    raw,
+                } => {
+                    commands::metrics::query(&cfg, query, from, to, raw).await?;
                 }
-                MetricActions::Submit { file, .. } => {
-                    if let Some(f) = file {
+                MetricActions::Submit {
+                    name,
+                    value,
+                    tags,
+                    r#type,
+                    host,
+                    interval,
+                    timestamp,
+                    file,
+                    many,
+                } => {
+                    if let (Some(f), true) = (&file, many) {
+                        commands::metrics::submit_many(&cfg, f).await?;
+                    } else if let Some(f) = file {
                         commands::metrics::submit(&cfg, &f).await?;
                     } else {
-                        anyhow::bail!("flag-based submit not yet implemented; use --file");
+                        let (Some(name), Some(value)) = (name, value) else {
+                            anyhow::bail!("--name and --value are required without --file");
+                        };
+                        let body = commands::metrics::series_payload(
+                            &name,
+                            value,
+                            tags.as_deref(),
+                            &r#type,
+                            host.as_deref(),
+                            interval,
+                            timestamp.as_deref(),
+                        )?;
+                        commands::metrics::submit_payload(&cfg, body).await?;
                     }
                 }
                 MetricActions::Metadata { action } => match action {
+                    MetricMetadataActions::List { filter, from } => {
+                        commands::metrics::metadata_list(&cfg, filter, from).await?;
+                    }
                     MetricMetadataActions::Get { metric_name } => {
                         commands::metrics::metadata_get(&cfg, &metric_name).await?;
                     }
@@ -4988,6 +6365,9 @@ async fn main_inner() -> anyhow::Result<()> {
                 SloActions::List => commands::slos::list(&cfg).await?,
                 SloActions::Get { id } => commands::slos::get(&cfg, &id).await?,
                 SloActions::Create { file } => commands::slos::create(&cfg, &file).await?,
+                SloActions::BulkCreate { file, dry_run } => {
+                    commands::slos::bulk_create(&cfg, &file, dry_run).await?;
+                }
                 SloActions::Update { id, file } => {
                     commands::slos::update(&cfg, &id, &file).await?;
                 }
@@ -4997,6 +6377,16 @@ async fn main_inner() -> anyhow::Result<()> {
                     let to_ts = util::parse_time_to_unix_millis(&to)? / 1000;
                     commands::slos::status(&cfg, &id, from_ts, to_ts).await?;
                 }
+                SloActions::History {
+                    id,
+                    from,
+                    to,
+                    target,
+                } => {
+                    let from_ts = util::parse_time_to_unix(&from)?;
+                    let to_ts = util::parse_time_to_unix(&to)?;
+                    commands::slos::history(&cfg, &id, from_ts, to_ts, target).await?;
+                }
             }
         }
         // --- Synthetics ---
@@ -5013,6 +6403,12 @@ async fn main_inner() -> anyhow::Result<()> {
                     SyntheticsTestActions::Search { text, count, start } => {
                         commands::synthetics::tests_search(&cfg, text, count, start).await?;
                     }
+                    SyntheticsTestActions::Pause { public_id } => {
+                        commands::synthetics::tests_pause(&cfg, &public_id).await?;
+                    }
+                    SyntheticsTestActions::Resume { public_id } => {
+                        commands::synthetics::tests_resume(&cfg, &public_id).await?;
+                    }
                 },
                 SyntheticsActions::Locations { action } => match action {
                     SyntheticsLocationActions::List => {
@@ -5097,6 +6493,21 @@ async fn main_inner() -> anyhow::Result<()> {
                 UserActions::Get { user_id } => commands::users::get(&cfg, &user_id).await?,
                 UserActions::Roles { action } => match action {
                     UserRoleActions::List => commands::users::roles_list(&cfg).await?,
+                    UserRoleActions::Assign { user_id, role_id } => {
+                        commands::users::roles_assign(&cfg, &user_id, &role_id).await?
+                    }
+                    UserRoleActions::Revoke { user_id, role_id } => {
+                        commands::users::roles_revoke(&cfg, &user_id, &role_id).await?
+                    }
+                },
+                UserActions::Permissions { action } => match action {
+                    UserPermissionActions::List => commands::users::permissions_list(&cfg).await?,
+                    UserPermissionActions::Assign {
+                        role_id,
+                        permission_id,
+                    } => {
+                        commands::users::permissions_assign(&cfg, &role_id, &permission_id).await?
+                    }
                 },
             }
         }
@@ -5116,6 +6527,19 @@ async fn main_inner() -> anyhow::Result<()> {
                         commands::infrastructure::hosts_get(&cfg, &hostname).await?;
                     }
                 },
+                InfraActions::Containers { action } => match action {
+                    InfraContainerActions::List {
+                        filter,
+                        sort,
+                        page_size,
+                    } => {
+                        commands::infrastructure::containers_list(&cfg, filter, sort, page_size)
+                            .await?;
+                    }
+                    InfraContainerActions::Get { container_id } => {
+                        commands::infrastructure::containers_get(&cfg, &container_id).await?;
+                    }
+                },
             }
         }
         // --- Audit Logs ---
@@ -5160,6 +6584,14 @@ async fn main_inner() -> anyhow::Result<()> {
                     } => {
                         commands::security::signals_search(&cfg, query, from, to, limit).await?;
                     }
+                    SecuritySignalActions::Correlate {
+                        signal_ids,
+                        title,
+                        file,
+                    } => {
+                        commands::security::signals_correlate(&cfg, signal_ids, title, file)
+                            .await?;
+                    }
                 },
                 SecurityActions::Findings { action } => match action {
                     SecurityFindingActions::Search { query, limit } => {
@@ -5170,6 +6602,9 @@ async fn main_inner() -> anyhow::Result<()> {
                     SecurityContentPackActions::List => {
                         commands::security::content_packs_list(&cfg).await?;
                     }
+                    SecurityContentPackActions::Get { pack_id } => {
+                        commands::security::content_packs_get(&cfg, &pack_id).await?;
+                    }
                     SecurityContentPackActions::Activate { pack_id } => {
                         commands::security::content_packs_activate(&cfg, &pack_id).await?;
                     }
@@ -5181,6 +6616,9 @@ async fn main_inner() -> anyhow::Result<()> {
                     SecurityRiskScoreActions::List { query } => {
                         commands::security::risk_scores_list(&cfg, query).await?;
                     }
+                    SecurityRiskScoreActions::Get { entity_id } => {
+                        commands::security::risk_scores_get(&cfg, &entity_id).await?;
+                    }
                 },
             }
         }
@@ -5190,6 +6628,21 @@ async fn main_inner() -> anyhow::Result<()> {
             match action {
                 OrgActions::List => commands::organizations::list(&cfg).await?,
                 OrgActions::Get => commands::organizations::get(&cfg).await?,
+                OrgActions::Settings { action } => match action {
+                    OrgSettingsActions::List => {
+                        commands::organizations::settings_list(&cfg).await?
+                    }
+                    OrgSettingsActions::Get { setting_name } => {
+                        commands::organizations::settings_get(&cfg, &setting_name).await?;
+                    }
+                    OrgSettingsActions::Update {
+                        setting_name,
+                        value,
+                    } => {
+                        commands::organizations::settings_update(&cfg, &setting_name, &value)
+                            .await?;
+                    }
+                },
             }
         }
         // --- Cloud ---
@@ -5198,12 +6651,45 @@ async fn main_inner() -> anyhow::Result<()> {
             match action {
                 CloudActions::Aws { action } => match action {
                     CloudAwsActions::List => commands::cloud::aws_list(&cfg).await?,
+                    CloudAwsActions::Get { account_id } => {
+                        commands::cloud::aws_get(&cfg, &account_id).await?;
+                    }
+                    CloudAwsActions::Create { file } => {
+                        commands::cloud::aws_create(&cfg, &file).await?;
+                    }
+                    CloudAwsActions::Update { account_id, file } => {
+                        commands::cloud::aws_update(&cfg, &account_id, &file).await?;
+                    }
+                    CloudAwsActions::Delete { account_id } => {
+                        commands::cloud::aws_delete(&cfg, &account_id).await?;
+                    }
                 },
                 CloudActions::Gcp { action } => match action {
                     CloudGcpActions::List => commands::cloud::gcp_list(&cfg).await?,
+                    CloudGcpActions::Create { file } => {
+                        commands::cloud::gcp_create(&cfg, &file).await?;
+                    }
+                    CloudGcpActions::Delete {
+                        account_id,
+                        client_email,
+                    } => {
+                        commands::cloud::gcp_delete(&cfg, &account_id, &client_email).await?;
+                    }
                 },
                 CloudActions::Azure { action } => match action {
                     CloudAzureActions::List => commands::cloud::azure_list(&cfg).await?,
+                    CloudAzureActions::Create { file } => {
+                        commands::cloud::azure_create(&cfg, &file).await?;
+                    }
+                    CloudAzureActions::Update { tenant_name, file } => {
+                        commands::cloud::azure_update(&cfg, &tenant_name, &file).await?;
+                    }
+                    CloudAzureActions::Delete {
+                        tenant_name,
+                        client_id,
+                    } => {
+                        commands::cloud::azure_delete(&cfg, &tenant_name, &client_id).await?;
+                    }
                 },
                 CloudActions::Oci { action } => match action {
                     CloudOciActions::Tenancies { action } => match action {
@@ -5276,6 +6762,16 @@ async fn main_inner() -> anyhow::Result<()> {
                 CaseActions::UpdateStatus { case_id, status } => {
                     commands::cases::update_status(&cfg, &case_id, &status).await?;
                 }
+                CaseActions::BulkUpdateStatus {
+                    mut case_ids,
+                    status,
+                    file,
+                } => {
+                    if let Some(f) = file {
+                        case_ids.extend(commands::cases::read_case_ids_file(&f)?);
+                    }
+                    commands::cases::bulk_update_status(&cfg, case_ids, &status).await?;
+                }
                 CaseActions::Move {
                     case_id,
                     project_id,
@@ -5364,6 +6860,18 @@ async fn main_inner() -> anyhow::Result<()> {
                 ServiceCatalogActions::Get { service_name } => {
                     commands::service_catalog::get(&cfg, &service_name).await?;
                 }
+                ServiceCatalogActions::Search { query, kind, limit } => {
+                    commands::service_catalog::search(&cfg, query, kind, limit).await?;
+                }
+                ServiceCatalogActions::Dependencies {
+                    service_name,
+                    direction,
+                } => {
+                    commands::service_catalog::dependencies(&cfg, &service_name, direction).await?;
+                }
+                ServiceCatalogActions::Upsert { file } => {
+                    commands::service_catalog::upsert(&cfg, &file).await?;
+                }
             }
         }
         // --- API Keys ---
@@ -5418,6 +6926,9 @@ async fn main_inner() -> anyhow::Result<()> {
                 UsageActions::Hourly { from, to } => {
                     commands::usage::hourly(&cfg, from, to).await?;
                 }
+                UsageActions::Timeseries { from, to } => {
+                    commands::usage::timeseries(&cfg, from, to).await?;
+                }
             }
         }
         // --- Notebooks ---
@@ -5431,6 +6942,9 @@ async fn main_inner() -> anyhow::Result<()> {
                 NotebookActions::Create { file } => {
                     commands::notebooks::create(&cfg, &file).await?;
                 }
+                NotebookActions::CreateFromTemplate { source_id, name } => {
+                    commands::notebooks::create_from_template(&cfg, source_id, &name).await?;
+                }
                 NotebookActions::Update { notebook_id, file } => {
                     commands::notebooks::update(&cfg, notebook_id, &file).await?;
                 }
@@ -5449,17 +6963,40 @@ async fn main_inner() -> anyhow::Result<()> {
                     RumAppActions::Create { name, app_type } => {
                         commands::rum::apps_create(&cfg, &name, app_type).await?;
                     }
-                    RumAppActions::Update { app_id, file, .. } => {
-                        let f = file.unwrap_or_default();
-                        commands::rum::apps_update(&cfg, &app_id, &f).await?;
-                    }
+                    RumAppActions::Update {
+                        app_id,
+                        name,
+                        app_type,
+                        file,
+                    } => match file {
+                        Some(f) => commands::rum::apps_update(&cfg, &app_id, &f).await?,
+                        None => {
+                            commands::rum::apps_update_fields(&cfg, &app_id, name, app_type).await?
+                        }
+                    },
                     RumAppActions::Delete { app_id } => {
                         commands::rum::apps_delete(&cfg, &app_id).await?;
                     }
+                    RumAppActions::Clone { app_id, name } => {
+                        commands::rum::apps_clone(&cfg, &app_id, &name).await?;
+                    }
                 },
                 RumActions::Events { from, to, limit } => {
                     commands::rum::events_list(&cfg, from, to, limit).await?;
                 }
+                RumActions::Aggregate {
+                    query,
+                    from,
+                    to,
+                    compute,
+                    group_by,
+                    limit,
+                } => {
+                    commands::rum::events_aggregate(
+                        &cfg, query, from, to, compute, group_by, limit,
+                    )
+                    .await?;
+                }
                 RumActions::Sessions { action } => match action {
                     RumSessionActions::Search {
                         query,
@@ -5472,6 +7009,14 @@ async fn main_inner() -> anyhow::Result<()> {
                     RumSessionActions::List { from, to, limit } => {
                         commands::rum::sessions_list(&cfg, from, to, limit).await?;
                     }
+                    RumSessionActions::Export {
+                        query,
+                        from,
+                        to,
+                        output_file,
+                    } => {
+                        commands::rum::sessions_export(&cfg, query, from, to, &output_file).await?;
+                    }
                 },
                 RumActions::Metrics { action } => match action {
                     RumMetricActions::List => commands::rum::metrics_list(&cfg).await?,
@@ -5563,6 +7108,21 @@ async fn main_inner() -> anyhow::Result<()> {
                     } => {
                         commands::cicd::tests_aggregate(&cfg, query, from, to).await?;
                     }
+                    CicdTestActions::Coverage {
+                        repository,
+                        branch,
+                        commit,
+                    } => match branch {
+                        Some(branch) => {
+                            commands::code_coverage::branch_summary(&cfg, repository, branch)
+                                .await?;
+                        }
+                        None => {
+                            let commit = commit.unwrap_or_default();
+                            commands::code_coverage::commit_summary(&cfg, repository, commit)
+                                .await?;
+                        }
+                    },
                 },
                 CicdActions::Events { action } => match action {
                     CicdEventActions::Search {
@@ -5587,10 +7147,47 @@ async fn main_inner() -> anyhow::Result<()> {
                     } => {
                         commands::cicd::dora_patch_deployment(&cfg, &deployment_id, &file).await?;
                     }
+                    CicdDoraActions::Deployment {
+                        service,
+                        version,
+                        repository_url,
+                        commit_sha,
+                        env,
+                        started_at,
+                        finished_at,
+                        file,
+                    } => {
+                        let args = commands::cicd::DoraDeploymentArgs {
+                            service,
+                            version,
+                            repository_url,
+                            commit_sha,
+                            env,
+                            started_at,
+                            finished_at,
+                        };
+                        commands::cicd::dora_create_deployment(&cfg, args, file).await?;
+                    }
                 },
                 CicdActions::FlakyTests { action } => match action {
-                    CicdFlakyTestActions::Search { query, .. } => {
-                        commands::cicd::flaky_tests_search(&cfg, query).await?;
+                    CicdFlakyTestActions::Search {
+                        query,
+                        cursor,
+                        limit,
+                        include_history,
+                        sort,
+                        all,
+                    } => {
+                        commands::cicd::flaky_tests_search(
+                            &cfg,
+                            query,
+                            cursor,
+                            limit,
+                            include_history,
+                            sort,
+                            all,
+                        )
+                        .await?;
                     }
                     CicdFlakyTestActions::Update { file } => {
                         commands::cicd::flaky_tests_update(&cfg, &file).await?;
@@ -5604,8 +7201,18 @@ async fn main_inner() -> anyhow::Result<()> {
             match action {
                 OnCallActions::Teams { action } => match action {
                     OnCallTeamActions::List => commands::on_call::teams_list(&cfg).await?,
-                    OnCallTeamActions::Get { team_id } => {
-                        commands::on_call::teams_get(&cfg, &team_id).await?;
+                    OnCallTeamActions::Get {
+                        team_id,
+                        include_links,
+                        include_memberships,
+                    } => {
+                        commands::on_call::teams_get(
+                            &cfg,
+                            &team_id,
+                            include_links,
+                            include_memberships,
+                        )
+                        .await?;
                     }
                     OnCallTeamActions::Create { name, handle, .. } => {
                         commands::on_call::teams_create(&cfg, &name, &handle).await?;
@@ -5620,6 +7227,9 @@ async fn main_inner() -> anyhow::Result<()> {
                     OnCallTeamActions::Delete { team_id } => {
                         commands::on_call::teams_delete(&cfg, &team_id).await?;
                     }
+                    OnCallTeamActions::GetOnCall { team_id } => {
+                        commands::on_call::teams_get_on_call(&cfg, &team_id).await?;
+                    }
                     OnCallTeamActions::Memberships { action } => match action {
                         OnCallMembershipActions::List {
                             team_id, page_size, ..
@@ -5646,6 +7256,62 @@ async fn main_inner() -> anyhow::Result<()> {
                             commands::on_call::memberships_remove(&cfg, &team_id, &user_id).await?;
                         }
                     },
+                    OnCallTeamActions::NotificationRules { action } => match action {
+                        TeamNotificationRuleActions::List { team_id } => {
+                            commands::on_call::notification_rules_list(&cfg, &team_id).await?;
+                        }
+                        TeamNotificationRuleActions::Get { team_id, rule_id } => {
+                            commands::on_call::notification_rules_get(&cfg, &team_id, &rule_id)
+                                .await?;
+                        }
+                        TeamNotificationRuleActions::Create { team_id, file } => {
+                            commands::on_call::notification_rules_create(&cfg, &team_id, &file)
+                                .await?;
+                        }
+                        TeamNotificationRuleActions::Update {
+                            team_id,
+                            rule_id,
+                            file,
+                        } => {
+                            commands::on_call::notification_rules_update(
+                                &cfg, &team_id, &rule_id, &file,
+                            )
+                            .await?;
+                        }
+                        TeamNotificationRuleActions::Delete { team_id, rule_id } => {
+                            commands::on_call::notification_rules_delete(&cfg, &team_id, &rule_id)
+                                .await?;
+                        }
+                    },
+                    OnCallTeamActions::Links { action } => match action {
+                        TeamLinkActions::List { team_id } => {
+                            commands::on_call::links_list(&cfg, &team_id).await?;
+                        }
+                        TeamLinkActions::Create {
+                            team_id,
+                            label,
+                            url,
+                        } => {
+                            commands::on_call::links_create(&cfg, &team_id, &label, &url).await?;
+                        }
+                        TeamLinkActions::Update {
+                            team_id,
+                            link_id,
+                            label,
+                            url,
+                        } => {
+                            commands::on_call::links_update(&cfg, &team_id, &link_id, label, url)
+                                .await?;
+                        }
+                        TeamLinkActions::Delete { team_id, link_id } => {
+                            commands::on_call::links_delete(&cfg, &team_id, &link_id).await?;
+                        }
+                    },
+                },
+                OnCallActions::Schedules { action } => match action {
+                    OnCallScheduleActions::CurrentlyOnCall { schedule_id } => {
+                        commands::on_call::schedules_on_call_users(&cfg, &schedule_id).await?;
+                    }
                 },
             }
         }
@@ -5661,6 +7327,32 @@ async fn main_inner() -> anyhow::Result<()> {
                         commands::fleet::agents_get(&cfg, &agent_key).await?;
                     }
                     FleetAgentActions::Versions => commands::fleet::agents_versions(&cfg).await?,
+                    FleetAgentActions::BulkUpdateTags {
+                        filter_tags,
+                        new_tags,
+                        replace,
+                    } => {
+                        commands::fleet::agents_bulk_update_tags(
+                            &cfg,
+                            &filter_tags,
+                            &new_tags,
+                            replace,
+                        )
+                        .await?;
+                    }
+                    FleetAgentActions::UpdateConfig {
+                        filter_tags,
+                        config_key,
+                        config_value,
+                    } => {
+                        commands::fleet::agents_update_config(
+                            &cfg,
+                            filter_tags,
+                            &config_key,
+                            &config_value,
+                        )
+                        .await?;
+                    }
                 },
                 FleetActions::Deployments { action } => match action {
                     FleetDeploymentActions::List { page_size } => {
@@ -5708,6 +7400,24 @@ async fn main_inner() -> anyhow::Result<()> {
                         DataGovScannerRuleActions::List => {
                             commands::data_governance::scanner_rules_list(&cfg).await?;
                         }
+                        DataGovScannerRuleActions::Create { file } => {
+                            commands::data_governance::scanner_rules_create(&cfg, &file).await?;
+                        }
+                        DataGovScannerRuleActions::Update { rule_id, file } => {
+                            commands::data_governance::scanner_rules_update(&cfg, &rule_id, &file)
+                                .await?;
+                        }
+                        DataGovScannerRuleActions::Delete { rule_id } => {
+                            commands::data_governance::scanner_rules_delete(&cfg, &rule_id).await?;
+                        }
+                    },
+                    DataGovScannerActions::Groups { action } => match action {
+                        DataGovScannerGroupActions::List => {
+                            commands::data_governance::scanner_groups_list(&cfg).await?;
+                        }
+                        DataGovScannerGroupActions::Reorder { ids } => {
+                            commands::data_governance::scanner_groups_reorder(&cfg, ids).await?;
+                        }
                     },
                 },
             }
@@ -5723,6 +7433,31 @@ async fn main_inner() -> anyhow::Result<()> {
                     ErrorTrackingIssueActions::Get { issue_id } => {
                         commands::error_tracking::issues_get(&cfg, &issue_id).await?;
                     }
+                    ErrorTrackingIssueActions::SetStatus {
+                        query,
+                        status,
+                        from,
+                        to,
+                    } => {
+                        commands::error_tracking::issues_bulk_update(
+                            &cfg, &query, &status, &from, &to,
+                        )
+                        .await?;
+                    }
+                    ErrorTrackingIssueActions::Bulk { file } => {
+                        commands::error_tracking::issues_bulk(&cfg, &file).await?;
+                    }
+                    ErrorTrackingIssueActions::BulkUpdate {
+                        query,
+                        state,
+                        from,
+                        to,
+                    } => {
+                        commands::error_tracking::issues_bulk_update(
+                            &cfg, &query, &state, &from, &to,
+                        )
+                        .await?;
+                    }
                 },
             }
         }
@@ -5747,6 +7482,9 @@ async fn main_inner() -> anyhow::Result<()> {
                     HamrConnectionActions::Create { file } => {
                         commands::hamr::connections_create(&cfg, &file).await?;
                     }
+                    HamrConnectionActions::Update { file } => {
+                        commands::hamr::connections_update(&cfg, &file).await?;
+                    }
                 },
             }
         }
@@ -5952,14 +7690,112 @@ async fn main_inner() -> anyhow::Result<()> {
                 },
                 IntegrationActions::Slack { action } => match action {
                     SlackActions::List => commands::integrations::slack_list(&cfg).await?,
+                    SlackActions::Workspaces { action } => match action {
+                        SlackWorkspaceActions::List => {
+                            commands::integrations::slack_workspaces_list(&cfg).await?;
+                        }
+                    },
+                    SlackActions::Channels { action } => match action {
+                        SlackChannelActions::List { workspace_name } => {
+                            commands::integrations::slack_channels_list(&cfg, &workspace_name)
+                                .await?;
+                        }
+                        SlackChannelActions::Get {
+                            workspace_name,
+                            channel_name,
+                        } => {
+                            commands::integrations::slack_channels_get(
+                                &cfg,
+                                &workspace_name,
+                                &channel_name,
+                            )
+                            .await?;
+                        }
+                        SlackChannelActions::Create {
+                            workspace_name,
+                            file,
+                        } => {
+                            commands::integrations::slack_channels_create(
+                                &cfg,
+                                &workspace_name,
+                                &file,
+                            )
+                            .await?;
+                        }
+                    },
+                },
+                IntegrationActions::MicrosoftTeams { action } => match action {
+                    MsTeamsActions::Tenants { action } => match action {
+                        MsTeamsTenantActions::List => {
+                            commands::integrations::ms_teams_tenants_list(&cfg).await?;
+                        }
+                        MsTeamsTenantActions::Get { tenant_id } => {
+                            commands::integrations::ms_teams_tenants_get(&cfg, &tenant_id).await?;
+                        }
+                    },
+                    MsTeamsActions::Channels { action } => match action {
+                        MsTeamsChannelActions::List { tenant_id, name } => {
+                            commands::integrations::ms_teams_channels_list(&cfg, tenant_id, name)
+                                .await?;
+                        }
+                        MsTeamsChannelActions::Get { handle_id } => {
+                            commands::integrations::ms_teams_channels_get(&cfg, &handle_id).await?;
+                        }
+                        MsTeamsChannelActions::Create { file } => {
+                            commands::integrations::ms_teams_channels_create(&cfg, &file).await?;
+                        }
+                        MsTeamsChannelActions::Update { handle_id, file } => {
+                            commands::integrations::ms_teams_channels_update(
+                                &cfg, &handle_id, &file,
+                            )
+                            .await?;
+                        }
+                        MsTeamsChannelActions::Delete { handle_id } => {
+                            commands::integrations::ms_teams_channels_delete(&cfg, &handle_id)
+                                .await?;
+                        }
+                    },
                 },
                 IntegrationActions::Pagerduty { action } => match action {
                     PagerdutyActions::List => {
                         commands::integrations::pagerduty_list(&cfg).await?;
                     }
+                    PagerdutyActions::Get { service_name } => {
+                        commands::integrations::pagerduty_get(&cfg, &service_name).await?;
+                    }
+                    PagerdutyActions::Create { file } => {
+                        commands::integrations::pagerduty_create(&cfg, &file).await?;
+                    }
+                    PagerdutyActions::Update { service_name, file } => {
+                        commands::integrations::pagerduty_update(&cfg, &service_name, &file)
+                            .await?;
+                    }
+                    PagerdutyActions::Delete { service_name } => {
+                        commands::integrations::pagerduty_delete(&cfg, &service_name).await?;
+                    }
+                    PagerdutyActions::Account { action } => match action {
+                        PagerdutyAccountActions::Get => {
+                            commands::integrations::pagerduty_account_get(&cfg).await?;
+                        }
+                        PagerdutyAccountActions::Update { file } => {
+                            commands::integrations::pagerduty_account_update(&cfg, &file).await?;
+                        }
+                    },
                 },
                 IntegrationActions::Webhooks { action } => match action {
                     WebhooksActions::List => commands::integrations::webhooks_list(&cfg).await?,
+                    WebhooksActions::Get { webhook_name } => {
+                        commands::integrations::webhooks_get(&cfg, &webhook_name).await?;
+                    }
+                    WebhooksActions::Create { file, name, url } => {
+                        commands::integrations::webhooks_create(&cfg, file, name, url).await?;
+                    }
+                    WebhooksActions::Update { webhook_name, file } => {
+                        commands::integrations::webhooks_update(&cfg, &webhook_name, &file).await?;
+                    }
+                    WebhooksActions::Delete { webhook_name } => {
+                        commands::integrations::webhooks_delete(&cfg, &webhook_name).await?;
+                    }
                 },
             }
         }
@@ -5978,14 +7814,30 @@ async fn main_inner() -> anyhow::Result<()> {
                 CostActions::Attribution { start, fields, .. } => {
                     commands::cost::attribution(&cfg, start, fields).await?;
                 }
+                CostActions::Forecast {
+                    product,
+                    granularity,
+                } => {
+                    commands::cost::forecast(&cfg, product, granularity).await?;
+                }
             }
         }
         // --- Misc ---
         Commands::Misc { action } => {
-            // No validate_auth() — ip-ranges is public, status IS the auth check
+            // No validate_auth() — ip-ranges is public, status and validate-api-key
+            // ARE the auth checks; permissions validates on its own
             match action {
                 MiscActions::IpRanges => commands::misc::ip_ranges(&cfg).await?,
                 MiscActions::Status => commands::misc::status(&cfg).await?,
+                MiscActions::ValidateApiKey => commands::misc::validate_api_key(&cfg).await?,
+                MiscActions::Permissions { action } => {
+                    cfg.validate_auth()?;
+                    match action {
+                        MiscPermissionActions::List => {
+                            commands::users::permissions_list(&cfg).await?
+                        }
+                    }
+                }
             }
         }
         // --- APM ---
@@ -6019,16 +7871,46 @@ async fn main_inner() -> anyhow::Result<()> {
                         commands::apm::services_resources(&cfg, service, operation, env, from, to)
                             .await?;
                     }
+                    ApmServiceActions::AllResources {
+                        service,
+                        env,
+                        from,
+                        to,
+                        limit,
+                    } => {
+                        commands::apm::services_all_resources(&cfg, service, env, from, to, limit)
+                            .await?;
+                    }
                 },
                 ApmActions::Entities { action } => match action {
                     ApmEntityActions::List { from, to, .. } => {
                         commands::apm::entities_list(&cfg, from, to).await?;
                     }
+                    ApmEntityActions::Search {
+                        query,
+                        from,
+                        to,
+                        limit,
+                    } => {
+                        commands::apm::entities_search(&cfg, query, from, to, limit).await?;
+                    }
                 },
                 ApmActions::Dependencies { action } => match action {
                     ApmDependencyActions::List { env, from, to, .. } => {
                         commands::apm::dependencies_list(&cfg, env, from, to).await?;
                     }
+                    ApmDependencyActions::GetForService {
+                        service,
+                        env,
+                        from,
+                        to,
+                        depth,
+                    } => {
+                        commands::apm::dependencies_for_service(
+                            &cfg, service, env, from, to, depth,
+                        )
+                        .await?;
+                    }
                 },
                 ApmActions::FlowMap {
                     query,
@@ -6082,17 +7964,62 @@ async fn main_inner() -> anyhow::Result<()> {
         },
         // --- Obs Pipelines (placeholder) ---
         Commands::ObsPipelines { action } => match action {
-            ObsPipelinesActions::List => commands::obs_pipelines::list()?,
+            ObsPipelinesActions::List {
+                page_size,
+                page_number,
+            } => {
+                cfg.validate_auth()?;
+                commands::obs_pipelines::list(&cfg, page_size, page_number).await?;
+            }
             ObsPipelinesActions::Get { pipeline_id } => {
-                commands::obs_pipelines::get(&pipeline_id)?;
+                cfg.validate_auth()?;
+                commands::obs_pipelines::get(&cfg, &pipeline_id).await?;
+            }
+            ObsPipelinesActions::Create { file } => {
+                cfg.validate_auth()?;
+                commands::obs_pipelines::create(&cfg, &file).await?;
+            }
+            ObsPipelinesActions::Update { pipeline_id, file } => {
+                cfg.validate_auth()?;
+                commands::obs_pipelines::update(&cfg, &pipeline_id, &file).await?;
+            }
+            ObsPipelinesActions::Delete { pipeline_id } => {
+                cfg.validate_auth()?;
+                commands::obs_pipelines::delete(&cfg, &pipeline_id).await?;
+            }
+            ObsPipelinesActions::Validate { file } => {
+                cfg.validate_auth()?;
+                commands::obs_pipelines::validate(&cfg, &file).await?;
             }
         },
         // --- Scorecards (placeholder) ---
         Commands::Scorecards { action } => match action {
-            ScorecardsActions::List => commands::scorecards::list()?,
-            ScorecardsActions::Get { scorecard_id } => {
-                commands::scorecards::get(&scorecard_id)?;
+            ScorecardsActions::List {
+                limit,
+                include_rules,
+            } => {
+                cfg.validate_auth()?;
+                commands::scorecards::list(&cfg, limit, include_rules).await?;
+            }
+            ScorecardsActions::Get {
+                scorecard_id,
+                include_rules,
+            } => {
+                cfg.validate_auth()?;
+                commands::scorecards::get(&cfg, &scorecard_id, include_rules).await?;
             }
+            ScorecardsActions::Rules { action } => match action {
+                ScorecardRuleActions::List { filter_query } => {
+                    cfg.validate_auth()?;
+                    commands::scorecards::rules_list(&cfg, filter_query).await?;
+                }
+            },
+            ScorecardsActions::Outcomes { action } => match action {
+                ScorecardOutcomeActions::List { service_name } => {
+                    cfg.validate_auth()?;
+                    commands::scorecards::outcomes_list(&cfg, service_name).await?;
+                }
+            },
         },
         // --- Traces ---
         Commands::Traces { action } => {
@@ -6125,7 +8052,7 @@ async fn main_inner() -> anyhow::Result<()> {
         },
         // --- Alias ---
         Commands::Alias { action } => match action {
-            AliasActions::List => commands::alias::list()?,
+            AliasActions::List => commands::alias::list(&cfg)?,
             AliasActions::Set { name, command } => commands::alias::set(name, command)?,
             AliasActions::Delete { names } => commands::alias::delete(names)?,
             AliasActions::Import { file } => commands::alias::import(&file)?,
@@ -6139,6 +8066,28 @@ async fn main_inner() -> anyhow::Result<()> {
                         let f = file.unwrap_or_default();
                         commands::product_analytics::events_send(&cfg, &f).await?;
                     }
+                    ProductAnalyticsEventActions::List {
+                        app_id,
+                        from,
+                        to,
+                        limit,
+                    } => {
+                        commands::product_analytics::events_list(&cfg, &app_id, from, to, limit)
+                            .await?;
+                    }
+                    ProductAnalyticsEventActions::Aggregate {
+                        app_id,
+                        query,
+                        from,
+                        to,
+                        compute,
+                        group_by,
+                    } => {
+                        commands::product_analytics::events_aggregate(
+                            &cfg, &app_id, query, from, to, compute, group_by,
+                        )
+                        .await?;
+                    }
                 },
             }
         }
@@ -6147,7 +8096,7 @@ async fn main_inner() -> anyhow::Result<()> {
             cfg.validate_auth()?;
             match action {
                 StaticAnalysisActions::Ast { action } => match action {
-                    StaticAnalysisAstActions::List { .. } => {
+                    StaticAnalysisAstActions::List => {
                         commands::static_analysis::ast_list(&cfg).await?;
                     }
                     StaticAnalysisAstActions::Get { id } => {
@@ -6182,11 +8131,12 @@ async fn main_inner() -> anyhow::Result<()> {
         }
         // --- Auth ---
         Commands::Auth { action } => match action {
-            AuthActions::Login => commands::auth::login(&cfg).await?,
+            AuthActions::Login { no_browser } => commands::auth::login(&cfg, no_browser).await?,
             AuthActions::Logout => commands::auth::logout(&cfg).await?,
             AuthActions::Status => commands::auth::status(&cfg)?,
             AuthActions::Token => commands::auth::token(&cfg)?,
             AuthActions::Refresh => commands::auth::refresh(&cfg).await?,
+            AuthActions::ExpireToken { force } => commands::auth::expire_token(&cfg, force).await?,
         },
         // --- Utility ---
         Commands::Completions { shell } => {
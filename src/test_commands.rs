@@ -31,6 +31,8 @@ fn test_config(mock_url: &str) -> Config {
         output_format: OutputFormat::Json,
         auto_approve: false,
         agent_mode: false,
+        table_max_width: None,
+        rate_limit_warning: false,
     }
 }
 
@@ -62,6 +64,33 @@ async fn mock_any(server: &mut mockito::Server, method: &str, body: &str) -> moc
 // Monitors
 // -------------------------------------------------------------------------
 
+#[tokio::test]
+async fn test_monitors_list_warns_on_low_rate_limit() {
+    use std::sync::atomic::Ordering;
+    let _lock = lock_env();
+    let mut server = mockito::Server::new_async().await;
+    let mut cfg = test_config(&server.url());
+    cfg.rate_limit_warning = true;
+    let _mock = server
+        .mock("GET", mockito::Matcher::Any)
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("x-ratelimit-limit", "100")
+        .with_header("x-ratelimit-remaining", "3")
+        .with_header("x-ratelimit-reset", "20")
+        .with_body("[]")
+        .create_async()
+        .await;
+
+    let before = crate::api::RATE_LIMIT_WARNINGS.load(Ordering::SeqCst);
+    let result =
+        crate::commands::monitors::list(&cfg, &Default::default(), 10, None, None, None).await;
+    assert!(result.is_ok(), "monitors list failed: {:?}", result.err());
+    assert!(crate::api::RATE_LIMIT_WARNINGS.load(Ordering::SeqCst) > before);
+    cleanup_env();
+}
+
 #[tokio::test]
 async fn test_monitors_list_empty() {
     let _lock = lock_env();
@@ -69,7 +98,8 @@ async fn test_monitors_list_empty() {
     let cfg = test_config(&server.url());
     let _mock = mock_any(&mut server, "GET", "[]").await;
 
-    let result = crate::commands::monitors::list(&cfg, None, None, 10).await;
+    let result =
+        crate::commands::monitors::list(&cfg, &Default::default(), 10, None, None, None).await;
     assert!(result.is_ok(), "monitors list failed: {:?}", result.err());
     cleanup_env();
 }
@@ -83,7 +113,18 @@ async fn test_monitors_list_with_results() {
     let body = r#"[{"id": 1, "name": "Test Monitor", "type": "metric alert", "query": "avg(last_5m):avg:system.cpu.user{*} > 90", "message": "CPU high", "tags": [], "options": {}}]"#;
     let _mock = mock_any(&mut server, "GET", body).await;
 
-    let result = crate::commands::monitors::list(&cfg, Some("Test".into()), None, 10).await;
+    let result = crate::commands::monitors::list(
+        &cfg,
+        &crate::commands::monitors::MonitorFilter {
+            name: Some("Test".into()),
+            ..Default::default()
+        },
+        10,
+        None,
+        None,
+        None,
+    )
+    .await;
     assert!(
         result.is_ok(),
         "monitors list with results failed: {:?}",
@@ -92,6 +133,237 @@ async fn test_monitors_list_with_results() {
     cleanup_env();
 }
 
+#[tokio::test]
+async fn test_monitors_list_exit_on_alert() {
+    let _lock = lock_env();
+    let mut server = mockito::Server::new_async().await;
+    let cfg = test_config(&server.url());
+
+    let body = r#"[{"id": 1, "name": "Test Monitor", "type": "metric alert", "query": "avg(last_5m):avg:system.cpu.user{*} > 90", "message": "CPU high", "tags": [], "options": {}, "overall_state": "Alert"}]"#;
+    let _mock = mock_any(&mut server, "GET", body).await;
+
+    let result =
+        crate::commands::monitors::list(&cfg, &Default::default(), 10, None, None, Some("Alert"))
+            .await;
+    assert!(result.is_err(), "alerting monitor should fail the command");
+    let result =
+        crate::commands::monitors::list(&cfg, &Default::default(), 10, None, None, Some("Warn"))
+            .await;
+    assert!(result.is_ok(), "no Warn monitors: {:?}", result.err());
+    cleanup_env();
+}
+
+#[tokio::test]
+async fn test_monitors_list_no_deleted() {
+    let _lock = lock_env();
+    let mut server = mockito::Server::new_async().await;
+    let cfg = test_config(&server.url());
+
+    let body = r#"[{"id": 1, "name": "Live", "type": "metric alert", "query": "q", "overall_state": "OK"},
+        {"id": 2, "name": "Gone", "type": "metric alert", "query": "q", "overall_state": "Alert", "deleted": "2026-01-01T00:00:00Z"}]"#;
+    let _mock = mock_any(&mut server, "GET", body).await;
+
+    let filter = crate::commands::monitors::MonitorFilter {
+        no_deleted: true,
+        ..Default::default()
+    };
+    let result =
+        crate::commands::monitors::list(&cfg, &filter, 10, None, None, Some("Alert")).await;
+    assert!(
+        result.is_ok(),
+        "deleted monitor should be excluded: {:?}",
+        result.err()
+    );
+    let result =
+        crate::commands::monitors::list(&cfg, &Default::default(), 10, None, None, Some("Alert"))
+            .await;
+    assert!(
+        result.is_err(),
+        "deleted monitor is listed without --no-deleted"
+    );
+    let result = crate::commands::monitors::count(&cfg, &filter, Some("Alert")).await;
+    assert!(
+        result.is_ok(),
+        "count should honor --no-deleted: {:?}",
+        result.err()
+    );
+    cleanup_env();
+}
+
+#[tokio::test]
+async fn test_monitors_list_sorts_before_limit() {
+    let _lock = lock_env();
+    let mut server = mockito::Server::new_async().await;
+    let cfg = test_config(&server.url());
+
+    let body = r#"[{"id": 1, "name": "Quiet", "type": "metric alert", "query": "q", "overall_state": "OK"},
+        {"id": 2, "name": "Live", "type": "metric alert", "query": "q", "overall_state": "Alert"}]"#;
+    let mock = server
+        .mock("GET", "/api/v1/monitor")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "page_size".into(),
+            "1000".into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(body)
+        .create_async()
+        .await;
+
+    // Sorting by status must see every monitor, so with --limit 1 the
+    // Alert monitor comes out ahead of the OK one returned first.
+    let result = crate::commands::monitors::list(
+        &cfg,
+        &Default::default(),
+        1,
+        Some("status"),
+        None,
+        Some("Alert"),
+    )
+    .await;
+    assert!(
+        result.is_err(),
+        "sorted first monitor should be Live (Alert)"
+    );
+    mock.assert_async().await;
+    cleanup_env();
+}
+
+#[tokio::test]
+async fn test_monitors_list_no_deleted_before_limit() {
+    let _lock = lock_env();
+    let mut server = mockito::Server::new_async().await;
+    let cfg = test_config(&server.url());
+
+    let body = r#"[{"id": 1, "name": "Gone", "type": "metric alert", "query": "q", "overall_state": "Alert", "deleted": "2026-01-01T00:00:00Z"},
+        {"id": 2, "name": "Quiet", "type": "metric alert", "query": "q", "overall_state": "OK"}]"#;
+    let mock = server
+        .mock("GET", "/api/v1/monitor")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "page_size".into(),
+            "1000".into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(body)
+        .create_async()
+        .await;
+
+    // With --limit 1 the deleted monitor has to be dropped before
+    // truncating, leaving the live OK monitor.
+    let filter = crate::commands::monitors::MonitorFilter {
+        no_deleted: true,
+        ..Default::default()
+    };
+    let result = crate::commands::monitors::list(&cfg, &filter, 1, None, None, Some("OK")).await;
+    assert!(result.is_err(), "first live monitor should be Quiet (OK)");
+    mock.assert_async().await;
+    cleanup_env();
+}
+
+#[tokio::test]
+async fn test_monitors_list_json_path() {
+    let _lock = lock_env();
+    let mut server = mockito::Server::new_async().await;
+    let cfg = test_config(&server.url());
+
+    let body = r#"[{"id": 1, "name": "Test Monitor", "type": "metric alert", "query": "avg(last_5m):avg:system.cpu.user{*} > 90", "message": "CPU high", "tags": [], "options": {}}]"#;
+    let _mock = mock_any(&mut server, "GET", body).await;
+
+    let result = crate::commands::monitors::list(
+        &cfg,
+        &Default::default(),
+        10,
+        None,
+        Some("$[*].name"),
+        None,
+    )
+    .await;
+    assert!(result.is_ok(), "json path failed: {:?}", result.err());
+    let result =
+        crate::commands::monitors::list(&cfg, &Default::default(), 10, None, Some("$["), None)
+            .await;
+    assert!(result.is_err(), "invalid JSONPath should fail");
+    cleanup_env();
+}
+
+#[tokio::test]
+async fn test_monitors_count_follows_pages() {
+    let _lock = lock_env();
+    let mut server = mockito::Server::new_async().await;
+    let cfg = test_config(&server.url());
+
+    let monitor = |i: usize| serde_json::json!({"id": i, "name": format!("m{i}"), "type": "metric alert", "query": "q", "overall_state": "OK"});
+    let full: Vec<_> = (0..1000).map(monitor).collect();
+    let rest: Vec<_> = (1000..1002).map(monitor).collect();
+    let page0 = server
+        .mock("GET", "/api/v1/monitor")
+        .match_query(mockito::Matcher::UrlEncoded("page".into(), "0".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&full).unwrap())
+        .create_async()
+        .await;
+    let page1 = server
+        .mock("GET", "/api/v1/monitor")
+        .match_query(mockito::Matcher::UrlEncoded("page".into(), "1".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&rest).unwrap())
+        .create_async()
+        .await;
+
+    let result = crate::commands::monitors::count(&cfg, &Default::default(), Some("Alert")).await;
+    assert!(result.is_ok(), "count failed: {:?}", result.err());
+    page0.assert_async().await;
+    page1.assert_async().await;
+    cleanup_env();
+}
+
+#[tokio::test]
+async fn test_monitors_bulk_mute_creates_downtimes() {
+    let _lock = lock_env();
+    let mut server = mockito::Server::new_async().await;
+    let cfg = test_config(&server.url());
+
+    let list = server
+        .mock("GET", "/api/v1/monitor")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "monitor_tags".into(),
+            "team:backend".into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"[{"id": 1, "name": "a", "type": "metric alert", "query": "q"}, {"id": 2, "name": "b", "type": "metric alert", "query": "q"}]"#,
+        )
+        .create_async()
+        .await;
+    let downtimes = server
+        .mock("POST", "/api/v2/downtime")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"data": {"type": "downtime", "attributes": {"scope": "*", "message": "maintenance"}}}"#.into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": {"id": "d1", "type": "downtime"}}"#)
+        .expect(2)
+        .create_async()
+        .await;
+
+    let result = crate::commands::monitors::bulk_mute(
+        &cfg,
+        "team:backend".into(),
+        Some("2h".into()),
+        Some("maintenance".into()),
+    )
+    .await;
+    assert!(result.is_ok(), "bulk mute failed: {:?}", result.err());
+    list.assert_async().await;
+    downtimes.assert_async().await;
+    cleanup_env();
+}
+
 #[tokio::test]
 async fn test_monitors_get() {
     let _lock = lock_env();
@@ -132,6 +404,32 @@ async fn test_monitors_delete() {
     cleanup_env();
 }
 
+#[tokio::test]
+async fn test_monitors_check_can_delete_conflict() {
+    let _lock = lock_env();
+    let mut server = mockito::Server::new_async().await;
+    let cfg = test_config(&server.url());
+    let _mock = server
+        .mock("GET", "/api/v1/monitor/can_delete")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "monitor_ids".into(),
+            "1,2".into(),
+        ))
+        .with_status(409)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": {"ok": [1]}, "errors": {"2": ["monitor [2] is referenced in composite monitors: [3]"]}}"#)
+        .create_async()
+        .await;
+
+    let result = crate::commands::monitors::check_can_delete(&cfg, &[1, 2]).await;
+    assert!(
+        result.is_ok(),
+        "check-can-delete should report a 409 body: {:?}",
+        result.err()
+    );
+    cleanup_env();
+}
+
 // -------------------------------------------------------------------------
 // Dashboards
 // -------------------------------------------------------------------------
@@ -143,11 +441,72 @@ async fn test_dashboards_list() {
     let cfg = test_config(&server.url());
     let _mock = mock_any(&mut server, "GET", r#"{"dashboards": []}"#).await;
 
-    let result = crate::commands::dashboards::list(&cfg).await;
+    let result = crate::commands::dashboards::list(&cfg, false).await;
     assert!(result.is_ok(), "dashboards list failed: {:?}", result.err());
     cleanup_env();
 }
 
+#[tokio::test]
+async fn test_dashboards_list_with_widgets() {
+    let _lock = lock_env();
+    let mut server = mockito::Server::new_async().await;
+    let cfg = test_config(&server.url());
+    let _list = server
+        .mock("GET", "/api/v1/dashboard")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"dashboards": [{"id": "abc-123", "title": "Test Dashboard"}]}"#)
+        .create_async()
+        .await;
+    let get = server
+        .mock("GET", "/api/v1/dashboard/abc-123")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"id": "abc-123", "title": "Test Dashboard", "layout_type": "ordered", "widgets": [{"definition": {"type": "note", "content": "hi"}}]}"#,
+        )
+        .create_async()
+        .await;
+
+    let result = crate::commands::dashboards::list(&cfg, true).await;
+    assert!(
+        result.is_ok(),
+        "dashboards list --with-widgets failed: {:?}",
+        result.err()
+    );
+    get.assert_async().await;
+    cleanup_env();
+}
+
+#[tokio::test]
+async fn test_dashboards_search() {
+    let _lock = lock_env();
+    let mut server = mockito::Server::new_async().await;
+    let cfg = test_config(&server.url());
+    let page = server
+        .mock("GET", "/api/v1/dashboard")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("count".into(), "100".into()),
+            mockito::Matcher::UrlEncoded("start".into(), "0".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"dashboards": [{"id": "a", "title": "Checkout Latency"}, {"id": "b", "title": "Payments"}]}"#,
+        )
+        .create_async()
+        .await;
+
+    let result = crate::commands::dashboards::search(&cfg, "CHECKOUT").await;
+    assert!(
+        result.is_ok(),
+        "dashboards search failed: {:?}",
+        result.err()
+    );
+    page.assert_async().await;
+    cleanup_env();
+}
+
 #[tokio::test]
 async fn test_dashboards_get() {
     let _lock = lock_env();
@@ -165,6 +524,35 @@ async fn test_dashboards_get() {
     cleanup_env();
 }
 
+#[tokio::test]
+async fn test_dashboards_create_from_flags() {
+    let _lock = lock_env();
+    let mut server = mockito::Server::new_async().await;
+    let cfg = test_config(&server.url());
+    let _mock = server
+        .mock("POST", "/api/v1/dashboard")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"title": "Checkout", "layout_type": "ordered", "widgets": []}"#.into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"id": "abc-123", "title": "Checkout", "layout_type": "ordered", "widgets": []}"#,
+        )
+        .create_async()
+        .await;
+
+    let body =
+        crate::commands::dashboards::dashboard_body("Checkout", None, "ordered", None).unwrap();
+    let result = crate::commands::dashboards::create_payload(&cfg, body).await;
+    assert!(
+        result.is_ok(),
+        "dashboards create failed: {:?}",
+        result.err()
+    );
+    cleanup_env();
+}
+
 #[tokio::test]
 async fn test_dashboards_delete() {
     let _lock = lock_env();
@@ -231,6 +619,87 @@ async fn test_slos_delete() {
     cleanup_env();
 }
 
+#[tokio::test]
+async fn test_slos_bulk_create() {
+    let _lock = lock_env();
+    let mut server = mockito::Server::new_async().await;
+    let cfg = test_config(&server.url());
+    let mock = server
+        .mock("POST", "/api/v1/slo")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"data": [{"id": "slo-1", "name": "API availability", "type": "metric",
+                "thresholds": [{"timeframe": "30d", "target": 99.9}],
+                "query": {"numerator": "sum:good{*}", "denominator": "sum:total{*}"}}]}"#,
+        )
+        .expect(2)
+        .create_async()
+        .await;
+
+    let path = std::env::temp_dir().join("pup_test_slos_bulk_create.json");
+    let slo = serde_json::json!({
+        "name": "API availability",
+        "type": "metric",
+        "thresholds": [{"timeframe": "30d", "target": 99.9}],
+        "query": {"numerator": "sum:good{*}", "denominator": "sum:total{*}"},
+    });
+    std::fs::write(&path, serde_json::json!([slo, slo]).to_string()).unwrap();
+    let file = path.to_str().unwrap();
+
+    let result = crate::commands::slos::bulk_create(&cfg, file, true).await;
+    assert!(
+        result.is_ok(),
+        "slos bulk-create dry run failed: {:?}",
+        result.err()
+    );
+    let result = crate::commands::slos::bulk_create(&cfg, file, false).await;
+    assert!(
+        result.is_ok(),
+        "slos bulk-create failed: {:?}",
+        result.err()
+    );
+    mock.assert_async().await;
+
+    std::fs::write(&path, r#"[{"name": "no type"}]"#).unwrap();
+    let result = crate::commands::slos::bulk_create(&cfg, file, false).await;
+    assert!(result
+        .unwrap_err()
+        .to_string()
+        .contains("SLO #0: missing type"));
+
+    let _ = std::fs::remove_file(&path);
+    cleanup_env();
+}
+
+#[tokio::test]
+async fn test_slos_history() {
+    let _lock = lock_env();
+    let mut server = mockito::Server::new_async().await;
+    let mut cfg = test_config(&server.url());
+    cfg.output_format = OutputFormat::Table;
+    let mock = server
+        .mock("GET", "/api/v1/slo/abc123/history")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("from_ts".into(), "1700000000".into()),
+            mockito::Matcher::UrlEncoded("to_ts".into(), "1700604800".into()),
+            mockito::Matcher::UrlEncoded("target".into(), "99.9".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"data": {"from_ts": 1700000000, "to_ts": 1700604800, "overall": {"name": "Checkout", "sli_value": 99.95}, "groups": [], "monitors": []}}"#,
+        )
+        .create_async()
+        .await;
+
+    let result =
+        crate::commands::slos::history(&cfg, "abc123", 1700000000, 1700604800, Some(99.9)).await;
+    assert!(result.is_ok(), "slos history failed: {:?}", result.err());
+    mock.assert_async().await;
+    cleanup_env();
+}
+
 // -------------------------------------------------------------------------
 // Tags
 // -------------------------------------------------------------------------
@@ -362,10 +831,206 @@ async fn test_logs_search() {
     let cfg = test_config(&server.url());
     let _mock = mock_any(&mut server, "POST", r#"{"data": [], "meta": {"page": {}}}"#).await;
 
-    let result =
-        crate::commands::logs::search(&cfg, "status:error".into(), "1h".into(), "now".into(), 10)
-            .await;
+    let result = crate::commands::logs::search(
+        &cfg,
+        "status:error".into(),
+        "1h".into(),
+        "now".into(),
+        10,
+        &Default::default(),
+    )
+    .await;
+    assert!(result.is_ok(), "logs search failed: {:?}", result.err());
+    cleanup_env();
+}
+
+#[tokio::test]
+async fn test_logs_search_sends_storage_tier() {
+    let _lock = lock_env();
+    let mut server = mockito::Server::new_async().await;
+    let cfg = test_config(&server.url());
+    let _mock = server
+        .mock("POST", "/api/v2/logs/events/search")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"filter": {"storage_tier": "flex"}}"#.into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": [], "meta": {"page": {}}}"#)
+        .create_async()
+        .await;
+
+    let result = crate::commands::logs::search(
+        &cfg,
+        "status:error".into(),
+        "1h".into(),
+        "now".into(),
+        10,
+        &crate::commands::logs::LogSearchOptions {
+            storage: Some("flex".into()),
+            ..Default::default()
+        },
+    )
+    .await;
+    assert!(result.is_ok(), "logs search failed: {:?}", result.err());
+    cleanup_env();
+}
+
+#[tokio::test]
+async fn test_logs_search_sends_indexes() {
+    let _lock = lock_env();
+    let mut server = mockito::Server::new_async().await;
+    let cfg = test_config(&server.url());
+    let mock = server
+        .mock("POST", "/api/v2/logs/events/search")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"filter": {"indexes": ["main", "security"]}}"#.into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": [], "meta": {"page": {}}}"#)
+        .create_async()
+        .await;
+
+    let search = |index: &str| {
+        let opts = crate::commands::logs::LogSearchOptions {
+            index: Some(index.into()),
+            ..Default::default()
+        };
+        let cfg = &cfg;
+        async move {
+            crate::commands::logs::search(
+                cfg,
+                "status:error".into(),
+                "1h".into(),
+                "now".into(),
+                10,
+                &opts,
+            )
+            .await
+        }
+    };
+    let result = search("main, security").await;
     assert!(result.is_ok(), "logs search failed: {:?}", result.err());
+    mock.assert_async().await;
+
+    let err = search("main security").await.unwrap_err().to_string();
+    assert!(err.contains("cannot contain spaces"), "{err}");
+    let err = search("https://app.datadoghq.com/logs/pipelines/indexes/main")
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("not its URL (e.g. --index=main)"), "{err}");
+    cleanup_env();
+}
+
+#[tokio::test]
+async fn test_logs_list_index_and_sort() {
+    let _lock = lock_env();
+    let mut server = mockito::Server::new_async().await;
+    let cfg = test_config(&server.url());
+    let mock = server
+        .mock("POST", "/api/v2/logs/events/search")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"filter": {"indexes": ["main"]}, "sort": "timestamp"}"#.into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": [], "meta": {"page": {}}}"#)
+        .create_async()
+        .await;
+
+    let opts = crate::commands::logs::LogSearchOptions {
+        index: Some("main".into()),
+        sort: Some("@timestamp".into()),
+        ..Default::default()
+    };
+    let result = crate::commands::logs::list(
+        &cfg,
+        "service:web-app".into(),
+        "1h".into(),
+        "now".into(),
+        10,
+        &opts,
+    )
+    .await;
+    assert!(result.is_ok(), "logs list failed: {:?}", result.err());
+    mock.assert_async().await;
+
+    let opts = crate::commands::logs::LogSearchOptions {
+        sort: Some("newest".into()),
+        ..Default::default()
+    };
+    let err = crate::commands::logs::list(&cfg, "*".into(), "1h".into(), "now".into(), 10, &opts)
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("invalid --sort"), "{err}");
+    cleanup_env();
+}
+
+#[tokio::test]
+async fn test_logs_search_stream_follows_cursor() {
+    let _lock = lock_env();
+    let mut server = mockito::Server::new_async().await;
+    let cfg = test_config(&server.url());
+    let first = server
+        .mock("POST", "/api/v2/logs/events/search")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"page": {"limit": 3}}"#.into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"data": [{"id": "a", "type": "log"}, {"id": "b", "type": "log"}], "meta": {"page": {"after": "next"}}}"#,
+        )
+        .create_async()
+        .await;
+    let second = server
+        .mock("POST", "/api/v2/logs/events/search")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"page": {"limit": 1, "cursor": "next"}}"#.into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": [{"id": "c", "type": "log"}], "meta": {"page": {}}}"#)
+        .create_async()
+        .await;
+
+    let result = crate::commands::logs::search_stream(
+        &cfg,
+        "status:error".into(),
+        "1h".into(),
+        "now".into(),
+        3,
+        &Default::default(),
+    )
+    .await;
+    assert!(result.is_ok(), "logs stream failed: {:?}", result.err());
+    first.assert_async().await;
+    second.assert_async().await;
+    cleanup_env();
+}
+
+#[tokio::test]
+async fn test_logs_search_rejects_unknown_storage_tier() {
+    let _lock = lock_env();
+    let server = mockito::Server::new_async().await;
+    let cfg = test_config(&server.url());
+
+    let result = crate::commands::logs::search(
+        &cfg,
+        "*".into(),
+        "1h".into(),
+        "now".into(),
+        10,
+        &crate::commands::logs::LogSearchOptions {
+            storage: Some("cold".into()),
+            ..Default::default()
+        },
+    )
+    .await;
+    assert!(result.is_err(), "unknown storage tier should be rejected");
     cleanup_env();
 }
 
@@ -383,11 +1048,19 @@ async fn test_logs_search_requires_api_keys() {
         output_format: OutputFormat::Json,
         auto_approve: false,
         agent_mode: false,
+        table_max_width: None,
+        rate_limit_warning: false,
     };
 
-    let result =
-        crate::commands::logs::search(&cfg, "status:error".into(), "1h".into(), "now".into(), 10)
-            .await;
+    let result = crate::commands::logs::search(
+        &cfg,
+        "status:error".into(),
+        "1h".into(),
+        "now".into(),
+        10,
+        &Default::default(),
+    )
+    .await;
     assert!(result.is_err(), "logs search should require API keys");
     assert!(
         result
@@ -412,6 +1085,98 @@ async fn test_logs_aggregate() {
     cleanup_env();
 }
 
+#[tokio::test]
+async fn test_logs_archives_reorder() {
+    let _lock = lock_env();
+    let mut server = mockito::Server::new_async().await;
+    let cfg = test_config(&server.url());
+    let mock = server
+        .mock("PUT", "/api/v2/logs/config/archive-order")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"data": {"type": "archive_order", "attributes": {"archive_ids": ["b", "a"]}}}"#
+                .into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"data": {"type": "archive_order", "attributes": {"archive_ids": ["b", "a"]}}}"#,
+        )
+        .create_async()
+        .await;
+
+    let result = crate::commands::logs::archives_reorder(&cfg, vec!["b".into(), "a".into()]).await;
+    assert!(
+        result.is_ok(),
+        "archives reorder failed: {:?}",
+        result.err()
+    );
+    mock.assert_async().await;
+    cleanup_env();
+}
+
+#[tokio::test]
+async fn test_logs_archives_rehydrate() {
+    let _lock = lock_env();
+    let mut server = mockito::Server::new_async().await;
+    let cfg = test_config(&server.url());
+    let mock = server
+        .mock("POST", "/api/v1/logs/config/archives/arch-1/logs/rehydrate")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"from": 1700000000000, "to": 1700003600000, "query": "status:error"}"#.into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"status": "pending"}"#)
+        .create_async()
+        .await;
+
+    let result = crate::commands::logs::archives_rehydrate(
+        &cfg,
+        "arch-1",
+        "1700000000000".into(),
+        "1700003600000".into(),
+        Some("status:error".into()),
+    )
+    .await;
+    assert!(
+        result.is_ok(),
+        "archives rehydrate failed: {:?}",
+        result.err()
+    );
+    mock.assert_async().await;
+    cleanup_env();
+}
+
+#[tokio::test]
+async fn test_logs_archives_rehydrate_rejects_inverted_range() {
+    let _lock = lock_env();
+    let cfg = test_config("http://127.0.0.1:1");
+    let result =
+        crate::commands::logs::archives_rehydrate(&cfg, "arch-1", "1h".into(), "2h".into(), None)
+            .await;
+    assert!(result.is_err());
+    cleanup_env();
+}
+
+#[tokio::test]
+async fn test_logs_archives_order() {
+    let _lock = lock_env();
+    let mut server = mockito::Server::new_async().await;
+    let cfg = test_config(&server.url());
+    let mock = server
+        .mock("GET", "/api/v2/logs/config/archive-order")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": {"type": "archive_order", "attributes": {"archive_ids": ["a"]}}}"#)
+        .create_async()
+        .await;
+
+    let result = crate::commands::logs::archives_order_get(&cfg).await;
+    assert!(result.is_ok(), "archives order failed: {:?}", result.err());
+    mock.assert_async().await;
+    cleanup_env();
+}
+
 #[tokio::test]
 async fn test_logs_archives_list() {
     let _lock = lock_env();
@@ -485,6 +1250,99 @@ async fn test_logs_restriction_queries_list() {
     cleanup_env();
 }
 
+#[tokio::test]
+async fn test_logs_restriction_queries_delete() {
+    let _lock = lock_env();
+    let mut server = mockito::Server::new_async().await;
+    let cfg = test_config(&server.url());
+
+    let _mock = server
+        .mock("DELETE", "/api/v2/logs/config/restriction_queries/rq-123")
+        .with_status(204)
+        .create_async()
+        .await;
+
+    let result = crate::commands::logs::restriction_queries_delete(&cfg, "rq-123").await;
+    assert!(
+        result.is_ok(),
+        "logs restriction queries delete failed: {:?}",
+        result.err()
+    );
+    cleanup_env();
+}
+
+#[tokio::test]
+async fn test_logs_restriction_queries_add_role() {
+    let _lock = lock_env();
+    let mut server = mockito::Server::new_async().await;
+    let cfg = test_config(&server.url());
+
+    let _mock = server
+        .mock(
+            "POST",
+            "/api/v2/logs/config/restriction_queries/rq-123/roles",
+        )
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"data": {"type": "roles", "id": "role-1"}}"#.into(),
+        ))
+        .with_status(204)
+        .create_async()
+        .await;
+
+    let result =
+        crate::commands::logs::restriction_queries_add_role(&cfg, "rq-123", "role-1").await;
+    assert!(
+        result.is_ok(),
+        "logs restriction queries add-role failed: {:?}",
+        result.err()
+    );
+    cleanup_env();
+}
+
+#[tokio::test]
+async fn test_logs_pipelines_list() {
+    let _lock = lock_env();
+    let mut server = mockito::Server::new_async().await;
+    let cfg = test_config(&server.url());
+    let _mock = server
+        .mock("GET", "/api/v1/logs/config/pipelines")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"[{"id": "p1", "name": "nginx", "is_enabled": true, "filter": {"query": "source:nginx"}, "processors": []}]"#)
+        .create_async()
+        .await;
+
+    let result = crate::commands::logs::pipelines_list(&cfg).await;
+    assert!(
+        result.is_ok(),
+        "logs pipelines list failed: {:?}",
+        result.err()
+    );
+    cleanup_env();
+}
+
+#[tokio::test]
+async fn test_logs_pipelines_delete() {
+    let _lock = lock_env();
+    let mut server = mockito::Server::new_async().await;
+    let cfg = test_config(&server.url());
+    let _mock = server
+        .mock("DELETE", "/api/v1/logs/config/pipelines/p1")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body("{}")
+        .create_async()
+        .await;
+
+    let result = crate::commands::logs::pipelines_delete(&cfg, "p1").await;
+    assert!(
+        result.is_ok(),
+        "logs pipelines delete failed: {:?}",
+        result.err()
+    );
+    cleanup_env();
+}
+
 // -------------------------------------------------------------------------
 // Metrics
 // -------------------------------------------------------------------------
@@ -505,6 +1363,26 @@ async fn test_metrics_list() {
     assert!(result.is_ok(), "metrics list failed: {:?}", result.err());
     cleanup_env();
 }
+#[tokio::test]
+async fn test_metrics_list_sends_from_in_seconds() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    let _m = s
+        .mock("GET", "/api/v1/metrics")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "from".into(),
+            "1704067200".into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"metrics": ["system.cpu.user"], "from": "1704067200"}"#)
+        .create_async()
+        .await;
+    let result = crate::commands::metrics::list(&cfg, None, "2024-01-01T00:00:00Z".into()).await;
+    assert!(result.is_ok(), "metrics list failed: {:?}", result.err());
+    cleanup_env();
+}
 
 #[tokio::test]
 async fn test_metrics_query() {
@@ -523,6 +1401,7 @@ async fn test_metrics_query() {
         "avg:system.cpu.user{*}".into(),
         "1h".into(),
         "now".into(),
+        false,
     )
     .await;
     assert!(result.is_ok(), "metrics query failed: {:?}", result.err());
@@ -530,17 +1409,156 @@ async fn test_metrics_query() {
 }
 
 #[tokio::test]
-async fn test_metrics_metadata_get() {
+async fn test_metrics_submit_value() {
     let _lock = lock_env();
     let mut server = mockito::Server::new_async().await;
     let cfg = test_config(&server.url());
-    let _mock = mock_any(
-        &mut server,
-        "GET",
-        r#"{"type": "gauge", "description": "CPU usage", "unit": "percent"}"#,
-    )
-    .await;
-
+    let _mock = server
+        .mock("POST", "/api/v2/series")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"series": [{"metric": "custom.metric", "type": 3, "points": [{"timestamp": 1700000000, "value": 42.5}], "tags": ["env:prod"]}]}"#.into(),
+        ))
+        .with_status(202)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"errors": []}"#)
+        .create_async()
+        .await;
+
+    let body = crate::commands::metrics::series_payload(
+        "custom.metric",
+        42.5,
+        Some("env:prod"),
+        "gauge",
+        None,
+        0,
+        Some("1700000000000"),
+    )
+    .unwrap();
+    let result = crate::commands::metrics::submit_payload(&cfg, body).await;
+    assert!(result.is_ok(), "metrics submit failed: {:?}", result.err());
+    cleanup_env();
+}
+
+#[tokio::test]
+async fn test_metrics_submit_many_batches() {
+    let _lock = lock_env();
+    let mut server = mockito::Server::new_async().await;
+    let cfg = test_config(&server.url());
+    let mock = server
+        .mock("POST", "/api/v2/series")
+        .with_status(202)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"errors": []}"#)
+        .expect(2)
+        .create_async()
+        .await;
+
+    let series: Vec<_> = (0..501)
+        .map(|i| {
+            serde_json::json!({"metric": "custom.metric", "type": 3, "points": [{"timestamp": 1700000000 + i, "value": 1.0}]})
+        })
+        .collect();
+    let file = std::env::temp_dir().join("pup_test_metrics_many.json");
+    std::fs::write(&file, serde_json::to_string(&series).unwrap()).unwrap();
+    let result = crate::commands::metrics::submit_many(&cfg, file.to_str().unwrap()).await;
+    let _ = std::fs::remove_file(&file);
+    assert!(
+        result.is_ok(),
+        "metrics submit --many failed: {:?}",
+        result.err()
+    );
+    mock.assert_async().await;
+    cleanup_env();
+}
+
+#[tokio::test]
+async fn test_metrics_metadata_list() {
+    let _lock = lock_env();
+    let mut server = mockito::Server::new_async().await;
+    let cfg = test_config(&server.url());
+    let _list = server
+        .mock("GET", "/api/v1/metrics")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"from": "1700000000", "metrics": ["system.cpu.user", "app.requests"]}"#)
+        .create_async()
+        .await;
+    let cpu = server
+        .mock("GET", "/api/v1/metrics/system.cpu.user")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"type": "gauge", "unit": "percent"}"#)
+        .create_async()
+        .await;
+    let requests = server
+        .mock("GET", "/api/v1/metrics/app.requests")
+        .expect(0)
+        .create_async()
+        .await;
+
+    let result =
+        crate::commands::metrics::metadata_list(&cfg, Some("cpu".into()), "1h".into()).await;
+    assert!(
+        result.is_ok(),
+        "metrics metadata list failed: {:?}",
+        result.err()
+    );
+    cpu.assert_async().await;
+    requests.assert_async().await;
+    cleanup_env();
+}
+
+#[tokio::test]
+async fn test_metrics_metadata_list_reports_partial_failures() {
+    let _lock = lock_env();
+    let mut server = mockito::Server::new_async().await;
+    let cfg = test_config(&server.url());
+    let _list = server
+        .mock("GET", "/api/v1/metrics")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"from": "1700000000", "metrics": ["system.cpu.user", "app.requests"]}"#)
+        .create_async()
+        .await;
+    let cpu = server
+        .mock("GET", "/api/v1/metrics/system.cpu.user")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"type": "gauge", "unit": "percent"}"#)
+        .create_async()
+        .await;
+    let requests = server
+        .mock("GET", "/api/v1/metrics/app.requests")
+        .with_status(500)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"errors": ["boom"]}"#)
+        .create_async()
+        .await;
+
+    let err = crate::commands::metrics::metadata_list(&cfg, None, "1h".into())
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("1 of 2 metric(s): app.requests"), "{err}");
+    cpu.assert_async().await;
+    requests.assert_async().await;
+    cleanup_env();
+}
+
+#[tokio::test]
+async fn test_metrics_metadata_get() {
+    let _lock = lock_env();
+    let mut server = mockito::Server::new_async().await;
+    let cfg = test_config(&server.url());
+    let _mock = mock_any(
+        &mut server,
+        "GET",
+        r#"{"type": "gauge", "description": "CPU usage", "unit": "percent"}"#,
+    )
+    .await;
+
     let result = crate::commands::metrics::metadata_get(&cfg, "system.cpu.user").await;
     assert!(
         result.is_ok(),
@@ -582,6 +1600,8 @@ async fn test_events_search_requires_api_keys() {
         output_format: OutputFormat::Json,
         auto_approve: false,
         agent_mode: false,
+        table_max_width: None,
+        rate_limit_warning: false,
     };
 
     let result =
@@ -610,6 +1630,8 @@ async fn test_api_get() {
         output_format: OutputFormat::Json,
         auto_approve: false,
         agent_mode: false,
+        table_max_width: None,
+        rate_limit_warning: false,
     };
 
     let mock = server
@@ -642,6 +1664,8 @@ async fn test_api_get_with_query() {
         output_format: OutputFormat::Json,
         auto_approve: false,
         agent_mode: false,
+        table_max_width: None,
+        rate_limit_warning: false,
     };
 
     let mock = server
@@ -678,6 +1702,8 @@ async fn test_api_post() {
         output_format: OutputFormat::Json,
         auto_approve: false,
         agent_mode: false,
+        table_max_width: None,
+        rate_limit_warning: false,
     };
 
     let mock = server
@@ -709,6 +1735,8 @@ async fn test_api_put() {
         output_format: OutputFormat::Json,
         auto_approve: false,
         agent_mode: false,
+        table_max_width: None,
+        rate_limit_warning: false,
     };
 
     let mock = server
@@ -740,6 +1768,8 @@ async fn test_api_patch() {
         output_format: OutputFormat::Json,
         auto_approve: false,
         agent_mode: false,
+        table_max_width: None,
+        rate_limit_warning: false,
     };
 
     let mock = server
@@ -771,6 +1801,8 @@ async fn test_api_delete() {
         output_format: OutputFormat::Json,
         auto_approve: false,
         agent_mode: false,
+        table_max_width: None,
+        rate_limit_warning: false,
     };
 
     let mock = server
@@ -801,6 +1833,8 @@ async fn test_api_error_response() {
         output_format: OutputFormat::Json,
         auto_approve: false,
         agent_mode: false,
+        table_max_width: None,
+        rate_limit_warning: false,
     };
 
     let mock = server
@@ -832,6 +1866,8 @@ async fn test_api_bearer_auth() {
         output_format: OutputFormat::Json,
         auto_approve: false,
         agent_mode: false,
+        table_max_width: None,
+        rate_limit_warning: false,
     };
 
     let mock = server
@@ -861,6 +1897,8 @@ async fn test_api_no_auth() {
         output_format: OutputFormat::Json,
         auto_approve: false,
         agent_mode: false,
+        table_max_width: None,
+        rate_limit_warning: false,
     };
 
     let result = crate::api::get(&cfg, "/api/v1/test", &[]).await;
@@ -886,6 +1924,8 @@ async fn test_api_empty_response() {
         output_format: OutputFormat::Json,
         auto_approve: false,
         agent_mode: false,
+        table_max_width: None,
+        rate_limit_warning: false,
     };
 
     let mock = server
@@ -917,6 +1957,8 @@ async fn test_api_server_error() {
         output_format: OutputFormat::Json,
         auto_approve: false,
         agent_mode: false,
+        table_max_width: None,
+        rate_limit_warning: false,
     };
 
     let mock = server
@@ -982,6 +2024,159 @@ async fn test_rum_apps_delete() {
     let _ = crate::commands::rum::apps_delete(&cfg, "abc").await;
     cleanup_env();
 }
+#[tokio::test]
+async fn test_rum_apps_clone() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    let _get = s
+        .mock("GET", "/api/v2/rum/applications/abc")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"data": {"id": "abc", "attributes": {"application_id": "abc", "name": "web", "type": "browser", "client_token": "pub1"}}}"#,
+        )
+        .create_async()
+        .await;
+    let create = s
+        .mock("POST", "/api/v2/rum/applications")
+        .match_body(mockito::Matcher::JsonString(
+            r#"{"data": {"attributes": {"name": "web-copy", "type": "browser"}, "type": "rum_application_create"}}"#.into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": {"id": "def", "attributes": {"client_token": "pub2"}}}"#)
+        .create_async()
+        .await;
+    let result = crate::commands::rum::apps_clone(&cfg, "abc", "web-copy").await;
+    assert!(result.is_ok(), "rum apps clone failed: {:?}", result.err());
+    create.assert_async().await;
+    cleanup_env();
+}
+
+#[tokio::test]
+async fn test_rum_apps_update_fields() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    let update = s
+        .mock("PATCH", "/api/v2/rum/applications/abc")
+        .match_body(mockito::Matcher::JsonString(
+            r#"{"data": {"attributes": {"name": "web-v2"}, "id": "abc", "type": "rum_application_update"}}"#.into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"data": {"id": "abc", "type": "rum_application", "attributes": {"application_id": "abc", "client_token": "pub1", "created_at": 1, "created_by_handle": "a", "name": "web-v2", "org_id": 1, "type": "browser", "updated_at": 2, "updated_by_handle": "a"}}}"#,
+        )
+        .create_async()
+        .await;
+    let result =
+        crate::commands::rum::apps_update_fields(&cfg, "abc", Some("web-v2".into()), None).await;
+    assert!(result.is_ok(), "rum apps update failed: {:?}", result.err());
+    update.assert_async().await;
+
+    let empty = crate::commands::rum::apps_update_fields(&cfg, "abc", None, None).await;
+    assert!(empty.is_err());
+    cleanup_env();
+}
+
+#[tokio::test]
+async fn test_rum_sessions_export() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    let _first = s
+        .mock("POST", "/api/v2/rum/events/search")
+        .match_body(mockito::Matcher::JsonString(
+            r#"{"filter": {"query": "@type:session", "from": "2024-01-01T00:00:00+00:00", "to": "2024-01-02T00:00:00+00:00"}, "sort": "-timestamp", "page": {"limit": 1000}}"#.into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"data": [{"attributes": {"attributes": {"session": {"id": "s1", "duration": 10}}}}], "meta": {"page": {"after": "c1"}}}"#,
+        )
+        .create_async()
+        .await;
+    let _second = s
+        .mock("POST", "/api/v2/rum/events/search")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"page": {"cursor": "c1"}}"#.into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"data": [{"attributes": {"attributes": {"session": {"id": "s2"}, "usr": {"id": "u2"}}}}], "meta": {"page": {}}}"#,
+        )
+        .create_async()
+        .await;
+    let path = std::env::temp_dir().join("pup_test_rum_sessions_export.csv");
+    let path_str = path.to_string_lossy().to_string();
+    let result = crate::commands::rum::sessions_export(
+        &cfg,
+        None,
+        "2024-01-01T00:00:00Z".into(),
+        "2024-01-02T00:00:00Z".into(),
+        &path_str,
+    )
+    .await;
+    assert!(result.is_ok(), "sessions export failed: {:?}", result.err());
+    let csv = std::fs::read_to_string(&path).unwrap();
+    let _ = std::fs::remove_file(&path);
+    assert_eq!(
+        csv,
+        "session.id,session.duration,session.page_count,session.error_count,usr.id\ns1,10,,,\ns2,,,,u2\n"
+    );
+    cleanup_env();
+}
+
+#[tokio::test]
+async fn test_rum_sessions_export_stops_on_repeated_cursor() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    let first = s
+        .mock("POST", "/api/v2/rum/events/search")
+        .match_body(mockito::Matcher::JsonString(
+            r#"{"filter": {"query": "@type:session", "from": "2024-01-01T00:00:00+00:00", "to": "2024-01-02T00:00:00+00:00"}, "sort": "-timestamp", "page": {"limit": 1000}}"#.into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": [], "meta": {"page": {"after": "c1"}}}"#)
+        .expect(1)
+        .create_async()
+        .await;
+    let stuck = s
+        .mock("POST", "/api/v2/rum/events/search")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"page": {"cursor": "c1"}}"#.into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": [], "meta": {"page": {"after": "c1"}}}"#)
+        .expect(1)
+        .create_async()
+        .await;
+    let path = std::env::temp_dir().join("pup_test_rum_sessions_export_repeat.csv");
+    let result = crate::commands::rum::sessions_export(
+        &cfg,
+        None,
+        "2024-01-01T00:00:00Z".into(),
+        "2024-01-02T00:00:00Z".into(),
+        &path.to_string_lossy(),
+    )
+    .await;
+    let _ = std::fs::remove_file(&path);
+    let err = result.unwrap_err().to_string();
+    assert!(
+        err.contains("cursor \"c1\" again"),
+        "unexpected error: {err}"
+    );
+    first.assert_async().await;
+    stuck.assert_async().await;
+    cleanup_env();
+}
+
 #[tokio::test]
 async fn test_rum_metrics_list() {
     let _lock = lock_env();
@@ -1037,6 +2232,98 @@ async fn test_rum_playlists_list() {
     cleanup_env();
 }
 
+#[tokio::test]
+async fn test_rum_events_aggregate() {
+    let _lock = lock_env();
+    let mut server = mockito::Server::new_async().await;
+    let cfg = test_config(&server.url());
+    let _mock = server
+        .mock("POST", "/api/v2/rum/analytics/aggregate")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"compute": [{"aggregation": "pc95", "metric": "@view.loading_time", "type": "total"}], "group_by": [{"facet": "@browser.name", "limit": 5}]}"#.into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": {"buckets": []}}"#)
+        .create_async()
+        .await;
+    let result = crate::commands::rum::events_aggregate(
+        &cfg,
+        None,
+        "1h".into(),
+        "now".into(),
+        "pc95:@view.loading_time".into(),
+        Some("@browser.name".into()),
+        5,
+    )
+    .await;
+    assert!(result.is_ok(), "rum aggregate failed: {:?}", result.err());
+    cleanup_env();
+}
+
+#[tokio::test]
+async fn test_product_analytics_events_list() {
+    let _lock = lock_env();
+    let mut server = mockito::Server::new_async().await;
+    let cfg = test_config(&server.url());
+    let mock = server
+        .mock("GET", "/api/v2/rum/events")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("filter[query]".into(), "@application.id:app-1".into()),
+            mockito::Matcher::UrlEncoded("page[limit]".into(), "25".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": []}"#)
+        .create_async()
+        .await;
+    let result = crate::commands::product_analytics::events_list(
+        &cfg,
+        "app-1",
+        "1h".into(),
+        "now".into(),
+        25,
+    )
+    .await;
+    assert!(result.is_ok(), "events list failed: {:?}", result.err());
+    mock.assert_async().await;
+    cleanup_env();
+}
+
+#[tokio::test]
+async fn test_product_analytics_events_aggregate() {
+    let _lock = lock_env();
+    let mut server = mockito::Server::new_async().await;
+    let cfg = test_config(&server.url());
+    let mock = server
+        .mock("POST", "/api/v2/rum/analytics/aggregate")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"filter": {"query": "@application.id:app-1 @type:action"}, "compute": [{"aggregation": "cardinality", "metric": "@usr.id"}], "group_by": [{"facet": "@action.name"}]}"#.into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": {"buckets": []}}"#)
+        .create_async()
+        .await;
+    let result = crate::commands::product_analytics::events_aggregate(
+        &cfg,
+        "app-1",
+        Some("@type:action".into()),
+        "1d".into(),
+        "now".into(),
+        "cardinality:@usr.id".into(),
+        Some("@action.name".into()),
+    )
+    .await;
+    assert!(
+        result.is_ok(),
+        "events aggregate failed: {:?}",
+        result.err()
+    );
+    mock.assert_async().await;
+    cleanup_env();
+}
+
 // --- Status Pages ---
 #[tokio::test]
 async fn test_status_pages_list() {
@@ -1139,6 +2426,85 @@ async fn test_cases_projects_delete() {
     let _ = crate::commands::cases::projects_delete(&cfg, "proj1").await;
     cleanup_env();
 }
+#[tokio::test]
+async fn test_cases_bulk_update_status() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    let case_ids: Vec<String> = (1..=20).map(|i| format!("case{i}")).collect();
+    let mut mocks = Vec::new();
+    for id in &case_ids {
+        mocks.push(
+            s.mock("POST", format!("/api/v2/cases/{id}/status").as_str())
+                .match_body(mockito::Matcher::PartialJsonString(
+                    r#"{"data": {"attributes": {"status": "CLOSED"}, "type": "case"}}"#.into(),
+                ))
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(format!(
+                    r#"{{"data": {{"id": "{id}", "type": "case", "attributes": {{"status": "CLOSED"}}}}}}"#
+                ))
+                .expect(1)
+                .create_async()
+                .await,
+        );
+    }
+    let result = crate::commands::cases::bulk_update_status(&cfg, case_ids, "CLOSED").await;
+    assert!(result.is_ok(), "bulk update failed: {:?}", result.err());
+    for m in &mocks {
+        m.assert_async().await;
+    }
+    cleanup_env();
+}
+
+#[tokio::test]
+async fn test_cases_bulk_update_status_reports_failures() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    let ok = s
+        .mock("POST", "/api/v2/cases/case1/status")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"data": {"id": "case1", "type": "case", "attributes": {"status": "CLOSED"}}}"#,
+        )
+        .expect(1)
+        .create_async()
+        .await;
+    let missing = s
+        .mock("POST", "/api/v2/cases/case2/status")
+        .with_status(404)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"errors": ["Not Found"]}"#)
+        .expect(1)
+        .create_async()
+        .await;
+    let err = crate::commands::cases::bulk_update_status(
+        &cfg,
+        vec!["case1".into(), "case2".into()],
+        "CLOSED",
+    )
+    .await
+    .unwrap_err()
+    .to_string();
+    assert_eq!(err, "1 of 2 case status updates failed");
+    ok.assert_async().await;
+    missing.assert_async().await;
+    cleanup_env();
+}
+#[tokio::test]
+async fn test_cases_bulk_update_status_invalid_status() {
+    let _lock = lock_env();
+    let result = crate::commands::cases::bulk_update_status(
+        &test_config("http://127.0.0.1:1"),
+        vec!["case1".into()],
+        "DONE",
+    )
+    .await;
+    assert!(result.is_err(), "invalid status should be rejected");
+    cleanup_env();
+}
 
 // --- Integrations ---
 #[tokio::test]
@@ -1187,508 +2553,2490 @@ async fn test_integrations_slack_list() {
     cleanup_env();
 }
 #[tokio::test]
-async fn test_integrations_webhooks_list() {
+async fn test_integrations_slack_workspaces_list() {
     let _lock = lock_env();
     let mut s = mockito::Server::new_async().await;
     let cfg = test_config(&s.url());
-    mock_all(&mut s, r#"{"data": []}"#).await;
-    let _ = crate::commands::integrations::webhooks_list(&cfg).await;
+    let mock = s
+        .mock("GET", "/api/v1/integration/slack")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"[]"#)
+        .create_async()
+        .await;
+    let result = crate::commands::integrations::slack_workspaces_list(&cfg).await;
+    assert!(
+        result.is_ok(),
+        "slack workspaces list failed: {:?}",
+        result.err()
+    );
+    mock.assert_async().await;
     cleanup_env();
 }
-
-// --- CI/CD ---
 #[tokio::test]
-async fn test_cicd_pipelines_list() {
+async fn test_integrations_slack_channels_get() {
     let _lock = lock_env();
     let mut s = mockito::Server::new_async().await;
     let cfg = test_config(&s.url());
-    mock_all(&mut s, r#"{"data": []}"#).await;
-    let _ = crate::commands::cicd::pipelines_list(&cfg, None, "1h".into(), "now".into(), 10).await;
+    let _m = s
+        .mock(
+            "GET",
+            "/api/v1/integration/slack/configuration/accounts/acme/channels/alerts",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"name": "alerts", "display": {"message": true}}"#)
+        .create_async()
+        .await;
+    let result = crate::commands::integrations::slack_channels_get(&cfg, "acme", "alerts").await;
+    assert!(
+        result.is_ok(),
+        "slack channel get failed: {:?}",
+        result.err()
+    );
     cleanup_env();
 }
 #[tokio::test]
-async fn test_cicd_tests_list() {
+async fn test_integrations_ms_teams_tenants_get() {
     let _lock = lock_env();
     let mut s = mockito::Server::new_async().await;
     let cfg = test_config(&s.url());
-    mock_all(&mut s, r#"{"data": []}"#).await;
-    let _ = crate::commands::cicd::tests_list(&cfg, None, "1h".into(), "now".into(), 10).await;
+    let m = s
+        .mock(
+            "GET",
+            "/api/v2/integration/ms-teams/configuration/tenant-based-handles",
+        )
+        .match_query(mockito::Matcher::UrlEncoded("tenant_id".into(), "t1".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"data": [{"id": "h1", "type": "tenant-based-handle-info", "attributes": {"name": "alerts", "tenant_id": "t1", "tenant_name": "Acme"}}]}"#,
+        )
+        .create_async()
+        .await;
+    let result = crate::commands::integrations::ms_teams_tenants_get(&cfg, "t1").await;
+    assert!(result.is_ok(), "tenant get failed: {:?}", result.err());
+    m.assert_async().await;
     cleanup_env();
 }
-
-// --- Fleet ---
 #[tokio::test]
-async fn test_fleet_agents_list() {
+async fn test_integrations_ms_teams_channels_delete() {
     let _lock = lock_env();
     let mut s = mockito::Server::new_async().await;
     let cfg = test_config(&s.url());
-    mock_all(&mut s, r#"{"data": []}"#).await;
-    let _ = crate::commands::fleet::agents_list(&cfg, None).await;
+    let m = s
+        .mock(
+            "DELETE",
+            "/api/v2/integration/ms-teams/configuration/tenant-based-handles/h1",
+        )
+        .with_status(204)
+        .create_async()
+        .await;
+    let result = crate::commands::integrations::ms_teams_channels_delete(&cfg, "h1").await;
+    assert!(result.is_ok(), "channel delete failed: {:?}", result.err());
+    m.assert_async().await;
     cleanup_env();
 }
 #[tokio::test]
-async fn test_fleet_agents_get() {
+async fn test_integrations_pagerduty_get() {
     let _lock = lock_env();
     let mut s = mockito::Server::new_async().await;
     let cfg = test_config(&s.url());
-    mock_all(&mut s, r#"{"data": {}}"#).await;
-    let _ = crate::commands::fleet::agents_get(&cfg, "a1").await;
+    let _m = s
+        .mock(
+            "GET",
+            "/api/v1/integration/pagerduty/configuration/services/payments",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"service_name": "payments"}"#)
+        .create_async()
+        .await;
+    let result = crate::commands::integrations::pagerduty_get(&cfg, "payments").await;
+    assert!(result.is_ok(), "pagerduty get failed: {:?}", result.err());
     cleanup_env();
 }
 #[tokio::test]
-async fn test_fleet_agents_versions() {
+async fn test_integrations_pagerduty_delete() {
     let _lock = lock_env();
     let mut s = mockito::Server::new_async().await;
     let cfg = test_config(&s.url());
-    mock_all(&mut s, r#"{"data": []}"#).await;
-    let _ = crate::commands::fleet::agents_versions(&cfg).await;
+    let mock = s
+        .mock(
+            "DELETE",
+            "/api/v1/integration/pagerduty/configuration/services/payments",
+        )
+        .with_status(204)
+        .create_async()
+        .await;
+    let result = crate::commands::integrations::pagerduty_delete(&cfg, "payments").await;
+    assert!(
+        result.is_ok(),
+        "pagerduty delete failed: {:?}",
+        result.err()
+    );
+    mock.assert_async().await;
     cleanup_env();
 }
+
 #[tokio::test]
-async fn test_fleet_deployments_list() {
+async fn test_integrations_webhooks_list() {
     let _lock = lock_env();
     let mut s = mockito::Server::new_async().await;
     let cfg = test_config(&s.url());
     mock_all(&mut s, r#"{"data": []}"#).await;
-    let _ = crate::commands::fleet::deployments_list(&cfg, None).await;
+    let _ = crate::commands::integrations::webhooks_list(&cfg).await;
     cleanup_env();
 }
+
 #[tokio::test]
-async fn test_fleet_schedules_list() {
+async fn test_integrations_webhooks_create_from_flags() {
     let _lock = lock_env();
     let mut s = mockito::Server::new_async().await;
     let cfg = test_config(&s.url());
-    mock_all(&mut s, r#"{"data": []}"#).await;
-    let _ = crate::commands::fleet::schedules_list(&cfg).await;
+    let _m = s
+        .mock(
+            "POST",
+            "/api/v1/integration/webhooks/configuration/webhooks",
+        )
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"name": "deploy-hook", "url": "https://example.com/hook"}"#.into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"name": "deploy-hook", "url": "https://example.com/hook"}"#)
+        .create_async()
+        .await;
+    let result = crate::commands::integrations::webhooks_create(
+        &cfg,
+        None,
+        Some("deploy-hook".into()),
+        Some("https://example.com/hook".into()),
+    )
+    .await;
+    assert!(result.is_ok(), "webhook create failed: {:?}", result.err());
     cleanup_env();
 }
-
-// --- Incidents ---
 #[tokio::test]
-async fn test_incidents_list() {
+async fn test_integrations_webhooks_create_requires_url() {
     let _lock = lock_env();
-    let mut s = mockito::Server::new_async().await;
-    let cfg = test_config(&s.url());
-    mock_all(&mut s, r#"{"data": []}"#).await;
-    let _ = crate::commands::incidents::list(&cfg, 10).await;
+    let cfg = test_config("http://unused.local");
+    let result =
+        crate::commands::integrations::webhooks_create(&cfg, None, Some("x".into()), None).await;
+    assert!(result.is_err());
     cleanup_env();
 }
 #[tokio::test]
-async fn test_incidents_get() {
+async fn test_integrations_webhooks_delete() {
     let _lock = lock_env();
     let mut s = mockito::Server::new_async().await;
     let cfg = test_config(&s.url());
-    mock_all(&mut s, r#"{"data": {}}"#).await;
-    let _ = crate::commands::incidents::get(&cfg, "inc1").await;
+    let mock = s
+        .mock(
+            "DELETE",
+            "/api/v1/integration/webhooks/configuration/webhooks/deploy-hook",
+        )
+        .with_status(204)
+        .create_async()
+        .await;
+    let result = crate::commands::integrations::webhooks_delete(&cfg, "deploy-hook").await;
+    assert!(result.is_ok(), "webhooks delete failed: {:?}", result.err());
+    mock.assert_async().await;
     cleanup_env();
 }
+
+// --- CI/CD ---
 #[tokio::test]
-async fn test_incidents_settings_get() {
+async fn test_cicd_pipelines_list() {
     let _lock = lock_env();
     let mut s = mockito::Server::new_async().await;
     let cfg = test_config(&s.url());
-    mock_all(&mut s, r#"{"data": {}}"#).await;
-    let _ = crate::commands::incidents::settings_get(&cfg).await;
+    mock_all(&mut s, r#"{"data": []}"#).await;
+    let _ = crate::commands::cicd::pipelines_list(&cfg, None, "1h".into(), "now".into(), 10).await;
     cleanup_env();
 }
 #[tokio::test]
-async fn test_incidents_handles_list() {
+async fn test_cicd_tests_list() {
     let _lock = lock_env();
     let mut s = mockito::Server::new_async().await;
     let cfg = test_config(&s.url());
     mock_all(&mut s, r#"{"data": []}"#).await;
-    let _ = crate::commands::incidents::handles_list(&cfg).await;
+    let _ = crate::commands::cicd::tests_list(&cfg, None, "1h".into(), "now".into(), 10).await;
     cleanup_env();
 }
+
 #[tokio::test]
-async fn test_incidents_postmortem_templates_list() {
+async fn test_code_coverage_commit_summary() {
     let _lock = lock_env();
     let mut s = mockito::Server::new_async().await;
     let cfg = test_config(&s.url());
-    mock_all(&mut s, r#"{"data": []}"#).await;
-    let _ = crate::commands::incidents::postmortem_templates_list(&cfg).await;
+    let m = s
+        .mock("POST", "/api/v2/code-coverage/commit/summary")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"data": {"type": "ci_app_coverage_commit_summary_request", "attributes": {"commit_sha": "abc123", "repository_id": "github.com/org/repo"}}}"#.into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": {"id": "abc123", "type": "ci_app_coverage_summary"}}"#)
+        .create_async()
+        .await;
+    let result = crate::commands::code_coverage::commit_summary(
+        &cfg,
+        "github.com/org/repo".into(),
+        "abc123".into(),
+    )
+    .await;
+    assert!(result.is_ok(), "commit summary failed: {:?}", result.err());
+    m.assert_async().await;
     cleanup_env();
 }
 
-// --- On-Call ---
 #[tokio::test]
-async fn test_on_call_teams_list() {
+async fn test_cicd_dora_create_deployment() {
     let _lock = lock_env();
     let mut s = mockito::Server::new_async().await;
     let cfg = test_config(&s.url());
-    mock_all(&mut s, r#"{"data": []}"#).await;
-    let _ = crate::commands::on_call::teams_list(&cfg).await;
+    let m = s
+        .mock("POST", "/api/v2/dora/deployment")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"data": {"attributes": {"service": "web-store", "env": "prod", "version": "v1.2.0", "started_at": 1700000000000000000, "finished_at": 1700000060000000000}}}"#
+                .into(),
+        ))
+        .with_status(202)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": {"id": "dep-1", "type": "dora_deployment"}}"#)
+        .create_async()
+        .await;
+    let args = crate::commands::cicd::DoraDeploymentArgs {
+        service: Some("web-store".into()),
+        version: Some("v1.2.0".into()),
+        repository_url: None,
+        commit_sha: None,
+        env: Some("prod".into()),
+        started_at: Some(1_700_000_000_000_000_000),
+        finished_at: Some(1_700_000_060_000_000_000),
+    };
+    let result = crate::commands::cicd::dora_create_deployment(&cfg, args, None).await;
+    assert!(
+        result.is_ok(),
+        "create deployment failed: {:?}",
+        result.err()
+    );
+    m.assert_async().await;
     cleanup_env();
 }
+
 #[tokio::test]
-async fn test_on_call_teams_get() {
+async fn test_cicd_flaky_tests_search_all_pages() {
     let _lock = lock_env();
     let mut s = mockito::Server::new_async().await;
     let cfg = test_config(&s.url());
-    mock_all(&mut s, r#"{"data": {}}"#).await;
-    let _ = crate::commands::on_call::teams_get(&cfg, "t1").await;
+    let first = s
+        .mock("POST", "/api/v2/ci/tests/flaky")
+        .match_body(mockito::Matcher::JsonString(
+            r#"{"data": {"attributes": {"page": {"limit": 2}}, "type": "search_flaky_tests_request"}}"#.into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"data": [{"id": "t1"}, {"id": "t2"}], "meta": {"pagination": {"next_page": "c2"}}}"#,
+        )
+        .create_async()
+        .await;
+    let second = s
+        .mock("POST", "/api/v2/ci/tests/flaky")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"data": {"attributes": {"page": {"cursor": "c2", "limit": 2}}}}"#.into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": [{"id": "t3"}], "meta": {"pagination": {}}}"#)
+        .create_async()
+        .await;
+    let result =
+        crate::commands::cicd::flaky_tests_search(&cfg, None, None, 2, false, None, true).await;
+    assert!(
+        result.is_ok(),
+        "flaky tests search failed: {:?}",
+        result.err()
+    );
+    second.assert_async().await;
+    first.assert_async().await;
     cleanup_env();
 }
+
 #[tokio::test]
-async fn test_on_call_teams_delete() {
+async fn test_cicd_flaky_tests_search_stops_on_repeated_cursor() {
     let _lock = lock_env();
     let mut s = mockito::Server::new_async().await;
     let cfg = test_config(&s.url());
-    mock_all(&mut s, r#"{}"#).await;
-    let _ = crate::commands::on_call::teams_delete(&cfg, "t1").await;
+    let first = s
+        .mock("POST", "/api/v2/ci/tests/flaky")
+        .match_body(mockito::Matcher::JsonString(
+            r#"{"data": {"attributes": {"page": {"limit": 2}}, "type": "search_flaky_tests_request"}}"#.into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": [{"id": "t1"}], "meta": {"pagination": {"next_page": "c2"}}}"#)
+        .expect(1)
+        .create_async()
+        .await;
+    let stuck = s
+        .mock("POST", "/api/v2/ci/tests/flaky")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"data": {"attributes": {"page": {"cursor": "c2"}}}}"#.into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": [{"id": "t2"}], "meta": {"pagination": {"next_page": "c2"}}}"#)
+        .expect(1)
+        .create_async()
+        .await;
+    let err = crate::commands::cicd::flaky_tests_search(&cfg, None, None, 2, false, None, true)
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(
+        err.contains("cursor \"c2\" again"),
+        "unexpected error: {err}"
+    );
+    first.assert_async().await;
+    stuck.assert_async().await;
     cleanup_env();
 }
 
-// --- Security ---
+// --- Fleet ---
 #[tokio::test]
-async fn test_security_rules_list() {
+async fn test_fleet_agents_list() {
     let _lock = lock_env();
     let mut s = mockito::Server::new_async().await;
     let cfg = test_config(&s.url());
     mock_all(&mut s, r#"{"data": []}"#).await;
-    let _ = crate::commands::security::rules_list(&cfg).await;
+    let _ = crate::commands::fleet::agents_list(&cfg, None).await;
     cleanup_env();
 }
 #[tokio::test]
-async fn test_security_rules_get() {
+async fn test_fleet_agents_get() {
     let _lock = lock_env();
     let mut s = mockito::Server::new_async().await;
     let cfg = test_config(&s.url());
     mock_all(&mut s, r#"{"data": {}}"#).await;
-    let _ = crate::commands::security::rules_get(&cfg, "r1").await;
+    let _ = crate::commands::fleet::agents_get(&cfg, "a1").await;
     cleanup_env();
 }
 #[tokio::test]
-async fn test_security_content_packs_list() {
+async fn test_fleet_agents_versions() {
     let _lock = lock_env();
     let mut s = mockito::Server::new_async().await;
     let cfg = test_config(&s.url());
     mock_all(&mut s, r#"{"data": []}"#).await;
-    let _ = crate::commands::security::content_packs_list(&cfg).await;
+    let _ = crate::commands::fleet::agents_versions(&cfg).await;
     cleanup_env();
 }
-
-// --- Synthetics ---
 #[tokio::test]
-async fn test_synthetics_tests_list() {
+async fn test_fleet_agents_update_config() {
     let _lock = lock_env();
     let mut s = mockito::Server::new_async().await;
-    let cfg = test_config(&s.url());
-    mock_all(&mut s, r#"{"tests": []}"#).await;
-    let _ = crate::commands::synthetics::tests_list(&cfg).await;
+    let mut cfg = test_config(&s.url());
+    cfg.auto_approve = true;
+    let list = s
+        .mock("GET", "/api/unstable/fleet/agents")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "filter".into(),
+            "env:prod".into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"data": {"id": "agents", "type": "agents", "attributes": {"agents": [{"hostname": "web-1"}]}}, "meta": {"total_filtered_count": 2}}"#,
+        )
+        .create_async()
+        .await;
+    let deploy = s
+        .mock("POST", "/api/unstable/fleet/deployments/configure")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"data": {"attributes": {"filter_query": "env:prod", "config_operations": [{"patch": {"log_level": "debug"}}]}}}"#.into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": {"id": "dep-1", "type": "deployment", "attributes": {"filter_query": "env:prod"}}}"#)
+        .create_async()
+        .await;
+
+    let result = crate::commands::fleet::agents_update_config(
+        &cfg,
+        Some("env:prod".into()),
+        "log_level",
+        "debug",
+    )
+    .await;
+    assert!(result.is_ok(), "update-config failed: {:?}", result.err());
+    list.assert_async().await;
+    deploy.assert_async().await;
     cleanup_env();
 }
+
 #[tokio::test]
-async fn test_synthetics_tests_get() {
+async fn test_fleet_agents_update_config_no_matches_skips_deploy() {
     let _lock = lock_env();
     let mut s = mockito::Server::new_async().await;
-    let cfg = test_config(&s.url());
-    mock_all(&mut s, r#"{}"#).await;
-    let _ = crate::commands::synthetics::tests_get(&cfg, "pub1").await;
+    let mut cfg = test_config(&s.url());
+    cfg.auto_approve = true;
+    let _list = s
+        .mock("GET", "/api/unstable/fleet/agents")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"data": {"id": "agents", "type": "agents", "attributes": {"agents": []}}, "meta": {"total_filtered_count": 0}}"#,
+        )
+        .create_async()
+        .await;
+    let deploy = s
+        .mock("POST", "/api/unstable/fleet/deployments/configure")
+        .expect(0)
+        .create_async()
+        .await;
+
+    let result =
+        crate::commands::fleet::agents_update_config(&cfg, None, "log_level", "debug").await;
+    assert!(result.is_ok(), "update-config failed: {:?}", result.err());
+    deploy.assert_async().await;
     cleanup_env();
 }
+
 #[tokio::test]
-async fn test_synthetics_locations_list() {
+async fn test_fleet_agents_bulk_update_tags_merges_current_tags() {
     let _lock = lock_env();
     let mut s = mockito::Server::new_async().await;
-    let cfg = test_config(&s.url());
-    mock_all(&mut s, r#"{"locations": []}"#).await;
-    let _ = crate::commands::synthetics::locations_list(&cfg).await;
+    let mut cfg = test_config(&s.url());
+    cfg.auto_approve = true;
+    let count = s
+        .mock("GET", "/api/unstable/fleet/agents")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("filter".into(), "env:prod".into()),
+            mockito::Matcher::UrlEncoded("page_size".into(), "1".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"data": {"id": "agents", "type": "agents", "attributes": {"agents": [{"hostname": "web-1"}]}}, "meta": {"total_filtered_count": 1}}"#,
+        )
+        .create_async()
+        .await;
+    let list = s
+        .mock("GET", "/api/unstable/fleet/agents")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("filter".into(), "env:prod".into()),
+            mockito::Matcher::UrlEncoded("page_size".into(), "100".into()),
+            mockito::Matcher::UrlEncoded("page_number".into(), "0".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"data": {"id": "agents", "type": "agents", "attributes": {"agents": [{"hostname": "web-1", "tags": [{"key": "env", "value": "prod"}, {"key": "team", "value": "old"}]}]}}}"#,
+        )
+        .create_async()
+        .await;
+    let deploy = s
+        .mock("POST", "/api/unstable/fleet/deployments/configure")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"data": {"attributes": {"filter_query": "hostname:web-1", "config_operations": [{"file_op": "merge-patch", "file_path": "/datadog.yaml", "patch": {"tags": ["env:prod", "team:old", "team:core"], "extra_tags": null}}]}}}"#.into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": {"id": "dep-1", "type": "deployment", "attributes": {"filter_query": "hostname:web-1"}}}"#)
+        .create_async()
+        .await;
+
+    let result = crate::commands::fleet::agents_bulk_update_tags(
+        &cfg,
+        "env:prod",
+        &["team:core".to_string()],
+        false,
+    )
+    .await;
+    assert!(
+        result.is_ok(),
+        "bulk-update-tags failed: {:?}",
+        result.err()
+    );
+    count.assert_async().await;
+    list.assert_async().await;
+    deploy.assert_async().await;
     cleanup_env();
 }
 
-// --- App Keys ---
 #[tokio::test]
-async fn test_app_keys_list() {
+async fn test_fleet_agents_bulk_update_tags_replace() {
     let _lock = lock_env();
     let mut s = mockito::Server::new_async().await;
-    let cfg = test_config(&s.url());
-    mock_all(&mut s, r#"{"data": []}"#).await;
-    let _ = crate::commands::app_keys::list(&cfg, 10, 0).await;
+    let mut cfg = test_config(&s.url());
+    cfg.auto_approve = true;
+    let count = s
+        .mock("GET", "/api/unstable/fleet/agents")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "filter".into(),
+            "env:prod".into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"data": {"id": "agents", "type": "agents", "attributes": {"agents": [{"hostname": "web-1"}]}}, "meta": {"total_filtered_count": 3}}"#,
+        )
+        .create_async()
+        .await;
+    let deploy = s
+        .mock("POST", "/api/unstable/fleet/deployments/configure")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"data": {"attributes": {"filter_query": "env:prod", "config_operations": [{"file_op": "merge-patch", "file_path": "/datadog.yaml", "patch": {"tags": ["team:core"], "extra_tags": null}}]}}}"#.into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": {"id": "dep-1", "type": "deployment", "attributes": {"filter_query": "env:prod"}}}"#)
+        .create_async()
+        .await;
+
+    let result = crate::commands::fleet::agents_bulk_update_tags(
+        &cfg,
+        "env:prod",
+        &["team:core".to_string()],
+        true,
+    )
+    .await;
+    assert!(
+        result.is_ok(),
+        "bulk-update-tags failed: {:?}",
+        result.err()
+    );
+    count.assert_async().await;
+    deploy.assert_async().await;
     cleanup_env();
 }
+
 #[tokio::test]
-async fn test_app_keys_get() {
+async fn test_fleet_deployments_list() {
     let _lock = lock_env();
     let mut s = mockito::Server::new_async().await;
     let cfg = test_config(&s.url());
-    mock_all(&mut s, r#"{"data": {}}"#).await;
-    let _ = crate::commands::app_keys::get(&cfg, "k1").await;
+    mock_all(&mut s, r#"{"data": []}"#).await;
+    let _ = crate::commands::fleet::deployments_list(&cfg, None).await;
     cleanup_env();
 }
 #[tokio::test]
-async fn test_app_keys_unregister() {
+async fn test_fleet_schedules_list() {
     let _lock = lock_env();
     let mut s = mockito::Server::new_async().await;
     let cfg = test_config(&s.url());
-    mock_all(&mut s, r#"{}"#).await;
-    let _ = crate::commands::app_keys::unregister(&cfg, "k1").await;
+    mock_all(&mut s, r#"{"data": []}"#).await;
+    let _ = crate::commands::fleet::schedules_list(&cfg).await;
     cleanup_env();
 }
 
-// --- API Keys ---
+// --- Incidents ---
 #[tokio::test]
-async fn test_api_keys_list() {
+async fn test_incidents_list_follows_pagination() {
     let _lock = lock_env();
     let mut s = mockito::Server::new_async().await;
     let cfg = test_config(&s.url());
-    mock_all(&mut s, r#"{"data": []}"#).await;
-    let _ = crate::commands::api_keys::list(&cfg).await;
-    cleanup_env();
-}
-#[tokio::test]
-async fn test_api_keys_get() {
-    let _lock = lock_env();
-    let mut s = mockito::Server::new_async().await;
+    let first = s
+        .mock("GET", "/api/v2/incidents")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("page[size]".into(), "25".into()),
+            mockito::Matcher::UrlEncoded("page[offset]".into(), "0".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": [{"id": "i1"}], "meta": {"pagination": {"next_offset": 25}}}"#)
+        .create_async()
+        .await;
+    let second = s
+        .mock("GET", "/api/v2/incidents")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "page[offset]".into(),
+            "25".into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": [{"id": "i2"}], "meta": {"pagination": {}}}"#)
+        .create_async()
+        .await;
+    let result = crate::commands::incidents::list(&cfg, 30).await;
+    assert!(result.is_ok(), "incidents list failed: {:?}", result.err());
+    first.assert_async().await;
+    second.assert_async().await;
+    cleanup_env();
+}
+
+#[tokio::test]
+async fn test_incidents_list() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    mock_all(&mut s, r#"{"data": []}"#).await;
+    let _ = crate::commands::incidents::list(&cfg, 10).await;
+    cleanup_env();
+}
+#[tokio::test]
+async fn test_incidents_get() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
     let cfg = test_config(&s.url());
     mock_all(&mut s, r#"{"data": {}}"#).await;
-    let _ = crate::commands::api_keys::get(&cfg, "k1").await;
+    let _ = crate::commands::incidents::get(&cfg, "inc1").await;
     cleanup_env();
 }
 #[tokio::test]
-async fn test_api_keys_delete() {
+async fn test_incidents_settings_get() {
     let _lock = lock_env();
     let mut s = mockito::Server::new_async().await;
     let cfg = test_config(&s.url());
-    mock_all(&mut s, r#"{}"#).await;
-    let _ = crate::commands::api_keys::delete(&cfg, "k1").await;
+    mock_all(&mut s, r#"{"data": {}}"#).await;
+    let _ = crate::commands::incidents::settings_get(&cfg).await;
+    cleanup_env();
+}
+#[tokio::test]
+async fn test_incidents_handles_list() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    mock_all(&mut s, r#"{"data": []}"#).await;
+    let _ = crate::commands::incidents::handles_list(&cfg).await;
+    cleanup_env();
+}
+#[tokio::test]
+async fn test_incidents_postmortem_templates_list() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    mock_all(&mut s, r#"{"data": []}"#).await;
+    let _ = crate::commands::incidents::postmortem_templates_list(&cfg).await;
     cleanup_env();
 }
 
-// --- Audit Logs ---
 #[tokio::test]
-async fn test_audit_logs_list() {
+async fn test_incidents_integrations_list() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    let m = s
+        .mock("GET", "/api/v2/incidents/inc1/relationships/integrations")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": []}"#)
+        .create_async()
+        .await;
+    let result = crate::commands::incidents::integrations_list(&cfg, "inc1").await;
+    assert!(
+        result.is_ok(),
+        "integrations list failed: {:?}",
+        result.err()
+    );
+    m.assert_async().await;
+    cleanup_env();
+}
+#[tokio::test]
+async fn test_incidents_integrations_create() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    let m = s
+        .mock("POST", "/api/v2/incidents/inc1/relationships/integrations")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"data": {"type": "incident_integrations", "attributes": {"integration_type": 1}}}"#
+                .into(),
+        ))
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": {"id": "i1", "type": "incident_integrations"}}"#)
+        .create_async()
+        .await;
+    let file = std::env::temp_dir().join("pup_test_incident_integration.json");
+    std::fs::write(
+        &file,
+        r#"{"data": {"type": "incident_integrations", "attributes": {"integration_type": 1, "metadata": {"issues": []}}}}"#,
+    )
+    .unwrap();
+    let result =
+        crate::commands::incidents::integrations_create(&cfg, "inc1", file.to_str().unwrap()).await;
+    let _ = std::fs::remove_file(&file);
+    assert!(
+        result.is_ok(),
+        "integrations create failed: {:?}",
+        result.err()
+    );
+    m.assert_async().await;
+    cleanup_env();
+}
+
+// --- On-Call ---
+#[tokio::test]
+async fn test_on_call_teams_list() {
     let _lock = lock_env();
     let mut s = mockito::Server::new_async().await;
     let cfg = test_config(&s.url());
     mock_all(&mut s, r#"{"data": []}"#).await;
-    let _ = crate::commands::audit_logs::list(&cfg, "1h".into(), "now".into(), 10).await;
+    let _ = crate::commands::on_call::teams_list(&cfg).await;
+    cleanup_env();
+}
+#[tokio::test]
+async fn test_on_call_teams_get() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    mock_all(&mut s, r#"{"data": {}}"#).await;
+    let _ = crate::commands::on_call::teams_get(&cfg, "t1", false, false).await;
+    cleanup_env();
+}
+#[tokio::test]
+async fn test_on_call_teams_get_with_includes() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    let mock = s
+        .mock("GET", "/api/v2/team/t1")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "include".into(),
+            "links,memberships".into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": {"id": "t1"}, "included": []}"#)
+        .create_async()
+        .await;
+    let result = crate::commands::on_call::teams_get(&cfg, "t1", true, true).await;
+    assert!(result.is_ok(), "team get failed: {:?}", result.err());
+    mock.assert_async().await;
+    cleanup_env();
+}
+#[tokio::test]
+async fn test_on_call_teams_delete() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    mock_all(&mut s, r#"{}"#).await;
+    let _ = crate::commands::on_call::teams_delete(&cfg, "t1").await;
     cleanup_env();
 }
 
-// --- Users ---
 #[tokio::test]
-async fn test_users_list() {
+async fn test_on_call_notification_rules_list() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    let mock = s
+        .mock("GET", "/api/v2/team/t1/notification-rules")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": []}"#)
+        .create_async()
+        .await;
+    let result = crate::commands::on_call::notification_rules_list(&cfg, "t1").await;
+    assert!(
+        result.is_ok(),
+        "notification rules list failed: {:?}",
+        result.err()
+    );
+    mock.assert_async().await;
+    cleanup_env();
+}
+#[tokio::test]
+async fn test_on_call_notification_rules_delete() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    let mock = s
+        .mock("DELETE", "/api/v2/team/t1/notification-rules/r1")
+        .with_status(204)
+        .create_async()
+        .await;
+    let result = crate::commands::on_call::notification_rules_delete(&cfg, "t1", "r1").await;
+    assert!(
+        result.is_ok(),
+        "notification rule delete failed: {:?}",
+        result.err()
+    );
+    mock.assert_async().await;
+    cleanup_env();
+}
+
+#[tokio::test]
+async fn test_on_call_links_create() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    let m = s
+        .mock("POST", "/api/v2/team/t1/links")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"data": {"type": "team_links", "attributes": {"label": "Runbook", "url": "https://wiki/runbook"}}}"#.into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": {"id": "l1"}}"#)
+        .create_async()
+        .await;
+    let result =
+        crate::commands::on_call::links_create(&cfg, "t1", "Runbook", "https://wiki/runbook").await;
+    assert!(result.is_ok(), "links create failed: {:?}", result.err());
+    m.assert_async().await;
+    cleanup_env();
+}
+#[tokio::test]
+async fn test_on_call_links_update_keeps_url() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    let _get = s
+        .mock("GET", "/api/v2/team/t1/links/l1")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"data": {"id": "l1", "attributes": {"label": "Old", "url": "https://wiki/old"}}}"#,
+        )
+        .create_async()
+        .await;
+    let patch = s
+        .mock("PATCH", "/api/v2/team/t1/links/l1")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"data": {"attributes": {"label": "New", "url": "https://wiki/old"}}}"#.into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": {"id": "l1"}}"#)
+        .create_async()
+        .await;
+    let result =
+        crate::commands::on_call::links_update(&cfg, "t1", "l1", Some("New".into()), None).await;
+    assert!(result.is_ok(), "links update failed: {:?}", result.err());
+    patch.assert_async().await;
+    cleanup_env();
+}
+#[tokio::test]
+async fn test_on_call_links_update_requires_field() {
+    let _lock = lock_env();
+    let cfg = test_config("http://127.0.0.1:1");
+    let result = crate::commands::on_call::links_update(&cfg, "t1", "l1", None, None).await;
+    assert!(result.is_err());
+    cleanup_env();
+}
+
+#[tokio::test]
+async fn test_on_call_schedules_on_call_users() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    let m = s
+        .mock("GET", "/api/v2/on-call/schedules/sched-1/on-call")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "include".into(),
+            "user".into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"data": {"id": "shift-1", "type": "shifts", "attributes": {"end": "2026-10-16T08:00:00Z"}, "relationships": {"user": {"data": {"id": "u1", "type": "users"}}}}, "included": [{"id": "u1", "type": "users", "attributes": {"name": "Ada", "email": "ada@example.com"}}]}"#,
+        )
+        .create_async()
+        .await;
+    let result = crate::commands::on_call::schedules_on_call_users(&cfg, "sched-1").await;
+    assert!(result.is_ok(), "on-call users failed: {:?}", result.err());
+    m.assert_async().await;
+    cleanup_env();
+}
+
+#[tokio::test]
+async fn test_on_call_teams_get_on_call() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    let m = s
+        .mock("GET", "/api/v2/on-call/teams/team-1/on-call")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "include".into(),
+            "responders".into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"data": {"id": "team-1", "type": "team_oncall_responders", "relationships": {"responders": {"data": [{"id": "u1", "type": "users"}]}}}, "included": [{"id": "u1", "type": "users", "attributes": {"name": "Ada", "email": "ada@example.com"}}]}"#,
+        )
+        .create_async()
+        .await;
+    let result = crate::commands::on_call::teams_get_on_call(&cfg, "team-1").await;
+    assert!(result.is_ok(), "team on-call failed: {:?}", result.err());
+    m.assert_async().await;
+    cleanup_env();
+}
+
+// --- Security ---
+#[tokio::test]
+async fn test_security_rules_list() {
     let _lock = lock_env();
     let mut s = mockito::Server::new_async().await;
     let cfg = test_config(&s.url());
     mock_all(&mut s, r#"{"data": []}"#).await;
-    let _ = crate::commands::users::list(&cfg).await;
+    let _ = crate::commands::security::rules_list(&cfg).await;
+    cleanup_env();
+}
+#[tokio::test]
+async fn test_security_rules_get() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    mock_all(&mut s, r#"{"data": {}}"#).await;
+    let _ = crate::commands::security::rules_get(&cfg, "r1").await;
+    cleanup_env();
+}
+#[tokio::test]
+async fn test_security_content_packs_list() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    mock_all(&mut s, r#"{"data": []}"#).await;
+    let _ = crate::commands::security::content_packs_list(&cfg).await;
+    cleanup_env();
+}
+
+#[tokio::test]
+async fn test_security_content_packs_get() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    let mock = s
+        .mock("GET", "/api/v2/security_monitoring/content_packs/states")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": [{"id": "aws-cloudtrail", "type": "content_pack_state", "attributes": {"cloud_siem_index_incorrect": false, "cp_activation": "activated", "filters_configured_for_logs": true, "integration_installed_status": "installed", "logs_last_collected": "within_24_hours", "logs_seen_from_any_index": true, "state": "active"}}, {"id": "okta", "type": "content_pack_state", "attributes": {"cloud_siem_index_incorrect": false, "cp_activation": "activated", "filters_configured_for_logs": true, "integration_installed_status": "installed", "logs_last_collected": "within_24_hours", "logs_seen_from_any_index": true, "state": "active"}}], "meta": {"cloud_siem_index_incorrect": false, "sku": "add_on_2024"}}"#)
+        .expect(2)
+        .create_async()
+        .await;
+    let result = crate::commands::security::content_packs_get(&cfg, "okta").await;
+    assert!(
+        result.is_ok(),
+        "content pack get failed: {:?}",
+        result.err()
+    );
+    let err = crate::commands::security::content_packs_get(&cfg, "missing")
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("not found"), "{err}");
+    mock.assert_async().await;
+    cleanup_env();
+}
+#[tokio::test]
+async fn test_security_risk_scores_get() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    let _m = s
+        .mock("GET", "/api/v2/entity_risk_scores/user-123")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": {"id": "user-123", "attributes": {"riskScore": 42}}}"#)
+        .create_async()
+        .await;
+    let result = crate::commands::security::risk_scores_get(&cfg, "user-123").await;
+    assert!(result.is_ok(), "risk score get failed: {:?}", result.err());
+    cleanup_env();
+}
+
+#[tokio::test]
+async fn test_security_signals_correlate() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    let signals = s
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/api/v2/security_monitoring/signals/sig-[12]$".into()),
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": {"attributes": {"attributes": {"title": "Brute force", "severity": "high"}}}}"#)
+        .expect(2)
+        .create_async()
+        .await;
+    let case = s
+        .mock("POST", "/api/v2/cases")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"data": {"type": "case", "attributes": {"title": "Credential stuffing", "type_id": "t-1", "description": "Correlated security signals:\n- sig-1: Brute force (high)\n- sig-2: Brute force (high)"}}}"#
+                .into(),
+        ))
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": {"id": "case-1", "type": "case"}}"#)
+        .create_async()
+        .await;
+    let path = std::env::temp_dir().join("pup_test_security_correlate_case.json");
+    std::fs::write(
+        &path,
+        r#"{"data": {"type": "case", "attributes": {"title": "placeholder", "type_id": "t-1"}, "relationships": {"project": {"data": {"id": "p-1", "type": "project"}}}}}"#,
+    )
+    .unwrap();
+    let result = crate::commands::security::signals_correlate(
+        &cfg,
+        vec!["sig-1".into(), "sig-2".into()],
+        Some("Credential stuffing".into()),
+        Some(path.to_str().unwrap().into()),
+    )
+    .await;
+    let _ = std::fs::remove_file(&path);
+    assert!(result.is_ok(), "correlate failed: {:?}", result.err());
+    signals.assert_async().await;
+    case.assert_async().await;
+    cleanup_env();
+}
+
+#[tokio::test]
+async fn test_security_signals_correlate_requires_two() {
+    let _lock = lock_env();
+    let cfg = test_config("http://127.0.0.1:1");
+    let result =
+        crate::commands::security::signals_correlate(&cfg, vec!["sig-1".into()], None, None).await;
+    assert!(result.is_err());
+    cleanup_env();
+}
+
+#[tokio::test]
+async fn test_security_signals_correlate_rejects_malformed_file() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    let _signals = s
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/api/v2/security_monitoring/signals/sig-[12]$".into()),
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": {"attributes": {}}}"#)
+        .create_async()
+        .await;
+    let case = s
+        .mock("POST", "/api/v2/cases")
+        .expect(0)
+        .create_async()
+        .await;
+    let path = std::env::temp_dir().join("pup_test_security_correlate_malformed.json");
+    for content in [
+        r#"[1, 2]"#,
+        r#""case""#,
+        r#"{"data": "case"}"#,
+        r#"{"data": {}}"#,
+    ] {
+        std::fs::write(&path, content).unwrap();
+        let err = crate::commands::security::signals_correlate(
+            &cfg,
+            vec!["sig-1".into(), "sig-2".into()],
+            None,
+            Some(path.to_str().unwrap().into()),
+        )
+        .await
+        .unwrap_err()
+        .to_string();
+        assert!(err.contains("data.attributes object"), "{content}: {err}");
+    }
+    let _ = std::fs::remove_file(&path);
+    case.assert_async().await;
+    cleanup_env();
+}
+
+// --- Synthetics ---
+#[tokio::test]
+async fn test_synthetics_tests_list() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    mock_all(&mut s, r#"{"tests": []}"#).await;
+    let _ = crate::commands::synthetics::tests_list(&cfg).await;
+    cleanup_env();
+}
+#[tokio::test]
+async fn test_synthetics_tests_get() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    mock_all(&mut s, r#"{}"#).await;
+    let _ = crate::commands::synthetics::tests_get(&cfg, "pub1").await;
+    cleanup_env();
+}
+#[tokio::test]
+async fn test_synthetics_tests_pause() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    let m = s
+        .mock("PUT", "/api/v1/synthetics/tests/pub1/status")
+        .match_body(mockito::Matcher::JsonString(
+            r#"{"new_status": "paused"}"#.into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body("true")
+        .create_async()
+        .await;
+    let result = crate::commands::synthetics::tests_pause(&cfg, "pub1").await;
+    assert!(
+        result.is_ok(),
+        "synthetics pause failed: {:?}",
+        result.err()
+    );
+    m.assert_async().await;
+    cleanup_env();
+}
+#[tokio::test]
+async fn test_synthetics_tests_resume() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    let mock = s
+        .mock("PUT", "/api/v1/synthetics/tests/pub1/status")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"new_status": "live"}"#.into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body("true")
+        .create_async()
+        .await;
+    let result = crate::commands::synthetics::tests_resume(&cfg, "pub1").await;
+    assert!(
+        result.is_ok(),
+        "synthetics resume failed: {:?}",
+        result.err()
+    );
+    mock.assert_async().await;
+    cleanup_env();
+}
+#[tokio::test]
+async fn test_synthetics_locations_list() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    mock_all(&mut s, r#"{"locations": []}"#).await;
+    let _ = crate::commands::synthetics::locations_list(&cfg).await;
+    cleanup_env();
+}
+
+// --- App Keys ---
+#[tokio::test]
+async fn test_app_keys_list() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    mock_all(&mut s, r#"{"data": []}"#).await;
+    let _ = crate::commands::app_keys::list(&cfg, 10, 0).await;
+    cleanup_env();
+}
+#[tokio::test]
+async fn test_app_keys_get() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    mock_all(&mut s, r#"{"data": {}}"#).await;
+    let _ = crate::commands::app_keys::get(&cfg, "k1").await;
+    cleanup_env();
+}
+#[tokio::test]
+async fn test_app_keys_unregister() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    mock_all(&mut s, r#"{}"#).await;
+    let _ = crate::commands::app_keys::unregister(&cfg, "k1").await;
+    cleanup_env();
+}
+
+// --- API Keys ---
+#[tokio::test]
+async fn test_api_keys_list() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    mock_all(&mut s, r#"{"data": []}"#).await;
+    let _ = crate::commands::api_keys::list(&cfg).await;
+    cleanup_env();
+}
+#[tokio::test]
+async fn test_api_keys_get() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    mock_all(&mut s, r#"{"data": {}}"#).await;
+    let _ = crate::commands::api_keys::get(&cfg, "k1").await;
+    cleanup_env();
+}
+#[tokio::test]
+async fn test_api_keys_delete() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    mock_all(&mut s, r#"{}"#).await;
+    let _ = crate::commands::api_keys::delete(&cfg, "k1").await;
+    cleanup_env();
+}
+
+// --- Audit Logs ---
+#[tokio::test]
+async fn test_audit_logs_list() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    mock_all(&mut s, r#"{"data": []}"#).await;
+    let _ = crate::commands::audit_logs::list(&cfg, "1h".into(), "now".into(), 10).await;
+    cleanup_env();
+}
+
+// --- Users ---
+#[tokio::test]
+async fn test_users_list() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    mock_all(&mut s, r#"{"data": []}"#).await;
+    let _ = crate::commands::users::list(&cfg).await;
+    cleanup_env();
+}
+#[tokio::test]
+async fn test_users_get() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    mock_all(&mut s, r#"{"data": {}}"#).await;
+    let _ = crate::commands::users::get(&cfg, "u1").await;
+    cleanup_env();
+}
+#[tokio::test]
+async fn test_users_roles_list() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    mock_all(&mut s, r#"{"data": []}"#).await;
+    let _ = crate::commands::users::roles_list(&cfg).await;
+    cleanup_env();
+}
+
+#[tokio::test]
+async fn test_users_roles_assign() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    let m = s
+        .mock("POST", "/api/v2/roles/role-1/users")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"data": {"id": "user-1", "type": "users"}}"#.to_string(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": []}"#)
+        .create_async()
+        .await;
+    let result = crate::commands::users::roles_assign(&cfg, "user-1", "role-1").await;
+    assert!(result.is_ok(), "roles assign failed: {:?}", result.err());
+    m.assert_async().await;
+    cleanup_env();
+}
+
+#[tokio::test]
+async fn test_users_roles_revoke() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    let m = s
+        .mock("DELETE", "/api/v2/roles/role-1/users")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"data": {"id": "user-1", "type": "users"}}"#.to_string(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": []}"#)
+        .create_async()
+        .await;
+    let result = crate::commands::users::roles_revoke(&cfg, "user-1", "role-1").await;
+    assert!(result.is_ok(), "roles revoke failed: {:?}", result.err());
+    m.assert_async().await;
+    cleanup_env();
+}
+
+#[tokio::test]
+async fn test_users_permissions_list() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    let m = s
+        .mock("GET", "/api/v2/permissions")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": []}"#)
+        .create_async()
+        .await;
+    let result = crate::commands::users::permissions_list(&cfg).await;
+    assert!(
+        result.is_ok(),
+        "permissions list failed: {:?}",
+        result.err()
+    );
+    m.assert_async().await;
+    cleanup_env();
+}
+
+#[tokio::test]
+async fn test_users_permissions_assign() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    let m = s
+        .mock("POST", "/api/v2/roles/role-1/permissions")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"data": {"id": "perm-1", "type": "permissions"}}"#.to_string(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": []}"#)
+        .create_async()
+        .await;
+    let result = crate::commands::users::permissions_assign(&cfg, "role-1", "perm-1").await;
+    assert!(
+        result.is_ok(),
+        "permissions assign failed: {:?}",
+        result.err()
+    );
+    m.assert_async().await;
+    cleanup_env();
+}
+
+// --- Usage ---
+#[tokio::test]
+async fn test_usage_summary() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    mock_all(&mut s, r#"{"usage": []}"#).await;
+    let _ = crate::commands::usage::summary(&cfg, "2024-01".into(), None).await;
+    cleanup_env();
+}
+
+#[tokio::test]
+async fn test_usage_timeseries() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    let _m = s
+        .mock("GET", "/api/v1/usage/timeseries")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "start_hr".into(),
+            "2023-11-14T22".into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"usage": []}"#)
+        .create_async()
+        .await;
+    let result = crate::commands::usage::timeseries(&cfg, "1700000000000".into(), None).await;
+    assert!(
+        result.is_ok(),
+        "usage timeseries failed: {:?}",
+        result.err()
+    );
+    cleanup_env();
+}
+
+// --- Infrastructure ---
+#[tokio::test]
+async fn test_infrastructure_hosts_list() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    mock_all(&mut s, r#"{"host_list": [], "total_returned": 0}"#).await;
+    let _ = crate::commands::infrastructure::hosts_list(&cfg, None, "name".into(), 10).await;
+    cleanup_env();
+}
+
+#[tokio::test]
+async fn test_infrastructure_containers_list() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    mock_all(&mut s, r#"{"data": []}"#).await;
+    let _ =
+        crate::commands::infrastructure::containers_list(&cfg, Some("env:prod".into()), None, 10)
+            .await;
+    cleanup_env();
+}
+
+#[tokio::test]
+async fn test_infrastructure_containers_get() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    let found = s
+        .mock("GET", "/api/v2/containers")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "filter[tags]".into(),
+            "container_id:c1".into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"data": [{"type": "container", "id": "c1", "attributes": {"name": "web"}}]}"#,
+        )
+        .create_async()
+        .await;
+    let missing = s
+        .mock("GET", "/api/v2/containers")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "filter[tags]".into(),
+            "container_id:nope".into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": []}"#)
+        .create_async()
+        .await;
+
+    let result = crate::commands::infrastructure::containers_get(&cfg, "c1").await;
+    assert!(result.is_ok(), "containers get failed: {:?}", result.err());
+    let err = crate::commands::infrastructure::containers_get(&cfg, "nope")
+        .await
+        .unwrap_err()
+        .to_string();
+    assert_eq!(err, "container nope not found");
+    found.assert_async().await;
+    missing.assert_async().await;
+    cleanup_env();
+}
+
+// --- Notebooks ---
+#[tokio::test]
+async fn test_notebooks_list() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    mock_all(&mut s, r#"{"data": []}"#).await;
+    let _ = crate::commands::notebooks::list(&cfg).await;
+    cleanup_env();
+}
+
+#[tokio::test]
+async fn test_notebooks_create_from_template() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    let notebook = |id: i64, name: &str| {
+        serde_json::json!({"data": {"id": id, "type": "notebooks", "attributes": {
+            "name": name,
+            "time": {"live_span": "1h"},
+            "status": "published",
+            "author": {"handle": "jane@example.com"},
+            "created": "2026-01-01T00:00:00Z",
+            "modified": "2026-01-02T00:00:00Z",
+            "cells": [{"id": "cell-1", "type": "notebook_cells", "attributes": {
+                "definition": {"type": "markdown", "text": "## Timeline"}
+            }}]
+        }}})
+        .to_string()
+    };
+    let get = s
+        .mock("GET", "/api/v1/notebooks/12345")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(notebook(12345, "Investigation template"))
+        .create_async()
+        .await;
+    let create = s
+        .mock("POST", "/api/v1/notebooks")
+        .match_body(mockito::Matcher::JsonString(
+            serde_json::json!({"data": {"type": "notebooks", "attributes": {
+                "name": "Incident 42",
+                "time": {"live_span": "1h"},
+                "status": "published",
+                "cells": [{"type": "notebook_cells", "attributes": {
+                    "definition": {"type": "markdown", "text": "## Timeline"}
+                }}]
+            }}})
+            .to_string(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(notebook(67890, "Incident 42"))
+        .create_async()
+        .await;
+
+    let result = crate::commands::notebooks::create_from_template(&cfg, 12345, "Incident 42").await;
+    assert!(
+        result.is_ok(),
+        "create-from-template failed: {:?}",
+        result.err()
+    );
+    get.assert_async().await;
+    create.assert_async().await;
+    cleanup_env();
+}
+
+// --- Downtime ---
+#[tokio::test]
+async fn test_downtime_list() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    mock_all(&mut s, r#"{"data": []}"#).await;
+    let _ = crate::commands::downtime::list(&cfg).await;
+    cleanup_env();
+}
+#[tokio::test]
+async fn test_downtime_get() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    mock_all(&mut s, r#"{"data": {}}"#).await;
+    let _ = crate::commands::downtime::get(&cfg, "d1").await;
+    cleanup_env();
+}
+
+// --- Cost ---
+#[tokio::test]
+async fn test_cost_projected() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    mock_all(&mut s, r#"{"data": []}"#).await;
+    let _ = crate::commands::cost::projected(&cfg).await;
+    cleanup_env();
+}
+
+#[tokio::test]
+async fn test_cost_forecast() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    let _estimated = s
+        .mock("GET", "/api/v2/usage/estimated_cost")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": [{"attributes": {"charges": [{"product_name": "logs", "charge_type": "total", "cost": 40.0}]}}]}"#)
+        .create_async()
+        .await;
+    let _projected = s
+        .mock("GET", "/api/v2/usage/projected_cost")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": [{"attributes": {"charges": [{"product_name": "logs", "charge_type": "total", "cost": 100.0}]}}]}"#)
+        .create_async()
+        .await;
+    let result = crate::commands::cost::forecast(&cfg, None, None).await;
+    assert!(result.is_ok(), "cost forecast failed: {:?}", result.err());
+    cleanup_env();
+}
+
+// --- Error Tracking ---
+#[tokio::test]
+async fn test_error_tracking_issues_search() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    mock_all(&mut s, r#"{"data": []}"#).await;
+    let _ = crate::commands::error_tracking::issues_search(&cfg, None, 10).await;
+    cleanup_env();
+}
+
+#[tokio::test]
+async fn test_error_tracking_issues_set_status() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let mut cfg = test_config(&s.url());
+    cfg.auto_approve = true;
+    let _search = s
+        .mock("POST", "/api/v2/error-tracking/issues/search")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": [{"id": "i1"}, {"id": "i2"}]}"#)
+        .create_async()
+        .await;
+    let update = s
+        .mock(
+            "PUT",
+            mockito::Matcher::Regex(r"^/api/v2/error-tracking/issues/i[12]/state$".into()),
+        )
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"data": {"attributes": {"state": "RESOLVED"}}}"#.into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": {}}"#)
+        .expect(2)
+        .create_async()
+        .await;
+    let result = crate::commands::error_tracking::issues_bulk_update(
+        &cfg,
+        "service:web",
+        "resolved",
+        "1d",
+        "now",
+    )
+    .await;
+    assert!(result.is_ok(), "set-status failed: {:?}", result.err());
+    update.assert_async().await;
+    cleanup_env();
+}
+
+#[tokio::test]
+async fn test_error_tracking_issues_set_status_refuses_truncated_search() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    let ids: Vec<serde_json::Value> = (0..100)
+        .map(|i| serde_json::json!({ "id": format!("i{i}") }))
+        .collect();
+    let _search = s
+        .mock("POST", "/api/v2/error-tracking/issues/search")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"data": {"attributes": {"query": "service:web"}}}"#.into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::json!({ "data": ids }).to_string())
+        .create_async()
+        .await;
+    let update = s
+        .mock("PUT", mockito::Matcher::Any)
+        .expect(0)
+        .create_async()
+        .await;
+    let err = crate::commands::error_tracking::issues_bulk_update(
+        &cfg,
+        "service:web",
+        "resolved",
+        "1d",
+        "now",
+    )
+    .await
+    .unwrap_err()
+    .to_string();
+    assert!(err.contains("more may match"), "{err}");
+    update.assert_async().await;
+    cleanup_env();
+}
+
+#[tokio::test]
+async fn test_error_tracking_issues_bulk_update() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let mut cfg = test_config(&s.url());
+    cfg.auto_approve = true;
+    let _search = s
+        .mock("POST", "/api/v2/error-tracking/issues/search")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"data": {"attributes": {"query": "env:staging"}}}"#.into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": [{"id": "i1"}, {"id": "i2"}]}"#)
+        .create_async()
+        .await;
+    let ok = s
+        .mock("PUT", "/api/v2/error-tracking/issues/i1/state")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"data": {"attributes": {"state": "IGNORED"}}}"#.into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": {}}"#)
+        .create_async()
+        .await;
+    let failing = s
+        .mock("PUT", "/api/v2/error-tracking/issues/i2/state")
+        .with_status(500)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"errors": ["boom"]}"#)
+        .create_async()
+        .await;
+    let result = crate::commands::error_tracking::issues_bulk_update(
+        &cfg,
+        "env:staging",
+        "ignored",
+        "30d",
+        "now",
+    )
+    .await;
+    let err = result.expect_err("partial failure should be reported");
+    assert!(
+        err.to_string().contains("1 of 2"),
+        "unexpected error: {err}"
+    );
+    ok.assert_async().await;
+    failing.assert_async().await;
+    cleanup_env();
+}
+
+#[tokio::test]
+async fn test_error_tracking_issues_bulk_attempts_every_entry() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    let failing = s
+        .mock("PUT", "/api/v2/error-tracking/issues/i1/state")
+        .with_status(500)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"errors": ["boom"]}"#)
+        .create_async()
+        .await;
+    let ok = s
+        .mock("PUT", "/api/v2/error-tracking/issues/i2/state")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"data": {"attributes": {"state": "RESOLVED"}}}"#.into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": {}}"#)
+        .create_async()
+        .await;
+
+    let path = std::env::temp_dir().join("pup_test_error_tracking_issues_bulk.json");
+    let updates = serde_json::json!([
+        {"issue_id": "i1", "status": "ignored"},
+        {"issue_id": "i2", "status": "resolved"},
+    ]);
+    std::fs::write(&path, updates.to_string()).unwrap();
+    let result = crate::commands::error_tracking::issues_bulk(&cfg, path.to_str().unwrap()).await;
+    let err = result.expect_err("partial failure should be reported");
+    assert!(
+        err.to_string().contains("1 of 2"),
+        "unexpected error: {err}"
+    );
+    failing.assert_async().await;
+    ok.assert_async().await;
+    cleanup_env();
+}
+
+// --- Cloud ---
+#[tokio::test]
+async fn test_cloud_aws_list() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    mock_all(&mut s, r#"{"data": []}"#).await;
+    let _ = crate::commands::cloud::aws_list(&cfg).await;
+    cleanup_env();
+}
+#[tokio::test]
+async fn test_cloud_gcp_list() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    mock_all(&mut s, r#"{"data": []}"#).await;
+    let _ = crate::commands::cloud::gcp_list(&cfg).await;
+    cleanup_env();
+}
+#[tokio::test]
+async fn test_cloud_azure_list() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    mock_all(&mut s, r#"{"data": []}"#).await;
+    let _ = crate::commands::cloud::azure_list(&cfg).await;
+    cleanup_env();
+}
+
+#[tokio::test]
+async fn test_cloud_aws_get() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    let m = s
+        .mock("GET", "/api/v2/integration/aws/accounts/acct-1")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": {"id": "acct-1", "type": "account"}}"#)
+        .create_async()
+        .await;
+    let _ = crate::commands::cloud::aws_get(&cfg, "acct-1").await;
+    m.assert_async().await;
+    cleanup_env();
+}
+#[tokio::test]
+async fn test_cloud_aws_delete() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    let m = s
+        .mock("DELETE", "/api/v2/integration/aws/accounts/acct-1")
+        .with_status(204)
+        .create_async()
+        .await;
+    let result = crate::commands::cloud::aws_delete(&cfg, "acct-1").await;
+    assert!(result.is_ok(), "aws delete failed: {:?}", result.err());
+    m.assert_async().await;
+    cleanup_env();
+}
+
+#[tokio::test]
+async fn test_cloud_gcp_delete() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    let m = s
+        .mock("DELETE", "/api/v1/integration/gcp")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"project_id": "my-project", "client_email": "dd@my-project.iam.gserviceaccount.com"}"#
+                .to_string(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body("{}")
+        .create_async()
+        .await;
+    let result = crate::commands::cloud::gcp_delete(
+        &cfg,
+        "my-project",
+        "dd@my-project.iam.gserviceaccount.com",
+    )
+    .await;
+    assert!(result.is_ok(), "gcp delete failed: {:?}", result.err());
+    m.assert_async().await;
+    cleanup_env();
+}
+
+#[tokio::test]
+async fn test_cloud_azure_create_update_delete() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    let create = s
+        .mock("POST", "/api/v1/integration/azure")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"tenant_name": "my-tenant", "client_id": "app-1"}"#.to_string(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body("{}")
+        .create_async()
+        .await;
+    let update = s
+        .mock("PUT", "/api/v1/integration/azure")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"tenant_name": "my-tenant", "client_id": "app-1", "host_filters": "env:prod"}"#
+                .to_string(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body("{}")
+        .create_async()
+        .await;
+    let delete = s
+        .mock("DELETE", "/api/v1/integration/azure")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"tenant_name": "my-tenant", "client_id": "app-1"}"#.to_string(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body("{}")
+        .create_async()
+        .await;
+
+    let create_file = std::env::temp_dir().join("pup_test_azure_create.json");
+    std::fs::write(
+        &create_file,
+        r#"{"tenant_name": "my-tenant", "client_id": "app-1", "client_secret": "s3cret"}"#,
+    )
+    .unwrap();
+    let update_file = std::env::temp_dir().join("pup_test_azure_update.json");
+    std::fs::write(
+        &update_file,
+        r#"{"client_id": "app-1", "host_filters": "env:prod"}"#,
+    )
+    .unwrap();
+
+    let created = crate::commands::cloud::azure_create(&cfg, create_file.to_str().unwrap()).await;
+    let updated =
+        crate::commands::cloud::azure_update(&cfg, "my-tenant", update_file.to_str().unwrap())
+            .await;
+    let deleted = crate::commands::cloud::azure_delete(&cfg, "my-tenant", "app-1").await;
+    let _ = std::fs::remove_file(&create_file);
+    let _ = std::fs::remove_file(&update_file);
+    assert!(created.is_ok(), "azure create failed: {:?}", created.err());
+    assert!(updated.is_ok(), "azure update failed: {:?}", updated.err());
+    assert!(deleted.is_ok(), "azure delete failed: {:?}", deleted.err());
+    create.assert_async().await;
+    update.assert_async().await;
+    delete.assert_async().await;
+    cleanup_env();
+}
+
+// --- Organizations ---
+#[tokio::test]
+async fn test_organizations_list() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    mock_all(&mut s, r#"{"orgs": []}"#).await;
+    let _ = crate::commands::organizations::list(&cfg).await;
+    cleanup_env();
+}
+
+#[tokio::test]
+async fn test_organizations_settings_update() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    let m = s
+        .mock("PATCH", "/api/v2/org_configs/monitor_timezone")
+        .match_body(mockito::Matcher::JsonString(
+            r#"{"data": {"attributes": {"value": "UTC"}, "type": "org_configs"}}"#.into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"data": {"id": "c1", "type": "org_configs", "attributes": {"name": "monitor_timezone", "description": "Monitor timezone", "value": "UTC", "value_type": "string"}}}"#,
+        )
+        .create_async()
+        .await;
+    let result =
+        crate::commands::organizations::settings_update(&cfg, "monitor_timezone", "UTC").await;
+    assert!(result.is_ok(), "settings update failed: {:?}", result.err());
+    m.assert_async().await;
+    cleanup_env();
+}
+
+// --- Service Catalog ---
+#[tokio::test]
+async fn test_service_catalog_list() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    mock_all(&mut s, r#"{"data": []}"#).await;
+    let _ = crate::commands::service_catalog::list(&cfg).await;
+    cleanup_env();
+}
+#[tokio::test]
+async fn test_service_catalog_get() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    mock_all(&mut s, r#"{"data": {}}"#).await;
+    let _ = crate::commands::service_catalog::get(&cfg, "svc1").await;
+    cleanup_env();
+}
+#[tokio::test]
+async fn test_service_catalog_search() {
+    let _lock = lock_env();
+    let mut server = mockito::Server::new_async().await;
+    let cfg = test_config(&server.url());
+    let _mock = server
+        .mock("GET", "/api/v2/catalog/entity")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("query".into(), "payments api".into()),
+            mockito::Matcher::UrlEncoded("kind".into(), "service".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": []}"#)
+        .create_async()
+        .await;
+    let result = crate::commands::service_catalog::search(
+        &cfg,
+        "payments api".into(),
+        Some("service".into()),
+        10,
+    )
+    .await;
+    assert!(
+        result.is_ok(),
+        "service catalog search failed: {:?}",
+        result.err()
+    );
+    cleanup_env();
+}
+#[tokio::test]
+async fn test_service_catalog_search_invalid_kind() {
+    let _lock = lock_env();
+    let cfg = test_config("http://127.0.0.1:1");
+    let result =
+        crate::commands::service_catalog::search(&cfg, "x".into(), Some("lambda".into()), 10).await;
+    assert!(result.is_err(), "invalid kind should be rejected");
+    cleanup_env();
+}
+
+#[tokio::test]
+async fn test_service_catalog_dependencies() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    let upstream = s
+        .mock("GET", "/api/v2/catalog/relation")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("filter[to_ref]".into(), "service:checkout".into()),
+            mockito::Matcher::UrlEncoded("filter[type]".into(), "RelationTypeDependsOn".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": []}"#)
+        .create_async()
+        .await;
+    let downstream = s
+        .mock("GET", "/api/v2/catalog/relation")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "filter[from_ref]".into(),
+            "service:checkout".into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": []}"#)
+        .create_async()
+        .await;
+    let result = crate::commands::service_catalog::dependencies(&cfg, "checkout", None).await;
+    assert!(result.is_ok(), "dependencies failed: {:?}", result.err());
+    upstream.assert_async().await;
+    downstream.assert_async().await;
+    cleanup_env();
+}
+#[tokio::test]
+async fn test_service_catalog_upsert_created() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    let path = std::env::temp_dir().join("pup_test_service_upsert_created.json");
+    std::fs::write(
+        &path,
+        r#"{"schema-version": "v2.2", "dd-service": "web-store"}"#,
+    )
+    .unwrap();
+    let lookup = s
+        .mock("GET", "/api/v2/services/definitions/web-store")
+        .with_status(404)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"errors": ["not found"]}"#)
+        .create_async()
+        .await;
+    let save = s
+        .mock("POST", "/api/v2/services/definitions")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"dd-service": "web-store"}"#.into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": []}"#)
+        .create_async()
+        .await;
+    let result = crate::commands::service_catalog::upsert(&cfg, path.to_str().unwrap()).await;
+    let _ = std::fs::remove_file(&path);
+    assert!(result.is_ok(), "upsert failed: {:?}", result.err());
+    lookup.assert_async().await;
+    save.assert_async().await;
+    cleanup_env();
+}
+
+#[tokio::test]
+async fn test_service_catalog_upsert_updated() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    let path = std::env::temp_dir().join("pup_test_service_upsert_updated.json");
+    std::fs::write(
+        &path,
+        r#"{"apiVersion": "v3", "kind": "service", "metadata": {"name": "checkout"}}"#,
+    )
+    .unwrap();
+    let lookup = s
+        .mock("GET", "/api/v2/services/definitions/checkout")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": {"type": "service-definition"}}"#)
+        .create_async()
+        .await;
+    let save = s
+        .mock("POST", "/api/v2/services/definitions")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": []}"#)
+        .create_async()
+        .await;
+    let result = crate::commands::service_catalog::upsert(&cfg, path.to_str().unwrap()).await;
+    let _ = std::fs::remove_file(&path);
+    assert!(result.is_ok(), "upsert failed: {:?}", result.err());
+    lookup.assert_async().await;
+    save.assert_async().await;
     cleanup_env();
 }
+
 #[tokio::test]
-async fn test_users_get() {
+async fn test_service_catalog_upsert_missing_name() {
     let _lock = lock_env();
-    let mut s = mockito::Server::new_async().await;
-    let cfg = test_config(&s.url());
-    mock_all(&mut s, r#"{"data": {}}"#).await;
-    let _ = crate::commands::users::get(&cfg, "u1").await;
+    let cfg = test_config("http://127.0.0.1:1");
+    let path = std::env::temp_dir().join("pup_test_service_upsert_missing.json");
+    std::fs::write(&path, r#"{"schema-version": "v2.2"}"#).unwrap();
+    let result = crate::commands::service_catalog::upsert(&cfg, path.to_str().unwrap()).await;
+    let _ = std::fs::remove_file(&path);
+    assert!(result.is_err());
     cleanup_env();
 }
+
 #[tokio::test]
-async fn test_users_roles_list() {
+async fn test_service_catalog_dependencies_invalid_direction() {
     let _lock = lock_env();
-    let mut s = mockito::Server::new_async().await;
-    let cfg = test_config(&s.url());
-    mock_all(&mut s, r#"{"data": []}"#).await;
-    let _ = crate::commands::users::roles_list(&cfg).await;
+    let cfg = test_config("http://127.0.0.1:1");
+    let result =
+        crate::commands::service_catalog::dependencies(&cfg, "checkout", Some("sideways".into()))
+            .await;
+    assert!(result.is_err(), "invalid direction should be rejected");
     cleanup_env();
 }
 
-// --- Usage ---
+// --- Misc ---
 #[tokio::test]
-async fn test_usage_summary() {
+async fn test_misc_ip_ranges() {
     let _lock = lock_env();
     let mut s = mockito::Server::new_async().await;
     let cfg = test_config(&s.url());
-    mock_all(&mut s, r#"{"usage": []}"#).await;
-    let _ = crate::commands::usage::summary(&cfg, "2024-01".into(), None).await;
+    mock_all(&mut s, r#"{}"#).await;
+    let _ = crate::commands::misc::ip_ranges(&cfg).await;
     cleanup_env();
 }
 
-// --- Infrastructure ---
 #[tokio::test]
-async fn test_infrastructure_hosts_list() {
+async fn test_misc_validate_api_key() {
     let _lock = lock_env();
     let mut s = mockito::Server::new_async().await;
     let cfg = test_config(&s.url());
-    mock_all(&mut s, r#"{"host_list": [], "total_returned": 0}"#).await;
-    let _ = crate::commands::infrastructure::hosts_list(&cfg, None, "name".into(), 10).await;
+    let _m = s
+        .mock("GET", "/api/v1/validate")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"valid": true}"#)
+        .create_async()
+        .await;
+    let result = crate::commands::misc::validate_api_key(&cfg).await;
+    assert!(
+        result.is_ok(),
+        "validate api key failed: {:?}",
+        result.err()
+    );
     cleanup_env();
 }
-
-// --- Notebooks ---
 #[tokio::test]
-async fn test_notebooks_list() {
+async fn test_misc_validate_api_key_invalid() {
     let _lock = lock_env();
     let mut s = mockito::Server::new_async().await;
     let cfg = test_config(&s.url());
-    mock_all(&mut s, r#"{"data": []}"#).await;
-    let _ = crate::commands::notebooks::list(&cfg).await;
+    let _m = s
+        .mock("GET", "/api/v1/validate")
+        .with_status(403)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"errors": ["Forbidden"]}"#)
+        .create_async()
+        .await;
+    let result = crate::commands::misc::validate_api_key(&cfg).await;
+    assert!(result.is_err(), "403 should fail validation");
     cleanup_env();
 }
 
-// --- Downtime ---
+// --- Data Governance ---
 #[tokio::test]
-async fn test_downtime_list() {
+async fn test_data_governance_scanner_rules_list() {
     let _lock = lock_env();
     let mut s = mockito::Server::new_async().await;
     let cfg = test_config(&s.url());
     mock_all(&mut s, r#"{"data": []}"#).await;
-    let _ = crate::commands::downtime::list(&cfg).await;
+    let _ = crate::commands::data_governance::scanner_rules_list(&cfg).await;
     cleanup_env();
 }
+
 #[tokio::test]
-async fn test_downtime_get() {
+async fn test_data_governance_scanner_rules_create() {
     let _lock = lock_env();
     let mut s = mockito::Server::new_async().await;
     let cfg = test_config(&s.url());
-    mock_all(&mut s, r#"{"data": {}}"#).await;
-    let _ = crate::commands::downtime::get(&cfg, "d1").await;
+    let m = s
+        .mock("POST", "/api/v2/sensitive-data-scanner/config/rules")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": {"id": "r1", "type": "sensitive_data_scanner_rule"}, "meta": {}}"#)
+        .create_async()
+        .await;
+    let file = std::env::temp_dir().join("pup_test_scanner_rule.json");
+    std::fs::write(
+        &file,
+        r#"{"data": {"type": "sensitive_data_scanner_rule", "attributes": {}, "relationships": {"group": {"data": {"id": "g1", "type": "sensitive_data_scanner_group"}}}}, "meta": {}}"#,
+    )
+    .unwrap();
+    let result =
+        crate::commands::data_governance::scanner_rules_create(&cfg, file.to_str().unwrap()).await;
+    let _ = std::fs::remove_file(&file);
+    assert!(result.is_ok(), "rule create failed: {:?}", result.err());
+    m.assert_async().await;
     cleanup_env();
 }
 
-// --- Cost ---
 #[tokio::test]
-async fn test_cost_projected() {
+async fn test_data_governance_scanner_rules_delete_sends_version() {
     let _lock = lock_env();
     let mut s = mockito::Server::new_async().await;
     let cfg = test_config(&s.url());
-    mock_all(&mut s, r#"{"data": []}"#).await;
-    let _ = crate::commands::cost::projected(&cfg).await;
+    let _config = s
+        .mock("GET", "/api/v2/sensitive-data-scanner/config")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"data": {"id": "cfg-1", "type": "sensitive_data_scanner_configuration"}, "meta": {"version": 7}}"#,
+        )
+        .create_async()
+        .await;
+    let m = s
+        .mock("DELETE", "/api/v2/sensitive-data-scanner/config/rules/r1")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"meta": {"version": 7}}"#.into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"meta": {}}"#)
+        .create_async()
+        .await;
+    let result = crate::commands::data_governance::scanner_rules_delete(&cfg, "r1").await;
+    assert!(result.is_ok(), "rule delete failed: {:?}", result.err());
+    m.assert_async().await;
     cleanup_env();
 }
 
-// --- Error Tracking ---
 #[tokio::test]
-async fn test_error_tracking_issues_search() {
+async fn test_data_governance_scanner_groups_reorder() {
     let _lock = lock_env();
     let mut s = mockito::Server::new_async().await;
     let cfg = test_config(&s.url());
-    mock_all(&mut s, r#"{"data": []}"#).await;
-    let _ = crate::commands::error_tracking::issues_search(&cfg, None, 10).await;
+    let _config = s
+        .mock("GET", "/api/v2/sensitive-data-scanner/config")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"data": {"id": "cfg-1", "type": "sensitive_data_scanner_configuration"}, "meta": {"version": 3}}"#,
+        )
+        .create_async()
+        .await;
+    let m = s
+        .mock("PATCH", "/api/v2/sensitive-data-scanner/config")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"data": {"id": "cfg-1", "relationships": {"groups": {"data": [{"id": "g2"}, {"id": "g1"}]}}}, "meta": {"version": 3}}"#.into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"meta": {}}"#)
+        .create_async()
+        .await;
+    let result = crate::commands::data_governance::scanner_groups_reorder(
+        &cfg,
+        vec!["g2".into(), "g1".into()],
+    )
+    .await;
+    assert!(result.is_ok(), "group reorder failed: {:?}", result.err());
+    m.assert_async().await;
     cleanup_env();
 }
 
-// --- Cloud ---
+// --- Investigations ---
 #[tokio::test]
-async fn test_cloud_aws_list() {
+async fn test_investigations_list() {
     let _lock = lock_env();
     let mut s = mockito::Server::new_async().await;
     let cfg = test_config(&s.url());
     mock_all(&mut s, r#"{"data": []}"#).await;
-    let _ = crate::commands::cloud::aws_list(&cfg).await;
+    let _ = crate::commands::investigations::list(&cfg, 10, 0).await;
     cleanup_env();
 }
 #[tokio::test]
-async fn test_cloud_gcp_list() {
+async fn test_investigations_get() {
     let _lock = lock_env();
     let mut s = mockito::Server::new_async().await;
     let cfg = test_config(&s.url());
-    mock_all(&mut s, r#"{"data": []}"#).await;
-    let _ = crate::commands::cloud::gcp_list(&cfg).await;
+    mock_all(&mut s, r#"{"data": {}}"#).await;
+    let _ = crate::commands::investigations::get(&cfg, "inv1").await;
     cleanup_env();
 }
+
+// --- Network ---
 #[tokio::test]
-async fn test_cloud_azure_list() {
+async fn test_network_flows_list() {
     let _lock = lock_env();
     let mut s = mockito::Server::new_async().await;
     let cfg = test_config(&s.url());
     mock_all(&mut s, r#"{"data": []}"#).await;
-    let _ = crate::commands::cloud::azure_list(&cfg).await;
+    let _ = crate::commands::network::flows_list(&cfg).await;
     cleanup_env();
 }
-
-// --- Organizations ---
 #[tokio::test]
-async fn test_organizations_list() {
+async fn test_network_devices_list() {
     let _lock = lock_env();
     let mut s = mockito::Server::new_async().await;
     let cfg = test_config(&s.url());
-    mock_all(&mut s, r#"{"orgs": []}"#).await;
-    let _ = crate::commands::organizations::list(&cfg).await;
+    let mock = s
+        .mock("GET", "/api/v2/ndm/devices")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": [{"id": "default:10.0.0.1", "type": "devices", "attributes": {"name": "core-switch-1", "status": "ok"}}]}"#)
+        .create_async()
+        .await;
+    let result = crate::commands::network::devices_list(&cfg).await;
+    assert!(
+        result.is_ok(),
+        "network devices list failed: {:?}",
+        result.err()
+    );
+    mock.assert_async().await;
     cleanup_env();
 }
 
-// --- Service Catalog ---
+// --- Scorecards ---
 #[tokio::test]
-async fn test_service_catalog_list() {
+async fn test_scorecards_rules_list() {
     let _lock = lock_env();
     let mut s = mockito::Server::new_async().await;
     let cfg = test_config(&s.url());
-    mock_all(&mut s, r#"{"data": []}"#).await;
-    let _ = crate::commands::service_catalog::list(&cfg).await;
+    let _m = s
+        .mock("GET", "/api/v2/scorecard/rules")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "filter[rule][name]".into(),
+            "Has owner".into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": []}"#)
+        .create_async()
+        .await;
+    let result = crate::commands::scorecards::rules_list(&cfg, Some("Has owner".into())).await;
+    assert!(
+        result.is_ok(),
+        "scorecard rules list failed: {:?}",
+        result.err()
+    );
     cleanup_env();
 }
 #[tokio::test]
-async fn test_service_catalog_get() {
+async fn test_scorecards_list_pages() {
     let _lock = lock_env();
     let mut s = mockito::Server::new_async().await;
     let cfg = test_config(&s.url());
-    mock_all(&mut s, r#"{"data": {}}"#).await;
-    let _ = crate::commands::service_catalog::get(&cfg, "svc1").await;
+    let page: Vec<_> = (0..100)
+        .map(|i| serde_json::json!({"id": format!("s{i}")}))
+        .collect();
+    let first = s
+        .mock("GET", "/api/v2/scorecard/scorecards")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("page[size]".into(), "100".into()),
+            mockito::Matcher::UrlEncoded("page[offset]".into(), "0".into()),
+            mockito::Matcher::UrlEncoded("include".into(), "rules".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::json!({"data": page, "included": [{"id": "r1"}]}).to_string())
+        .create_async()
+        .await;
+    let second = s
+        .mock("GET", "/api/v2/scorecard/scorecards")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("page[size]".into(), "1".into()),
+            mockito::Matcher::UrlEncoded("page[offset]".into(), "100".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": [{"id": "s100"}]}"#)
+        .create_async()
+        .await;
+    let result = crate::commands::scorecards::list(&cfg, 101, true).await;
+    assert!(result.is_ok(), "scorecards list failed: {:?}", result.err());
+    first.assert_async().await;
+    second.assert_async().await;
     cleanup_env();
 }
-
-// --- Misc ---
 #[tokio::test]
-async fn test_misc_ip_ranges() {
+async fn test_scorecards_get() {
     let _lock = lock_env();
     let mut s = mockito::Server::new_async().await;
     let cfg = test_config(&s.url());
-    mock_all(&mut s, r#"{}"#).await;
-    let _ = crate::commands::misc::ip_ranges(&cfg).await;
+    let m = s
+        .mock("GET", "/api/v2/scorecard/scorecards/s1")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": {"id": "s1"}}"#)
+        .create_async()
+        .await;
+    let result = crate::commands::scorecards::get(&cfg, "s1", false).await;
+    assert!(result.is_ok(), "scorecards get failed: {:?}", result.err());
+    m.assert_async().await;
     cleanup_env();
 }
-
-// --- Data Governance ---
 #[tokio::test]
-async fn test_data_governance_scanner_rules_list() {
+async fn test_scorecards_outcomes_list() {
     let _lock = lock_env();
     let mut s = mockito::Server::new_async().await;
     let cfg = test_config(&s.url());
     mock_all(&mut s, r#"{"data": []}"#).await;
-    let _ = crate::commands::data_governance::scanner_rules_list(&cfg).await;
+    let _ = crate::commands::scorecards::outcomes_list(&cfg, Some("web-store".into())).await;
     cleanup_env();
 }
 
-// --- Investigations ---
+// --- Obs Pipelines ---
 #[tokio::test]
-async fn test_investigations_list() {
+async fn test_obs_pipelines_delete() {
     let _lock = lock_env();
     let mut s = mockito::Server::new_async().await;
     let cfg = test_config(&s.url());
-    mock_all(&mut s, r#"{"data": []}"#).await;
-    let _ = crate::commands::investigations::list(&cfg, 10, 0).await;
+    let mock = s
+        .mock("DELETE", "/api/v2/obs-pipelines/pipelines/p-1")
+        .with_status(204)
+        .create_async()
+        .await;
+    let result = crate::commands::obs_pipelines::delete(&cfg, "p-1").await;
+    assert!(result.is_ok(), "pipeline delete failed: {:?}", result.err());
+    mock.assert_async().await;
     cleanup_env();
 }
 #[tokio::test]
-async fn test_investigations_get() {
+async fn test_obs_pipelines_list() {
     let _lock = lock_env();
     let mut s = mockito::Server::new_async().await;
     let cfg = test_config(&s.url());
-    mock_all(&mut s, r#"{"data": {}}"#).await;
-    let _ = crate::commands::investigations::get(&cfg, "inv1").await;
+    let mock = s
+        .mock("GET", "/api/v2/obs-pipelines/pipelines")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "page[size]".into(),
+            "5".into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": []}"#)
+        .create_async()
+        .await;
+    let result = crate::commands::obs_pipelines::list(&cfg, 5, 0).await;
+    assert!(result.is_ok(), "pipeline list failed: {:?}", result.err());
+    mock.assert_async().await;
     cleanup_env();
 }
-
-// --- Network ---
 #[tokio::test]
-async fn test_network_flows_list() {
+async fn test_obs_pipelines_get() {
     let _lock = lock_env();
     let mut s = mockito::Server::new_async().await;
     let cfg = test_config(&s.url());
-    mock_all(&mut s, r#"{"data": []}"#).await;
-    let _ = crate::commands::network::flows_list(&cfg).await;
+    let mock = s
+        .mock("GET", "/api/v2/obs-pipelines/pipelines/p-1")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"data": {"id": "p-1", "type": "pipelines", "attributes": {"name": "main", "config": {"destinations": [], "sources": []}}}}"#,
+        )
+        .create_async()
+        .await;
+    let result = crate::commands::obs_pipelines::get(&cfg, "p-1").await;
+    assert!(result.is_ok(), "pipeline get failed: {:?}", result.err());
+    mock.assert_async().await;
     cleanup_env();
 }
 #[tokio::test]
-async fn test_network_devices_list() {
+async fn test_obs_pipelines_validate_missing_file() {
     let _lock = lock_env();
     let mut s = mockito::Server::new_async().await;
     let cfg = test_config(&s.url());
-    mock_all(&mut s, r#"{"data": []}"#).await;
-    let _ = crate::commands::network::devices_list(&cfg).await;
+    mock_all(&mut s, r#"{"errors": []}"#).await;
+    let result = crate::commands::obs_pipelines::validate(&cfg, "/nonexistent/pipeline.json").await;
+    assert!(result.is_err());
     cleanup_env();
 }
 
@@ -1715,6 +5063,30 @@ async fn test_hamr_connections_get() {
     cleanup_env();
 }
 
+#[tokio::test]
+async fn test_hamr_connections_update() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    let m = s
+        .mock("POST", "/api/v2/hamr")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"data": {"type": "hamr_org_connections", "attributes": {"hamr_status": 2}}}"#.into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": {"id": "uuid-1", "type": "hamr_org_connections", "attributes": {"hamr_status": 2, "is_primary": true, "modified_by": "admin@example.com", "target_org_datacenter": "us3", "target_org_name": "Backup", "target_org_uuid": "uuid-2", "modified_at": "2026-01-01T00:00:00Z"}}}"#)
+        .create_async()
+        .await;
+    let file = std::env::temp_dir().join("pup_test_hamr_connection.json");
+    std::fs::write(&file, r#"{"data": {"id": "uuid-1", "type": "hamr_org_connections", "attributes": {"hamr_status": 2, "is_primary": true, "modified_by": "admin@example.com", "target_org_datacenter": "us3", "target_org_name": "Backup", "target_org_uuid": "uuid-2"}}}"#).unwrap();
+    let result = crate::commands::hamr::connections_update(&cfg, file.to_str().unwrap()).await;
+    let _ = std::fs::remove_file(&file);
+    assert!(result.is_ok(), "hamr update failed: {:?}", result.err());
+    m.assert_async().await;
+    cleanup_env();
+}
+
 // --- Static Analysis ---
 #[tokio::test]
 async fn test_static_analysis_ast_list() {
@@ -1764,3 +5136,100 @@ async fn test_apm_services_list() {
         crate::commands::apm::services_list(&cfg, "prod".into(), "1h".into(), "now".into()).await;
     cleanup_env();
 }
+#[tokio::test]
+async fn test_apm_dependencies_for_service() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    let m = s
+        .mock("GET", "/api/v1/service_dependencies")
+        .match_query(mockito::Matcher::UrlEncoded("env".into(), "prod".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"web": {"calls": ["checkout"]}, "checkout": {"calls": ["postgres"]}}"#)
+        .expect(2)
+        .create_async()
+        .await;
+    let result = crate::commands::apm::dependencies_for_service(
+        &cfg,
+        "checkout".into(),
+        "prod".into(),
+        "1h".into(),
+        "now".into(),
+        Some(2),
+    )
+    .await;
+    assert!(result.is_ok(), "get-for-service failed: {:?}", result.err());
+    let missing = crate::commands::apm::dependencies_for_service(
+        &cfg,
+        "unknown".into(),
+        "prod".into(),
+        "1h".into(),
+        "now".into(),
+        None,
+    )
+    .await;
+    assert!(missing.is_err());
+    m.assert_async().await;
+    cleanup_env();
+}
+#[tokio::test]
+async fn test_apm_services_all_resources() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    let _m = s
+        .mock("GET", "/api/ui/apm/resources")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("service".into(), "web-store".into()),
+            mockito::Matcher::UrlEncoded("limit".into(), "25".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": []}"#)
+        .create_async()
+        .await;
+    let result = crate::commands::apm::services_all_resources(
+        &cfg,
+        "web-store".into(),
+        "prod".into(),
+        "1h".into(),
+        "now".into(),
+        25,
+    )
+    .await;
+    assert!(result.is_ok(), "all-resources failed: {:?}", result.err());
+    cleanup_env();
+}
+
+#[tokio::test]
+async fn test_apm_entities_search() {
+    let _lock = lock_env();
+    let mut s = mockito::Server::new_async().await;
+    let cfg = test_config(&s.url());
+    let _m = s
+        .mock("GET", "/api/unstable/apm/entities")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "filter[query]".into(),
+            "service:web-store".into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": []}"#)
+        .create_async()
+        .await;
+    let result = crate::commands::apm::entities_search(
+        &cfg,
+        "service:web-store".into(),
+        "1h".into(),
+        "now".into(),
+        10,
+    )
+    .await;
+    assert!(
+        result.is_ok(),
+        "apm entities search failed: {:?}",
+        result.err()
+    );
+    cleanup_env();
+}
@@ -16,6 +16,17 @@ use regex::Regex;
 /// All relative times are interpreted as "ago from now".
 /// Returns second-aligned milliseconds (Unix seconds * 1000) to match Go behavior.
 pub fn parse_time_to_unix_millis(input: &str) -> Result<i64> {
+    parse_time_millis(input, -1)
+}
+
+/// Like `parse_time_to_unix`, but relative durations such as "2h" count
+/// forward from now instead of back, e.g. for the end of a mute window.
+pub fn parse_future_time(input: &str) -> Result<i64> {
+    Ok(parse_time_millis(input, 1)? / 1000)
+}
+
+/// Shared parser; `direction` is -1 for "ago" and 1 for "from now".
+fn parse_time_millis(input: &str, direction: i64) -> Result<i64> {
     let input = input.trim();
 
     // "now" (case-insensitive)
@@ -54,7 +65,7 @@ pub fn parse_time_to_unix_millis(input: &str) -> Result<i64> {
             _ => bail!("unknown time unit: {}", unit),
         };
         // Second-aligned: Unix seconds * 1000 (matches Go behavior)
-        return Ok((Utc::now().timestamp() - seconds) * 1000);
+        return Ok((Utc::now().timestamp() + direction * seconds) * 1000);
     }
 
     bail!(
@@ -86,6 +97,18 @@ pub fn parse_uuid(id: &str, label: &str) -> anyhow::Result<uuid::Uuid> {
     uuid::Uuid::parse_str(id).map_err(|e| anyhow::anyhow!("invalid {label} UUID '{id}': {e}"))
 }
 
+/// Apply a JSONPath expression (e.g. `$[*].name`) to `data`, returning the
+/// matched values as an array.
+pub fn json_path_query(data: &serde_json::Value, expr: &str) -> Result<serde_json::Value> {
+    use jsonpath_rust::JsonPath;
+    let matches = data
+        .query(expr)
+        .map_err(|e| anyhow::anyhow!("invalid JSONPath expression {expr:?}: {e}"))?;
+    Ok(serde_json::Value::Array(
+        matches.into_iter().cloned().collect(),
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -162,6 +185,28 @@ mod tests {
         assert!((secs - expected).abs() < 2);
     }
 
+    #[test]
+    fn test_parse_future_time_counts_forward() {
+        let secs = parse_future_time("2h").unwrap();
+        let expected = Utc::now().timestamp() + 7200;
+        assert!((secs - expected).abs() < 2);
+        let secs = parse_future_time("30 minutes").unwrap();
+        let expected = Utc::now().timestamp() + 1800;
+        assert!((secs - expected).abs() < 2);
+    }
+
+    #[test]
+    fn test_parse_future_time_absolute() {
+        assert_eq!(
+            parse_future_time("2099-01-01T00:00:00Z").unwrap(),
+            4070908800
+        );
+        assert_eq!(parse_future_time("1700000000000").unwrap(), 1700000000);
+        let now = parse_future_time("now").unwrap();
+        assert!((now - Utc::now().timestamp()).abs() < 2);
+        assert!(parse_future_time("soon").is_err());
+    }
+
     #[test]
     fn test_relative_days() {
         let ms = parse_time_to_unix_millis("7d").unwrap();
@@ -230,4 +275,30 @@ mod tests {
         assert_eq!(result.unwrap()["name"], "test");
         std::fs::remove_file(path).ok();
     }
+
+    #[test]
+    fn test_json_path_query() {
+        let data = serde_json::json!([
+            {"id": 1, "name": "cpu", "tags": ["env:prod"]},
+            {"id": 2, "name": "mem", "tags": []}
+        ]);
+        assert_eq!(
+            json_path_query(&data, "$[*].name").unwrap(),
+            serde_json::json!(["cpu", "mem"])
+        );
+        assert_eq!(
+            json_path_query(&data, "$[?@.id == 2].name").unwrap(),
+            serde_json::json!(["mem"])
+        );
+        assert_eq!(
+            json_path_query(&data, "$[*].missing").unwrap(),
+            serde_json::json!([])
+        );
+    }
+
+    #[test]
+    fn test_json_path_query_invalid() {
+        let err = json_path_query(&serde_json::json!([]), "$[").unwrap_err();
+        assert!(err.to_string().contains("invalid JSONPath expression"));
+    }
 }